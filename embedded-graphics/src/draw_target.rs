@@ -241,6 +241,30 @@ where
         Ok(())
     }
 
+    /// Draws a horizontal span of pixels with the same color.
+    ///
+    /// This default trait method can be overridden if a display provides a fast way to fill
+    /// horizontal lines, for example by setting a drawing window once and filling it using DMA.
+    fn draw_span(&mut self, span: drawable::Span<C>) -> Result<(), Self::Error> {
+        self.draw_iter(span)
+    }
+
+    /// Draws an object from an iterator over its spans.
+    ///
+    /// The default implementation calls [`draw_span`] for each span.
+    ///
+    /// [`draw_span`]: #method.draw_span
+    fn draw_spans<T>(&mut self, spans: T) -> Result<(), Self::Error>
+    where
+        T: IntoIterator<Item = drawable::Span<C>>,
+    {
+        for span in spans {
+            self.draw_span(span)?;
+        }
+
+        Ok(())
+    }
+
     /// Returns the dimensions of the `DrawTarget` in pixels.
     fn size(&self) -> Size;
 
@@ -262,6 +286,9 @@ where
     /// This default trait method can be overridden if a display provides hardware-accelerated
     /// methods for drawing lines.
     ///
+    /// The default implementation only draws the pixels which are inside the area defined by
+    /// [`size`], without iterating over the pixels outside this area.
+    ///
     /// # Caution
     ///
     /// This method should not be called directly from application code. It is used to define the
@@ -271,11 +298,14 @@ where
     /// [`Line`]: ../primitives/line/struct.Line.html
     /// [`draw`]: ./trait.DrawTarget.html#method.draw
     /// [`Styled`]: ../style/struct.Styled.html
+    /// [`size`]: ./trait.DrawTarget.html#tymethod.size
     fn draw_line(
         &mut self,
         item: &Styled<primitives::Line, PrimitiveStyle<C>>,
     ) -> Result<(), Self::Error> {
-        self.draw_iter(item)
+        let clip_area = primitives::Rectangle::new(Point::zero(), self.size());
+
+        self.draw_iter(item.clipped(&clip_area))
     }
 
     /// Draws a styled triangle primitive.
@@ -296,7 +326,7 @@ where
         &mut self,
         item: &Styled<primitives::Triangle, PrimitiveStyle<C>>,
     ) -> Result<(), Self::Error> {
-        self.draw_iter(item)
+        self.draw_spans(item.spans())
     }
 
     /// Draws a styled rectangle primitive.
@@ -317,7 +347,7 @@ where
         &mut self,
         item: &Styled<primitives::Rectangle, PrimitiveStyle<C>>,
     ) -> Result<(), Self::Error> {
-        self.draw_iter(item)
+        self.draw_spans(item.spans())
     }
 
     /// Draws a styled circle primitive.
@@ -338,7 +368,7 @@ where
         &mut self,
         item: &Styled<primitives::Circle, PrimitiveStyle<C>>,
     ) -> Result<(), Self::Error> {
-        self.draw_iter(item)
+        self.draw_spans(item.spans())
     }
 
     /// Draws an image with known size
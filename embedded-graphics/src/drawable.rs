@@ -7,6 +7,11 @@ use crate::{geometry::Point, pixelcolor::PixelColor, DrawTarget};
 /// can define its `draw` method as a collection of graphical primitives or as an iterator
 /// over pixels being rendered with [`DrawTarget`]'s [`draw_iter`] method.
 ///
+/// `Drawable` is also implemented for tuples of up to 12 drawables, arrays and slices of
+/// drawables and for `Option`s. The items in a group are drawn in order, which makes it possible
+/// to return a tuple of primitives from a function to describe a more complex object. `None` isn't
+/// drawn at all.
+///
 /// ```rust
 /// use embedded_graphics::{
 ///     egrectangle, egtext,
@@ -122,6 +127,297 @@ where
     }
 }
 
+impl<C, T> Drawable<C> for Option<T>
+where
+    C: PixelColor,
+    T: Drawable<C>,
+{
+    fn draw<D: DrawTarget<C>>(self, display: &mut D) -> Result<(), D::Error> {
+        if let Some(drawable) = self {
+            drawable.draw(display)
+        } else {
+            Ok(())
+        }
+    }
+}
+
+impl<'a, C, T> Drawable<C> for &'a Option<T>
+where
+    C: PixelColor,
+    &'a T: Drawable<C>,
+{
+    fn draw<D: DrawTarget<C>>(self, display: &mut D) -> Result<(), D::Error> {
+        self.as_ref().draw(display)
+    }
+}
+
+impl<'a, C, T> Drawable<C> for &'a [T]
+where
+    C: PixelColor,
+    &'a T: Drawable<C>,
+{
+    fn draw<D: DrawTarget<C>>(self, display: &mut D) -> Result<(), D::Error> {
+        for drawable in self {
+            drawable.draw(display)?;
+        }
+
+        Ok(())
+    }
+}
+
+/// Implements `Drawable` for arrays.
+///
+/// Arrays of references or other `Copy` drawables can be drawn by value and references to arrays
+/// are drawn by drawing a reference to each element.
+macro_rules! drawable_array {
+    ($($n:expr),*) => {
+        $(
+            impl<C, T> Drawable<C> for [T; $n]
+            where
+                C: PixelColor,
+                T: Drawable<C> + Copy,
+            {
+                fn draw<D: DrawTarget<C>>(self, display: &mut D) -> Result<(), D::Error> {
+                    for drawable in self.iter() {
+                        drawable.draw(display)?;
+                    }
+
+                    Ok(())
+                }
+            }
+
+            impl<'a, C, T> Drawable<C> for &'a [T; $n]
+            where
+                C: PixelColor,
+                &'a T: Drawable<C>,
+            {
+                fn draw<D: DrawTarget<C>>(self, display: &mut D) -> Result<(), D::Error> {
+                    (&self[..]).draw(display)
+                }
+            }
+        )*
+    };
+}
+
+drawable_array!(
+    0, 1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12, 13, 14, 15, 16, 17, 18, 19, 20, 21, 22, 23, 24, 25,
+    26, 27, 28, 29, 30, 31, 32
+);
+
+/// Implements `Drawable` for tuples.
+///
+/// The elements of a tuple are drawn in order, which means that later elements are drawn on top
+/// of earlier elements.
+macro_rules! drawable_tuple {
+    ($(($($t:ident: $i:tt),+)),*) => {
+        $(
+            impl<C, $($t),+> Drawable<C> for ($($t,)+)
+            where
+                C: PixelColor,
+                $($t: Drawable<C>,)+
+            {
+                fn draw<D: DrawTarget<C>>(self, display: &mut D) -> Result<(), D::Error> {
+                    $(self.$i.draw(display)?;)+
+
+                    Ok(())
+                }
+            }
+
+            impl<'a, C, $($t),+> Drawable<C> for &'a ($($t,)+)
+            where
+                C: PixelColor,
+                $(&'a $t: Drawable<C>,)+
+            {
+                fn draw<D: DrawTarget<C>>(self, display: &mut D) -> Result<(), D::Error> {
+                    $((&self.$i).draw(display)?;)+
+
+                    Ok(())
+                }
+            }
+        )*
+    };
+}
+
+drawable_tuple!(
+    (T1: 0),
+    (T1: 0, T2: 1),
+    (T1: 0, T2: 1, T3: 2),
+    (T1: 0, T2: 1, T3: 2, T4: 3),
+    (T1: 0, T2: 1, T3: 2, T4: 3, T5: 4),
+    (T1: 0, T2: 1, T3: 2, T4: 3, T5: 4, T6: 5),
+    (T1: 0, T2: 1, T3: 2, T4: 3, T5: 4, T6: 5, T7: 6),
+    (T1: 0, T2: 1, T3: 2, T4: 3, T5: 4, T6: 5, T7: 6, T8: 7),
+    (T1: 0, T2: 1, T3: 2, T4: 3, T5: 4, T6: 5, T7: 6, T8: 7, T9: 8),
+    (T1: 0, T2: 1, T3: 2, T4: 3, T5: 4, T6: 5, T7: 6, T8: 7, T9: 8, T10: 9),
+    (T1: 0, T2: 1, T3: 2, T4: 3, T5: 4, T6: 5, T7: 6, T8: 7, T9: 8, T10: 9, T11: 10),
+    (T1: 0, T2: 1, T3: 2, T4: 3, T5: 4, T6: 5, T7: 6, T8: 7, T9: 8, T10: 9, T11: 10, T12: 11)
+);
+
+/// A horizontal run of pixels with the same color.
+///
+/// Spans are an alternative to [`Pixel`]s which can be used by display drivers to draw large
+/// areas with the same color more efficiently. Drivers for displays that provide a fast way to
+/// fill horizontal lines, e.g. by setting a drawing window once and streaming the color data with
+/// DMA, can override [`DrawTarget::draw_span`] to draw all pixels in a span at once.
+///
+/// Styled rectangles, circles, triangles and texts provide a `spans` method which returns an
+/// iterator over the spans that make up the drawn object. Any iterator over pixels can be
+/// converted into spans by using [`Spans`].
+///
+/// # Examples
+///
+/// ```
+/// use embedded_graphics::{
+///     drawable::Span, pixelcolor::BinaryColor, prelude::*, primitives::Rectangle,
+///     style::PrimitiveStyle,
+/// };
+///
+/// let rectangle = Rectangle::new(Point::new(1, 2), Size::new(5, 2))
+///     .into_styled(PrimitiveStyle::with_fill(BinaryColor::On));
+///
+/// let mut spans = rectangle.spans();
+/// assert_eq!(spans.next(), Some(Span::new(Point::new(1, 2), 5, BinaryColor::On)));
+/// assert_eq!(spans.next(), Some(Span::new(Point::new(1, 3), 5, BinaryColor::On)));
+/// assert_eq!(spans.next(), None);
+/// ```
+///
+/// [`Pixel`]: struct.Pixel.html
+/// [`Spans`]: struct.Spans.html
+/// [`DrawTarget::draw_span`]: ../trait.DrawTarget.html#method.draw_span
+#[derive(Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Debug, Default)]
+pub struct Span<C: PixelColor> {
+    /// Position of the leftmost pixel.
+    pub start: Point,
+
+    /// Number of pixels in the span.
+    pub width: u32,
+
+    /// Color of all pixels in the span.
+    pub color: C,
+}
+
+impl<C> Span<C>
+where
+    C: PixelColor,
+{
+    /// Creates a new span.
+    pub fn new(start: Point, width: u32, color: C) -> Self {
+        Self {
+            start,
+            width,
+            color,
+        }
+    }
+}
+
+impl<C> Drawable<C> for Span<C>
+where
+    C: PixelColor,
+{
+    fn draw<D: DrawTarget<C>>(self, display: &mut D) -> Result<(), D::Error> {
+        display.draw_span(self)
+    }
+}
+
+impl<C> IntoIterator for Span<C>
+where
+    C: PixelColor,
+{
+    type Item = Pixel<C>;
+    type IntoIter = SpanPixels<C>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        SpanPixels { span: self, x: 0 }
+    }
+}
+
+/// Iterator over the pixels in a span.
+#[derive(Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Debug)]
+pub struct SpanPixels<C>
+where
+    C: PixelColor,
+{
+    span: Span<C>,
+    x: u32,
+}
+
+impl<C> Iterator for SpanPixels<C>
+where
+    C: PixelColor,
+{
+    type Item = Pixel<C>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.x >= self.span.width {
+            return None;
+        }
+
+        let point = self.span.start + Point::new(self.x as i32, 0);
+        self.x += 1;
+
+        Some(Pixel(point, self.span.color))
+    }
+}
+
+/// Iterator adapter which combines pixels into spans.
+///
+/// Consecutive pixels which are on the same row, have the same color and whose X coordinate
+/// increases by one are combined into a single [`Span`]. The order of the pixels is preserved,
+/// which means that drawing the spans results in the same output as drawing the pixels.
+///
+/// [`Span`]: struct.Span.html
+#[derive(Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Debug)]
+pub struct Spans<I, C>
+where
+    C: PixelColor,
+{
+    pixels: I,
+    next: Option<Pixel<C>>,
+}
+
+impl<I, C> Spans<I, C>
+where
+    I: Iterator<Item = Pixel<C>>,
+    C: PixelColor,
+{
+    /// Creates a span iterator from an iterator over pixels.
+    pub fn new<T>(pixels: T) -> Self
+    where
+        T: IntoIterator<IntoIter = I, Item = Pixel<C>>,
+    {
+        Self {
+            pixels: pixels.into_iter(),
+            next: None,
+        }
+    }
+}
+
+impl<I, C> Iterator for Spans<I, C>
+where
+    I: Iterator<Item = Pixel<C>>,
+    C: PixelColor,
+{
+    type Item = Span<C>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let Pixel(start, color) = self.next.take().or_else(|| self.pixels.next())?;
+
+        let mut width = 1;
+        for pixel in &mut self.pixels {
+            let Pixel(point, pixel_color) = pixel;
+
+            if point.y == start.y && point.x == start.x + width as i32 && pixel_color == color {
+                width += 1;
+            } else {
+                self.next = Some(pixel);
+                break;
+            }
+        }
+
+        Some(Span::new(start, width, color))
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -146,4 +442,123 @@ mod tests {
 
         Ok(())
     }
+
+    #[test]
+    fn spans_from_pixels() {
+        let pixels = [
+            Pixel(Point::new(0, 0), BinaryColor::On),
+            Pixel(Point::new(1, 0), BinaryColor::On),
+            Pixel(Point::new(2, 0), BinaryColor::Off),
+            Pixel(Point::new(4, 0), BinaryColor::Off),
+            Pixel(Point::new(5, 1), BinaryColor::Off),
+            Pixel(Point::new(6, 1), BinaryColor::Off),
+        ];
+
+        let mut spans = Spans::new(pixels.iter().copied());
+        assert_eq!(
+            spans.next(),
+            Some(Span::new(Point::new(0, 0), 2, BinaryColor::On))
+        );
+        assert_eq!(
+            spans.next(),
+            Some(Span::new(Point::new(2, 0), 1, BinaryColor::Off))
+        );
+        assert_eq!(
+            spans.next(),
+            Some(Span::new(Point::new(4, 0), 1, BinaryColor::Off))
+        );
+        assert_eq!(
+            spans.next(),
+            Some(Span::new(Point::new(5, 1), 2, BinaryColor::Off))
+        );
+        assert_eq!(spans.next(), None);
+
+        assert!(Spans::new(pixels.iter().copied())
+            .flat_map(|span| span.into_iter())
+            .eq(pixels.iter().copied()));
+    }
+
+    #[test]
+    fn draw_span() -> Result<(), core::convert::Infallible> {
+        let mut display = MockDisplay::new();
+        Span::new(Point::new(1, 0), 3, BinaryColor::On).draw(&mut display)?;
+        Span::new(Point::new(0, 2), 2, BinaryColor::Off).draw(&mut display)?;
+
+        #[rustfmt::skip]
+        assert_eq!(
+            MockDisplay::from_pattern(&[
+                " ###",
+                "    ",
+                "..  ",
+            ]),
+            display
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn draw_groups() -> Result<(), core::convert::Infallible> {
+        use crate::{
+            geometry::{Dimensions, Size},
+            primitives::{Circle, Primitive, Rectangle},
+            style::{PrimitiveStyle, Styled},
+        };
+
+        let style = PrimitiveStyle::with_fill(BinaryColor::On);
+        let rectangle = Rectangle::new(Point::new(0, 0), Size::new(2, 2)).into_styled(style);
+        let rectangle2 = Rectangle::new(Point::new(3, 2), Size::new(2, 2)).into_styled(style);
+        let circle = Circle::new(Point::new(3, 1), 3).into_styled(style);
+
+        let group = (
+            rectangle,
+            Some(circle),
+            None::<Styled<Circle, PrimitiveStyle<BinaryColor>>>,
+        );
+
+        let mut display = MockDisplay::new();
+        group.draw(&mut display)?;
+
+        #[rustfmt::skip]
+        assert_eq!(
+            display,
+            MockDisplay::from_pattern(&[
+                "##    ",
+                "##  # ",
+                "   ###",
+                "    # ",
+            ])
+        );
+        assert_eq!(
+            group.bounding_box(),
+            Rectangle::new(Point::zero(), Size::new(6, 4))
+        );
+
+        let mut slice_display = MockDisplay::new();
+        (&[rectangle, rectangle2][..]).draw(&mut slice_display)?;
+
+        let mut array_display = MockDisplay::new();
+        [&rectangle, &rectangle2].draw(&mut array_display)?;
+
+        #[rustfmt::skip]
+        let expected = MockDisplay::from_pattern(&[
+            "##   ",
+            "##   ",
+            "   ##",
+            "   ##",
+        ]);
+        assert_eq!(slice_display, expected);
+        assert_eq!(array_display, expected);
+
+        assert_eq!(
+            [rectangle, rectangle2].bounding_box(),
+            Rectangle::new(Point::zero(), Size::new(5, 4))
+        );
+        assert_eq!(
+            None::<Rectangle>.bounding_box(),
+            Rectangle::new(Point::zero(), Size::zero())
+        );
+
+        Ok(())
+    }
 }
@@ -0,0 +1,412 @@
+//! Flood fill.
+//!
+//! Flood filling replaces the color of a connected region of pixels on a draw target. The region
+//! starts at a seed point and contains all pixels that have the same color as the seed point and
+//! are connected to it by a horizontal or vertical path of pixels with that color.
+//!
+//! The fill requires a draw target that can read back the color of its pixels by implementing
+//! the [`GetPixel`] trait. [`MockDisplay`] and the `SimulatorDisplay` from the
+//! [embedded-graphics-simulator] crate implement this trait.
+//!
+//! The fill uses a scanline algorithm with a fixed size stack of pending [`Segment`]s, which is
+//! provided by the caller of [`flood_fill_with_stack`] or allocated on the call stack by
+//! [`flood_fill`]. No heap allocations are required. If the stack isn't large enough to hold all
+//! pending segments the fill is continued for the segments that fit on the stack and a
+//! [`FloodFillError::StackOverflow`] error is returned. Regions with complex shapes, for example
+//! mazes, require larger stacks than simple convex regions.
+//!
+//! # Examples
+//!
+//! ## Fill the inside of a circle
+//!
+//! ```rust
+//! use embedded_graphics::{
+//!     flood_fill::flood_fill,
+//!     pixelcolor::BinaryColor,
+//!     prelude::*,
+//!     primitive_style,
+//!     primitives::Circle,
+//! };
+//! # use embedded_graphics::mock_display::MockDisplay;
+//! # let mut display: MockDisplay<BinaryColor> = MockDisplay::default();
+//!
+//! // The flood fill only replaces pixels with a known color
+//! display.clear(BinaryColor::Off)?;
+//!
+//! Circle::with_center(Point::new(20, 20), 31)
+//!     .into_styled(primitive_style!(stroke_color = BinaryColor::On, stroke_width = 1))
+//!     .draw(&mut display)?;
+//!
+//! flood_fill(&mut display, Point::new(20, 20), BinaryColor::On)?;
+//! # assert_eq!(display.get_pixel(Point::new(10, 20)), Some(BinaryColor::On));
+//! # assert_eq!(display.get_pixel(Point::new(50, 20)), Some(BinaryColor::Off));
+//! # Ok::<(), embedded_graphics::flood_fill::FloodFillError<core::convert::Infallible>>(())
+//! ```
+//!
+//! ## Use a larger stack
+//!
+//! ```rust
+//! use embedded_graphics::{
+//!     flood_fill::{flood_fill_with_stack, Segment},
+//!     pixelcolor::BinaryColor,
+//!     prelude::*,
+//! };
+//! # use embedded_graphics::mock_display::MockDisplay;
+//! # let mut display: MockDisplay<BinaryColor> = MockDisplay::default();
+//! # display.clear(BinaryColor::Off)?;
+//!
+//! let mut stack = [Segment::default(); 256];
+//!
+//! flood_fill_with_stack(&mut display, Point::new(5, 5), BinaryColor::On, &mut stack)?;
+//! # Ok::<(), embedded_graphics::flood_fill::FloodFillError<core::convert::Infallible>>(())
+//! ```
+//!
+//! [`GetPixel`]: ../image/trait.GetPixel.html
+//! [`MockDisplay`]: ../mock_display/struct.MockDisplay.html
+//! [embedded-graphics-simulator]: https://crates.io/crates/embedded-graphics-simulator
+//! [`Segment`]: struct.Segment.html
+//! [`flood_fill`]: fn.flood_fill.html
+//! [`flood_fill_with_stack`]: fn.flood_fill_with_stack.html
+//! [`FloodFillError::StackOverflow`]: enum.FloodFillError.html#variant.StackOverflow
+
+use crate::{
+    drawable::Pixel, geometry::Point, image::GetPixel, pixelcolor::PixelColor, DrawTarget,
+};
+
+/// Number of segments in the stack used by [`flood_fill`].
+///
+/// [`flood_fill`]: fn.flood_fill.html
+pub const DEFAULT_STACK_SIZE: usize = 64;
+
+/// Pending horizontal segment in a flood fill.
+///
+/// This type is only used to allocate the stack for [`flood_fill_with_stack`].
+///
+/// [`flood_fill_with_stack`]: fn.flood_fill_with_stack.html
+#[derive(Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Debug, Default)]
+pub struct Segment {
+    /// Row which should be scanned.
+    y: i32,
+
+    /// Start column of the filled segment in the parent row.
+    x1: i32,
+
+    /// End column of the filled segment in the parent row.
+    x2: i32,
+
+    /// Direction from the parent row to this row.
+    dy: i32,
+}
+
+/// Flood fill error.
+#[derive(Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Debug)]
+pub enum FloodFillError<E> {
+    /// The stack was too small to hold all pending segments.
+    ///
+    /// The filled region might be incomplete.
+    StackOverflow,
+
+    /// The draw target returned an error.
+    DrawTarget(E),
+}
+
+impl<E> From<E> for FloodFillError<E> {
+    fn from(error: E) -> Self {
+        FloodFillError::DrawTarget(error)
+    }
+}
+
+/// Fills a region with a color using a stack with [`DEFAULT_STACK_SIZE`] segments.
+///
+/// See the [module-level documentation](index.html) for more information.
+///
+/// [`DEFAULT_STACK_SIZE`]: constant.DEFAULT_STACK_SIZE.html
+pub fn flood_fill<D, C>(
+    target: &mut D,
+    seed: Point,
+    replacement: C,
+) -> Result<(), FloodFillError<D::Error>>
+where
+    D: DrawTarget<C> + GetPixel<C>,
+    C: PixelColor,
+{
+    let mut stack = [Segment::default(); DEFAULT_STACK_SIZE];
+
+    flood_fill_with_stack(target, seed, replacement, &mut stack)
+}
+
+/// Fills a region with a color using a custom stack.
+///
+/// The maximum number of pending segments is determined by the length of `stack`.
+///
+/// See the [module-level documentation](index.html) for more information.
+pub fn flood_fill_with_stack<D, C>(
+    target: &mut D,
+    seed: Point,
+    replacement: C,
+    stack: &mut [Segment],
+) -> Result<(), FloodFillError<D::Error>>
+where
+    D: DrawTarget<C> + GetPixel<C>,
+    C: PixelColor,
+{
+    let mut fill = ScanlineFill {
+        target,
+        stack,
+        len: 0,
+        overflow: false,
+        replacement,
+        original: None,
+    };
+
+    fill.run(seed)
+}
+
+struct ScanlineFill<'a, D, C> {
+    target: &'a mut D,
+    stack: &'a mut [Segment],
+    len: usize,
+    overflow: bool,
+    replacement: C,
+    original: Option<C>,
+}
+
+impl<D, C> ScanlineFill<'_, D, C>
+where
+    D: DrawTarget<C> + GetPixel<C>,
+    C: PixelColor,
+{
+    fn run(&mut self, seed: Point) -> Result<(), FloodFillError<D::Error>> {
+        self.original = self.target.pixel(seed);
+
+        // Pixels with an unknown color or the replacement color are never filled.
+        if self.original.is_none()
+            || self.original == Some(self.replacement)
+            || !self.is_inside(seed.x, seed.y)
+        {
+            return Ok(());
+        }
+
+        let replacement = self.replacement;
+
+        self.push(seed.y, seed.x, seed.x, 1);
+        self.push(seed.y - 1, seed.x, seed.x, -1);
+
+        while let Some(Segment { y, x1, x2, dy }) = self.pop() {
+            // Find the first run in this row that is connected to the parent segment.
+            let mut left = if self.is_inside(x1, y) {
+                let mut x = x1;
+                while self.is_inside(x - 1, y) {
+                    x -= 1;
+                }
+                x
+            } else {
+                match self.next_inside(x1 + 1, x2, y) {
+                    Some(next) => next,
+                    None => continue,
+                }
+            };
+
+            loop {
+                let mut right = left;
+                while self.is_inside(right + 1, y) {
+                    right += 1;
+                }
+
+                self.target
+                    .draw_iter((left..=right).map(|x| Pixel(Point::new(x, y), replacement)))?;
+
+                self.push(y + dy, left, right, dy);
+
+                // Parts of the run that extend past the parent segment can leak back into the
+                // parent row.
+                if left < x1 {
+                    self.push(y - dy, left, x1 - 1, -dy);
+                }
+                if right > x2 {
+                    self.push(y - dy, x2 + 1, right, -dy);
+                }
+
+                match self.next_inside(right + 2, x2, y) {
+                    Some(next) => left = next,
+                    None => break,
+                }
+            }
+        }
+
+        if self.overflow {
+            Err(FloodFillError::StackOverflow)
+        } else {
+            Ok(())
+        }
+    }
+
+    /// Returns `true` if the pixel is inside the region that should be filled.
+    fn is_inside(&self, x: i32, y: i32) -> bool {
+        let size = self.target.size();
+
+        x >= 0
+            && y >= 0
+            && (x as u32) < size.width
+            && (y as u32) < size.height
+            && self.target.pixel(Point::new(x, y)) == self.original
+    }
+
+    /// Returns the first column in `start..=end` which is inside the region.
+    fn next_inside(&self, start: i32, end: i32, y: i32) -> Option<i32> {
+        (start..=end).find(|&x| self.is_inside(x, y))
+    }
+
+    fn push(&mut self, y: i32, x1: i32, x2: i32, dy: i32) {
+        if y < 0 || y as u32 >= self.target.size().height {
+            return;
+        }
+
+        if let Some(segment) = self.stack.get_mut(self.len) {
+            *segment = Segment { y, x1, x2, dy };
+            self.len += 1;
+        } else {
+            self.overflow = true;
+        }
+    }
+
+    fn pop(&mut self) -> Option<Segment> {
+        self.len = self.len.checked_sub(1)?;
+
+        Some(self.stack[self.len])
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        mock_display::MockDisplay,
+        pixelcolor::{BinaryColor, Gray8},
+    };
+
+    fn fill_pattern(
+        pattern: &[&str],
+        seed: Point,
+        stack_size: usize,
+    ) -> (
+        MockDisplay<BinaryColor>,
+        Result<(), FloodFillError<core::convert::Infallible>>,
+    ) {
+        let mut display = MockDisplay::from_pattern(pattern);
+        let mut stack = [Segment::default(); 64];
+
+        let result = flood_fill_with_stack(
+            &mut display,
+            seed,
+            BinaryColor::On,
+            &mut stack[..stack_size],
+        );
+
+        (display, result)
+    }
+
+    #[test]
+    fn fill_enclosed_region() {
+        #[rustfmt::skip]
+        let (display, result) = fill_pattern(&[
+            "#########.",
+            "#...#...#.",
+            "#.#.#.#.#.",
+            "#.#...#.#.",
+            "#.#####.#.",
+            "#.......#.",
+            "#########.",
+        ], Point::new(1, 1), 64);
+
+        assert_eq!(result, Ok(()));
+
+        #[rustfmt::skip]
+        assert_eq!(
+            display,
+            MockDisplay::from_pattern(&[
+                "#########.",
+                "#########.",
+                "#########.",
+                "#########.",
+                "#########.",
+                "#########.",
+                "#########.",
+            ])
+        );
+    }
+
+    #[test]
+    fn fill_leaks_around_obstacles() {
+        #[rustfmt::skip]
+        let (display, result) = fill_pattern(&[
+            "#.....#",
+            "#.###.#",
+            "#.#.#.#",
+            "#..##.#",
+            "#####.#",
+        ], Point::new(5, 4), 64);
+
+        assert_eq!(result, Ok(()));
+
+        #[rustfmt::skip]
+        assert_eq!(
+            display,
+            MockDisplay::from_pattern(&[
+                "#######",
+                "#######",
+                "###.###",
+                "#######",
+                "#######",
+            ])
+        );
+    }
+
+    #[test]
+    fn fill_unknown_color_is_noop() {
+        let (display, result) = fill_pattern(&["#.", " ."], Point::new(0, 1), 64);
+
+        assert_eq!(result, Ok(()));
+        assert_eq!(display, MockDisplay::from_pattern(&["#.", " ."]));
+    }
+
+    #[test]
+    fn fill_same_color_is_noop() {
+        let (display, result) = fill_pattern(&["#.", "##"], Point::new(0, 0), 0);
+
+        assert_eq!(result, Ok(()));
+        assert_eq!(display, MockDisplay::from_pattern(&["#.", "##"]));
+    }
+
+    #[test]
+    fn fill_is_bounded_by_display() {
+        let mut display = MockDisplay::new();
+        display.clear(Gray8::new(0x11)).unwrap();
+
+        flood_fill(&mut display, Point::new(63, 63), Gray8::new(0x22)).unwrap();
+
+        let mut expected = MockDisplay::new();
+        expected.clear(Gray8::new(0x22)).unwrap();
+
+        assert_eq!(display, expected);
+    }
+
+    #[test]
+    fn stack_overflow() {
+        #[rustfmt::skip]
+        let pattern = [
+            "..........",
+            ".#.#.#.#..",
+            ".#.#.#.#..",
+            ".#.#.#.#..",
+            "..........",
+        ];
+
+        let (_, result) = fill_pattern(&pattern, Point::new(0, 2), 1);
+        assert_eq!(result, Err(FloodFillError::StackOverflow));
+
+        let (display, result) = fill_pattern(&pattern, Point::new(0, 2), 64);
+        assert_eq!(result, Ok(()));
+        assert!(display.get_pixel(Point::new(8, 2)) == Some(BinaryColor::On));
+    }
+}
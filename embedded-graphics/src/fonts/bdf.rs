@@ -0,0 +1,826 @@
+use crate::{
+    fonts::{BitmapFont, BitsPerPixel, Glyph},
+    geometry::{Point, Size},
+    primitives::Rectangle,
+};
+use core::{
+    convert::TryFrom,
+    str::{FromStr, SplitWhitespace},
+};
+
+/// BDF font.
+///
+/// `BdfFont` parses fonts in the [Glyph Bitmap Distribution Format] (BDF), which is used by many
+/// existing bitmap fonts, like the X11 core fonts and GNU Unifont. Parsing doesn't allocate any
+/// memory, the parsed font only references the source text.
+///
+/// A BDF font can't be used to draw text directly. It needs to be converted into a
+/// [`BitmapFont`] by using the [`load`] method first. The glyphs and bitmap data of the
+/// `BitmapFont` are stored in buffers that are provided by the caller. The required buffer sizes
+/// can be queried by using the [`glyph_count`] and [`data_size`] methods.
+///
+/// Glyphs without an encoding, which are marked by an `ENCODING -1` line in the BDF file, are
+/// ignored.
+///
+/// # Examples
+///
+/// ```rust
+/// use embedded_graphics::{
+///     fonts::{BdfFont, Glyph, Text},
+///     pixelcolor::BinaryColor,
+///     prelude::*,
+///     style::TextStyle,
+/// };
+/// # use embedded_graphics::mock_display::MockDisplay;
+/// # let mut display: MockDisplay<BinaryColor> = MockDisplay::default();
+///
+/// // BDF source, which could also be read from a file at runtime.
+/// let source = "\
+///     STARTFONT 2.1
+///     FONT -misc-example-medium-r-normal--4-40-75-75-c-40-iso10646-1
+///     SIZE 4 75 75
+///     FONTBOUNDINGBOX 4 4 0 -1
+///     CHARS 1
+///     STARTCHAR T
+///     ENCODING 84
+///     SWIDTH 1000 0
+///     DWIDTH 4 0
+///     BBX 3 3 0 0
+///     BITMAP
+///     E0
+///     40
+///     40
+///     ENDCHAR
+///     ENDFONT
+/// ";
+///
+/// let bdf = BdfFont::parse(source).unwrap();
+///
+/// let mut glyphs = [Glyph::default(); 1];
+/// let mut data = [0u8; 2];
+/// assert_eq!(bdf.glyph_count(), glyphs.len());
+/// assert_eq!(bdf.data_size(), data.len());
+///
+/// let font = bdf.load(&mut glyphs, &mut data).unwrap();
+///
+/// Text::new("TT", Point::zero())
+///     .into_styled(TextStyle::new(font, BinaryColor::On))
+///     .draw(&mut display)?;
+/// # Ok::<(), core::convert::Infallible>(())
+/// ```
+///
+/// [Glyph Bitmap Distribution Format]: https://en.wikipedia.org/wiki/Glyph_Bitmap_Distribution_Format
+/// [`BitmapFont`]: struct.BitmapFont.html
+/// [`load`]: #method.load
+/// [`glyph_count`]: #method.glyph_count
+/// [`data_size`]: #method.data_size
+#[derive(Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Debug)]
+pub struct BdfFont<'a> {
+    /// Bounding box which contains all glyphs.
+    ///
+    /// The bounding box uses the same coordinate system as the bounding boxes of [`Glyph`]s.
+    ///
+    /// [`Glyph`]: struct.Glyph.html
+    pub bounding_box: Rectangle,
+
+    /// Number of rows above the baseline, including the baseline row.
+    ///
+    /// The value is read from the `FONT_ASCENT` property. The font bounding box is used if the
+    /// property is missing.
+    pub ascent: u32,
+
+    /// Number of rows below the baseline.
+    ///
+    /// The value is read from the `FONT_DESCENT` property. The font bounding box is used if the
+    /// property is missing.
+    pub descent: u32,
+
+    /// Character which is drawn for characters that aren't included in the font.
+    ///
+    /// The value is read from the `DEFAULT_CHAR` property.
+    pub default_character: Option<char>,
+
+    /// Remaining source, starting after the font header.
+    glyphs: Lines<'a>,
+
+    /// Default advance for glyphs without a `DWIDTH` entry.
+    default_advance: Option<u32>,
+
+    /// Number of glyphs.
+    glyph_count: usize,
+
+    /// Number of pixels in all glyph bitmaps.
+    pixel_count: usize,
+}
+
+impl<'a> BdfFont<'a> {
+    /// Parses a BDF font.
+    ///
+    /// The whole font is validated while it is parsed, which ensures that the glyphs and the
+    /// font can be loaded without errors later on.
+    pub fn parse(source: &'a str) -> Result<Self, BdfError> {
+        let mut lines = Lines::new(source);
+
+        match lines.next() {
+            Some((_, line)) if keyword(line) == "STARTFONT" => {}
+            _ => return Err(BdfError::NotBdf),
+        }
+
+        let mut bounding_box = None;
+        let mut ascent = None;
+        let mut descent = None;
+        let mut default_character = None;
+        let mut default_advance = None;
+
+        loop {
+            let glyphs = lines;
+            let (number, line) = lines.next().ok_or(BdfError::UnexpectedEnd)?;
+            let mut values = Values::new(line, number);
+
+            match values.keyword() {
+                "FONTBOUNDINGBOX" => bounding_box = Some(values.bounding_box()?),
+                "FONT_ASCENT" => ascent = Some(values.value()?),
+                "FONT_DESCENT" => descent = Some(values.value()?),
+                "DEFAULT_CHAR" => default_character = values.encoding()?,
+                "DWIDTH" => default_advance = Some(values.value()?),
+                "STARTCHAR" | "ENDFONT" => {
+                    let (bounding_box, raw_ascent, raw_descent) =
+                        bounding_box.ok_or(BdfError::MissingValue("FONTBOUNDINGBOX"))?;
+
+                    let mut font = Self {
+                        bounding_box,
+                        ascent: ascent.unwrap_or(raw_ascent),
+                        descent: descent.unwrap_or(raw_descent),
+                        default_character,
+                        glyphs,
+                        default_advance,
+                        glyph_count: 0,
+                        pixel_count: 0,
+                    };
+
+                    let mut glyphs = font.glyphs();
+                    while let Some(glyph) = glyphs.next_glyph()? {
+                        font.glyph_count += 1;
+                        font.pixel_count += glyph.bounding_box.size.width as usize
+                            * glyph.bounding_box.size.height as usize;
+                    }
+
+                    return Ok(font);
+                }
+                _ => {}
+            }
+        }
+    }
+
+    /// Returns an iterator over all glyphs in the font.
+    ///
+    /// The glyphs are returned in the same order as they are stored in the BDF file.
+    pub fn glyphs(&self) -> BdfGlyphs<'a> {
+        BdfGlyphs {
+            lines: self.glyphs,
+            default_advance: self.default_advance,
+        }
+    }
+
+    /// Returns the number of glyphs in the font.
+    ///
+    /// The glyph buffer that is passed to [`load`] must have at least this length.
+    ///
+    /// [`load`]: #method.load
+    pub fn glyph_count(&self) -> usize {
+        self.glyph_count
+    }
+
+    /// Returns the size of the bitmap data in bytes.
+    ///
+    /// The data buffer that is passed to [`load`] must have at least this length.
+    ///
+    /// [`load`]: #method.load
+    pub fn data_size(&self) -> usize {
+        (self.pixel_count + 7) / 8
+    }
+
+    /// Loads the font into a bitmap font.
+    ///
+    /// The glyphs and bitmap data of the font are stored in the given buffers. The buffers must be
+    /// large enough to store the whole font, otherwise `BdfError::BufferTooSmall` is returned.
+    ///
+    /// If the font doesn't define a default character `?` is used as the replacement character.
+    pub fn load<'b>(
+        &self,
+        glyphs: &'b mut [Glyph],
+        data: &'b mut [u8],
+    ) -> Result<BitmapFont<'b>, BdfError> {
+        if glyphs.len() < self.glyph_count() || data.len() < self.data_size() {
+            return Err(BdfError::BufferTooSmall);
+        }
+
+        let glyphs = &mut glyphs[0..self.glyph_count()];
+        let data = &mut data[0..self.data_size()];
+
+        for byte in data.iter_mut() {
+            *byte = 0;
+        }
+
+        let mut data_offset = 0;
+        for (glyph, bdf_glyph) in glyphs.iter_mut().zip(self.glyphs()) {
+            let size = bdf_glyph.bounding_box.size;
+
+            *glyph = Glyph::new(
+                bdf_glyph.character,
+                bdf_glyph.bounding_box,
+                bdf_glyph.advance,
+                data_offset,
+            );
+
+            // The bitmap rows are decoded in a single pass, because looking up each pixel with
+            // `BdfGlyph::pixel` would scan the bitmap again for every row.
+            for (y, row) in (0..size.height).zip(bdf_glyph.bitmap.lines()) {
+                for x in 0..size.width {
+                    if bdf_glyph.row_pixel(row, x) {
+                        let bit_index = (data_offset + y * size.width + x) as usize;
+                        data[bit_index / 8] |= 0x80 >> (bit_index % 8);
+                    }
+                }
+            }
+
+            data_offset += size.width * size.height;
+        }
+
+        glyphs.sort_unstable_by_key(|glyph| glyph.character);
+
+        Ok(BitmapFont {
+            glyphs,
+            data,
+            bits_per_pixel: BitsPerPixel::One,
+            ascent: self.ascent,
+            descent: self.descent,
+            replacement_character: self.default_character.unwrap_or('?'),
+            kerning: &[],
+        })
+    }
+}
+
+/// Glyph in a BDF font.
+#[derive(Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Debug)]
+pub struct BdfGlyph<'a> {
+    /// Character.
+    pub character: char,
+
+    /// Bounding box of the glyph bitmap, relative to the glyph origin.
+    ///
+    /// See [`Glyph::bounding_box`] for more information.
+    ///
+    /// [`Glyph::bounding_box`]: struct.Glyph.html#structfield.bounding_box
+    pub bounding_box: Rectangle,
+
+    /// Horizontal distance between the origins of this glyph and the next glyph.
+    pub advance: u32,
+
+    /// Hex encoded bitmap rows.
+    bitmap: &'a str,
+}
+
+impl BdfGlyph<'_> {
+    /// Returns the value of a pixel in the glyph bitmap.
+    ///
+    /// The coordinates are relative to the top left corner of the bitmap. Pixels outside the
+    /// bitmap are always off.
+    pub fn pixel(&self, x: u32, y: u32) -> bool {
+        self.bitmap
+            .lines()
+            .nth(y as usize)
+            .map_or(false, |row| self.row_pixel(row, x))
+    }
+
+    /// Returns the value of a pixel in a hex encoded bitmap row.
+    fn row_pixel(&self, row: &str, x: u32) -> bool {
+        if x >= self.bounding_box.size.width {
+            return false;
+        }
+
+        row.trim()
+            .as_bytes()
+            .get(x as usize / 4)
+            .and_then(|digit| char::from(*digit).to_digit(16))
+            .map_or(false, |nibble| nibble & (0x8 >> (x % 4)) != 0)
+    }
+}
+
+/// Iterator over the glyphs in a BDF font.
+///
+/// See [`BdfFont::glyphs`] for more information.
+///
+/// [`BdfFont::glyphs`]: struct.BdfFont.html#method.glyphs
+#[derive(Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Debug)]
+pub struct BdfGlyphs<'a> {
+    lines: Lines<'a>,
+    default_advance: Option<u32>,
+}
+
+impl<'a> BdfGlyphs<'a> {
+    /// Parses the next encoded glyph.
+    fn next_glyph(&mut self) -> Result<Option<BdfGlyph<'a>>, BdfError> {
+        loop {
+            let (_, line) = self.lines.next().ok_or(BdfError::UnexpectedEnd)?;
+
+            match keyword(line) {
+                "STARTCHAR" => {
+                    if let Some(glyph) = self.parse_glyph()? {
+                        return Ok(Some(glyph));
+                    }
+                }
+                "ENDFONT" => return Ok(None),
+                _ => {}
+            }
+        }
+    }
+
+    /// Parses a glyph after the `STARTCHAR` line.
+    ///
+    /// Returns `None` if the glyph has no encoding.
+    fn parse_glyph(&mut self) -> Result<Option<BdfGlyph<'a>>, BdfError> {
+        let mut character = None;
+        let mut advance = self.default_advance;
+        let mut bounding_box = None;
+        let mut bitmap = None;
+
+        loop {
+            let (number, line) = self.lines.next().ok_or(BdfError::UnexpectedEnd)?;
+            let mut values = Values::new(line, number);
+
+            match values.keyword() {
+                "ENCODING" => character = values.encoding()?,
+                "DWIDTH" => advance = Some(values.value()?),
+                "BBX" => bounding_box = Some(values.bounding_box()?.0),
+                "BITMAP" => {
+                    let bounding_box: Rectangle =
+                        bounding_box.ok_or(BdfError::MissingValue("BBX"))?;
+                    bitmap = Some(self.parse_bitmap(bounding_box.size)?);
+                }
+                "ENDCHAR" => break,
+                _ => {}
+            }
+        }
+
+        let bounding_box = bounding_box.ok_or(BdfError::MissingValue("BBX"))?;
+        let advance = advance.ok_or(BdfError::MissingValue("DWIDTH"))?;
+        let bitmap = bitmap.ok_or(BdfError::MissingValue("BITMAP"))?;
+
+        Ok(character.map(|character| BdfGlyph {
+            character,
+            bounding_box,
+            advance,
+            bitmap,
+        }))
+    }
+
+    /// Validates the bitmap rows after the `BITMAP` line and returns them.
+    fn parse_bitmap(&mut self, size: Size) -> Result<&'a str, BdfError> {
+        let start = self.lines.offset;
+        let digits = (size.width as usize + 3) / 4;
+
+        for _ in 0..size.height {
+            let (number, row) = self.lines.next_row().ok_or(BdfError::UnexpectedEnd)?;
+
+            if row.len() < digits || !row.bytes().all(|digit| digit.is_ascii_hexdigit()) {
+                return Err(BdfError::InvalidLine(number));
+            }
+        }
+
+        Ok(&self.lines.source[start..self.lines.offset])
+    }
+}
+
+impl<'a> Iterator for BdfGlyphs<'a> {
+    type Item = BdfGlyph<'a>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        // The font was validated by `BdfFont::parse` and parsing can't fail at this point.
+        self.next_glyph().ok().and_then(|glyph| glyph)
+    }
+}
+
+/// BDF parse error.
+#[derive(Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Debug)]
+pub enum BdfError {
+    /// The source doesn't start with a `STARTFONT` line.
+    NotBdf,
+
+    /// The source ended before the end of the font.
+    UnexpectedEnd,
+
+    /// A line contains an invalid or missing value.
+    ///
+    /// The line number starts at 1 for the first line in the source.
+    InvalidLine(usize),
+
+    /// A required entry is missing.
+    ///
+    /// Contains the keyword of the missing entry, e.g. `"BBX"`.
+    MissingValue(&'static str),
+
+    /// A buffer passed to [`BdfFont::load`] is too small.
+    ///
+    /// [`BdfFont::load`]: struct.BdfFont.html#method.load
+    BufferTooSmall,
+}
+
+/// Returns the keyword of a line.
+fn keyword(line: &str) -> &str {
+    line.split_whitespace().next().unwrap_or("")
+}
+
+/// Iterator over the non empty lines in a BDF source.
+///
+/// Returns the line number and the line without leading and trailing whitespace.
+#[derive(Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Debug)]
+struct Lines<'a> {
+    source: &'a str,
+    offset: usize,
+    number: usize,
+}
+
+impl<'a> Lines<'a> {
+    const fn new(source: &'a str) -> Self {
+        Self {
+            source,
+            offset: 0,
+            number: 0,
+        }
+    }
+
+    /// Returns the next line, including empty lines.
+    fn next_row(&mut self) -> Option<(usize, &'a str)> {
+        if self.offset >= self.source.len() {
+            return None;
+        }
+
+        let remaining = &self.source[self.offset..];
+        let length = remaining.find('\n').map_or(remaining.len(), |end| end + 1);
+
+        self.offset += length;
+        self.number += 1;
+
+        Some((self.number, remaining[0..length].trim()))
+    }
+}
+
+impl<'a> Iterator for Lines<'a> {
+    type Item = (usize, &'a str);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        loop {
+            let (number, line) = self.next_row()?;
+
+            if !line.is_empty() {
+                return Some((number, line));
+            }
+        }
+    }
+}
+
+/// Whitespace separated values in a line.
+struct Values<'a> {
+    values: SplitWhitespace<'a>,
+    number: usize,
+}
+
+impl<'a> Values<'a> {
+    fn new(line: &'a str, number: usize) -> Self {
+        Self {
+            values: line.split_whitespace(),
+            number,
+        }
+    }
+
+    fn keyword(&mut self) -> &'a str {
+        self.values.next().unwrap_or("")
+    }
+
+    fn value<T: FromStr>(&mut self) -> Result<T, BdfError> {
+        self.values
+            .next()
+            .and_then(|value| value.parse().ok())
+            .ok_or(BdfError::InvalidLine(self.number))
+    }
+
+    /// Parses an encoding.
+    ///
+    /// Returns `None` for negative encodings, which are used for glyphs without an encoding.
+    fn encoding(&mut self) -> Result<Option<char>, BdfError> {
+        let encoding: i32 = self.value()?;
+
+        if encoding < 0 {
+            return Ok(None);
+        }
+
+        core::char::from_u32(encoding as u32)
+            .map(Some)
+            .ok_or(BdfError::InvalidLine(self.number))
+    }
+
+    /// Parses a bounding box.
+    ///
+    /// Returns the bounding box, relative to the glyph origin, and the number of rows above and
+    /// below the baseline.
+    fn bounding_box(&mut self) -> Result<(Rectangle, u32, u32), BdfError> {
+        let width: u32 = self.value()?;
+        let height: u32 = self.value()?;
+        let x: i32 = self.value()?;
+        let y: i32 = self.value()?;
+
+        let number = self.number;
+        let invalid = || BdfError::InvalidLine(number);
+
+        // BDF uses a Y axis that points up and the offset is the position of the bottom row.
+        let bottom = i32::try_from(height)
+            .ok()
+            .and_then(|height| y.checked_add(height))
+            .ok_or_else(invalid)?;
+        let top = bottom
+            .checked_sub(1)
+            .and_then(i32::checked_neg)
+            .ok_or_else(invalid)?;
+        let bounding_box = Rectangle::new(Point::new(x, top), Size::new(width, height));
+
+        let ascent = bottom.max(0) as u32;
+        let descent = y.checked_neg().ok_or_else(invalid)?.max(0) as u32;
+
+        Ok((bounding_box, ascent, descent))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        drawable::Drawable, fonts::Text, mock_display::MockDisplay, pixelcolor::BinaryColor,
+        style::TextStyle,
+    };
+
+    const FONT: &str = "STARTFONT 2.1
+COMMENT Test font
+FONT -test-fixed-medium-r-normal--5-50-75-75-c-40-iso10646-1
+SIZE 5 75 75
+FONTBOUNDINGBOX 3 5 0 -1
+STARTPROPERTIES 3
+FONT_ASCENT 4
+FONT_DESCENT 1
+DEFAULT_CHAR 63
+ENDPROPERTIES
+CHARS 4
+STARTCHAR question
+ENCODING 63
+SWIDTH 750 0
+DWIDTH 4 0
+BBX 3 4 0 0
+BITMAP
+E0
+20
+00
+40
+ENDCHAR
+STARTCHAR j
+ENCODING 106
+SWIDTH 750 0
+DWIDTH 4 0
+BBX 2 4 0 -1
+BITMAP
+40
+40
+40
+80
+ENDCHAR
+STARTCHAR unencoded
+ENCODING -1
+DWIDTH 4 0
+BBX 1 1 0 0
+BITMAP
+80
+ENDCHAR
+STARTCHAR A
+ENCODING 65
+SWIDTH 750 0
+DWIDTH 4 0
+BBX 3 3 0 0
+BITMAP
+40
+A0
+E0
+ENDCHAR
+ENDFONT
+";
+
+    #[test]
+    fn parse_header() {
+        let font = BdfFont::parse(FONT).unwrap();
+
+        assert_eq!(
+            font.bounding_box,
+            Rectangle::new(Point::new(0, -3), Size::new(3, 5))
+        );
+        assert_eq!(font.ascent, 4);
+        assert_eq!(font.descent, 1);
+        assert_eq!(font.default_character, Some('?'));
+        assert_eq!(font.glyph_count(), 3);
+        assert_eq!(font.data_size(), 4);
+    }
+
+    #[test]
+    fn header_fallbacks() {
+        let font = BdfFont::parse("STARTFONT 2.1\nFONTBOUNDINGBOX 8 16 0 -4\nENDFONT\n").unwrap();
+
+        assert_eq!(font.ascent, 12);
+        assert_eq!(font.descent, 4);
+        assert_eq!(font.default_character, None);
+        assert_eq!(font.glyph_count(), 0);
+        assert_eq!(font.data_size(), 0);
+    }
+
+    #[test]
+    fn parse_glyphs() {
+        let font = BdfFont::parse(FONT).unwrap();
+        let mut glyphs = font.glyphs();
+
+        let glyph = glyphs.next().unwrap();
+        assert_eq!(glyph.character, '?');
+        assert_eq!(glyph.advance, 4);
+        assert_eq!(
+            glyph.bounding_box,
+            Rectangle::new(Point::new(0, -3), Size::new(3, 4))
+        );
+        assert!(glyph.pixel(0, 0));
+        assert!(glyph.pixel(2, 1));
+        assert!(!glyph.pixel(0, 1));
+        assert!(!glyph.pixel(3, 0));
+        assert!(!glyph.pixel(0, 4));
+
+        let glyph = glyphs.next().unwrap();
+        assert_eq!(glyph.character, 'j');
+        assert_eq!(
+            glyph.bounding_box,
+            Rectangle::new(Point::new(0, -2), Size::new(2, 4))
+        );
+
+        assert_eq!(glyphs.next().unwrap().character, 'A');
+        assert_eq!(glyphs.next(), None);
+    }
+
+    #[test]
+    fn parse_errors() {
+        assert_eq!(BdfFont::parse(""), Err(BdfError::NotBdf));
+        assert_eq!(BdfFont::parse("FONT test\n"), Err(BdfError::NotBdf));
+        assert_eq!(
+            BdfFont::parse("STARTFONT 2.1\nFONTBOUNDINGBOX 8 16 0 -4\n"),
+            Err(BdfError::UnexpectedEnd)
+        );
+        assert_eq!(
+            BdfFont::parse("STARTFONT 2.1\nENDFONT\n"),
+            Err(BdfError::MissingValue("FONTBOUNDINGBOX"))
+        );
+        assert_eq!(
+            BdfFont::parse("STARTFONT 2.1\nFONTBOUNDINGBOX 8 16 x -4\nENDFONT\n"),
+            Err(BdfError::InvalidLine(2))
+        );
+
+        macro_rules! parse {
+            ($glyph:expr) => {
+                BdfFont::parse(concat!(
+                    "STARTFONT 2.1\nFONTBOUNDINGBOX 8 8 0 0\nSTARTCHAR A\nENCODING 65\n",
+                    $glyph
+                ))
+                .map(|_| ())
+            };
+        }
+
+        assert_eq!(
+            parse!("DWIDTH 2 0\nBBX 1 1 0 0\nBITMAP\n80\nENDCHAR\nENDFONT\n"),
+            Ok(())
+        );
+        assert_eq!(
+            parse!("BBX 1 1 0 0\nBITMAP\n80\nENDCHAR\nENDFONT\n"),
+            Err(BdfError::MissingValue("DWIDTH"))
+        );
+        assert_eq!(
+            parse!("DWIDTH 2 0\nBITMAP\n80\nENDCHAR\nENDFONT\n"),
+            Err(BdfError::MissingValue("BBX"))
+        );
+        assert_eq!(
+            parse!("DWIDTH 2 0\nBBX 1 1 0 0\nENDCHAR\nENDFONT\n"),
+            Err(BdfError::MissingValue("BITMAP"))
+        );
+        assert_eq!(
+            parse!("DWIDTH 2 0\nBBX 1 2 0 0\nBITMAP\n80\nXX\nENDCHAR\nENDFONT\n"),
+            Err(BdfError::InvalidLine(9))
+        );
+        assert_eq!(
+            parse!("DWIDTH 2 0\nBBX 1 1 0 0\nBITMAP\n80\n"),
+            Err(BdfError::UnexpectedEnd)
+        );
+        assert_eq!(
+            BdfFont::parse(
+                "STARTFONT 2.1\nFONTBOUNDINGBOX 8 8 0 0\nSTARTCHAR A\nENCODING 55296\nENDCHAR\n"
+            ),
+            Err(BdfError::InvalidLine(4))
+        );
+
+        // Offsets that don't fit into the coordinate range
+        assert_eq!(
+            parse!("DWIDTH 2 0\nBBX 1 2 0 2147483647\nBITMAP\n80\n80\nENDCHAR\nENDFONT\n"),
+            Err(BdfError::InvalidLine(6))
+        );
+        assert_eq!(
+            parse!("DWIDTH 2 0\nBBX 1 1 0 -2147483648\nBITMAP\n80\nENDCHAR\nENDFONT\n"),
+            Err(BdfError::InvalidLine(6))
+        );
+        assert_eq!(
+            BdfFont::parse("STARTFONT 2.1\nFONTBOUNDINGBOX 8 4294967295 0 0\nENDFONT\n"),
+            Err(BdfError::InvalidLine(2))
+        );
+    }
+
+    #[test]
+    fn load() {
+        let bdf = BdfFont::parse(FONT).unwrap();
+
+        let mut glyphs = [Glyph::default(); 2];
+        let mut data = [0u8; 4];
+        assert_eq!(
+            bdf.load(&mut glyphs, &mut data),
+            Err(BdfError::BufferTooSmall)
+        );
+
+        let mut glyphs = [Glyph::default(); 3];
+        let mut data = [0u8; 3];
+        assert_eq!(
+            bdf.load(&mut glyphs, &mut data),
+            Err(BdfError::BufferTooSmall)
+        );
+
+        let mut glyphs = [Glyph::default(); 4];
+        let mut data = [0xFFu8; 4];
+        let font = bdf.load(&mut glyphs, &mut data).unwrap();
+
+        assert_eq!(font.glyphs.len(), 3);
+        assert_eq!(
+            font.glyphs[0],
+            Glyph::new(
+                '?',
+                Rectangle::new(Point::new(0, -3), Size::new(3, 4)),
+                4,
+                0
+            )
+        );
+        assert_eq!(
+            font.glyphs[1],
+            Glyph::new(
+                'A',
+                Rectangle::new(Point::new(0, -2), Size::new(3, 3)),
+                4,
+                20
+            )
+        );
+        assert_eq!(
+            font.glyphs[2],
+            Glyph::new(
+                'j',
+                Rectangle::new(Point::new(0, -2), Size::new(2, 4)),
+                4,
+                12
+            )
+        );
+        assert_eq!(
+            font.data,
+            &[0b1110_0100, 0b0010_0101, 0b0110_0101, 0b0111_1000]
+        );
+        assert_eq!(font.ascent, 4);
+        assert_eq!(font.descent, 1);
+        assert_eq!(font.replacement_character, '?');
+    }
+
+    #[test]
+    fn draw_loaded_font() {
+        let bdf = BdfFont::parse(FONT).unwrap();
+        let mut glyphs = [Glyph::default(); 3];
+        let mut data = [0u8; 4];
+        let font = bdf.load(&mut glyphs, &mut data).unwrap();
+
+        let mut display = MockDisplay::new();
+        Text::new("Aj?x", Point::zero())
+            .into_styled(TextStyle::new(font, BinaryColor::On))
+            .draw(&mut display)
+            .unwrap();
+
+        assert_eq!(
+            display,
+            MockDisplay::from_pattern(&[
+                "        ### ###",
+                " #   #    #   #",
+                "# #  #         ",
+                "###  #   #   # ",
+                "    #          ",
+            ])
+        );
+    }
+}
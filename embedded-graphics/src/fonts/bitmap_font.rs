@@ -0,0 +1,560 @@
+use crate::{
+    fonts::{Font, GlyphMetrics},
+    geometry::Point,
+    primitives::Rectangle,
+    transform::Transform,
+};
+
+/// Bitmap font with glyphs of different sizes.
+///
+/// Unlike the [built-in fonts], a `BitmapFont` isn't a type with the font data stored in
+/// associated constants. Instead the glyph metrics and bitmaps are stored in slices, which makes it
+/// possible to load fonts at runtime, for example by using a [`BdfFont`] to parse a BDF file that
+/// was read from flash. Because all fields are public, a `BitmapFont` can also be declared as a
+/// constant, which allows build scripts or macros to convert fonts at compile time.
+///
+/// Each [`Glyph`] defines its own bounding box and advance width. The bounding boxes are relative
+/// to the glyph origin, which is located on the left edge of the character cell in the row that
+/// contains the alphabetic baseline. Glyph bitmaps are stored in `data` as rows of 1, 2, 4 or 8
+/// bits per pixel, without any padding between rows or glyphs. The most significant bits of each
+/// byte are the leftmost pixel. Fonts with more than 1 bit per pixel store the coverage of each
+/// pixel and can be drawn with smooth edges by using [`antialiased`].
+///
+/// The distance between pairs of characters can be adjusted by an optional table of
+/// [`KerningPair`]s.
+///
+/// # Examples
+///
+/// ```rust
+/// use embedded_graphics::{
+///     fonts::{BitmapFont, BitsPerPixel, Glyph, Text},
+///     pixelcolor::BinaryColor,
+///     prelude::*,
+///     primitives::Rectangle,
+///     style::TextStyle,
+/// };
+/// # use embedded_graphics::mock_display::MockDisplay;
+/// # let mut display: MockDisplay<BinaryColor> = MockDisplay::default();
+///
+/// // A font with a single 3x3 glyph, which is drawn as a hollow square.
+/// const GLYPHS: &[Glyph] = &[Glyph::new(
+///     'o',
+///     Rectangle::new(Point::new(0, -2), Size::new(3, 3)),
+///     4,
+///     0,
+/// )];
+/// const DATA: &[u8] = &[0b1111_0111, 0b1000_0000];
+///
+/// const FONT: BitmapFont = BitmapFont {
+///     glyphs: GLYPHS,
+///     data: DATA,
+///     bits_per_pixel: BitsPerPixel::One,
+///     ascent: 3,
+///     descent: 0,
+///     replacement_character: 'o',
+///     kerning: &[],
+/// };
+///
+/// Text::new("ooo", Point::new(10, 20))
+///     .into_styled(TextStyle::new(FONT, BinaryColor::On))
+///     .draw(&mut display)?;
+/// # Ok::<(), core::convert::Infallible>(())
+/// ```
+///
+/// [built-in fonts]: index.html#built-in-fonts
+/// [`BdfFont`]: struct.BdfFont.html
+/// [`Glyph`]: struct.Glyph.html
+/// [`KerningPair`]: struct.KerningPair.html
+/// [`antialiased`]: ../style/struct.Styled.html#method.antialiased
+#[derive(Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Debug)]
+pub struct BitmapFont<'a> {
+    /// Glyphs sorted by character.
+    pub glyphs: &'a [Glyph],
+
+    /// Glyph bitmap data.
+    pub data: &'a [u8],
+
+    /// Number of bits per pixel in the bitmap data.
+    pub bits_per_pixel: BitsPerPixel,
+
+    /// Number of rows above the baseline, including the baseline row.
+    pub ascent: u32,
+
+    /// Number of rows below the baseline.
+    pub descent: u32,
+
+    /// Character which is drawn instead of characters that aren't included in the font.
+    pub replacement_character: char,
+
+    /// Kerning pairs sorted by the left and right character.
+    pub kerning: &'a [KerningPair],
+}
+
+impl<'a> BitmapFont<'a> {
+    /// Returns the glyph for a character.
+    ///
+    /// The glyph of the replacement character is returned if the font doesn't contain a glyph for
+    /// `c`. `None` is returned if the replacement character is also missing.
+    pub fn glyph(&self, c: char) -> Option<&'a Glyph> {
+        self.find_glyph(c)
+            .or_else(|| self.find_glyph(self.replacement_character))
+    }
+
+    fn find_glyph(&self, c: char) -> Option<&'a Glyph> {
+        let glyphs = self.glyphs;
+
+        glyphs
+            .binary_search_by_key(&c, |glyph| glyph.character)
+            .ok()
+            .map(|index| &glyphs[index])
+    }
+}
+
+impl Font for BitmapFont<'_> {
+    fn line_height(&self) -> u32 {
+        self.ascent + self.descent
+    }
+
+    fn baseline(&self) -> u32 {
+        self.ascent.saturating_sub(1)
+    }
+
+    fn glyph_metrics(&self, c: char) -> GlyphMetrics {
+        self.glyph(c).map_or(GlyphMetrics::default(), |glyph| {
+            let origin = Point::new(0, self.baseline() as i32);
+
+            GlyphMetrics::new(glyph.advance, glyph.bounding_box.translate(origin))
+        })
+    }
+
+    fn kerning(&self, left: char, right: char) -> i32 {
+        self.kerning
+            .binary_search_by_key(&(left, right), |pair| (pair.left, pair.right))
+            .ok()
+            .map_or(0, |index| self.kerning[index].adjustment)
+    }
+
+    fn glyph_pixel(&self, c: char, x: u32, y: u32) -> bool {
+        self.glyph_coverage(c, x, y) >= 128
+    }
+
+    fn glyph_coverage(&self, c: char, x: u32, y: u32) -> u8 {
+        let glyph = match self.glyph(c) {
+            Some(glyph) => glyph,
+            None => return 0,
+        };
+
+        let size = glyph.bounding_box.size;
+        if x >= size.width || y >= size.height {
+            return 0;
+        }
+
+        let bits = self.bits_per_pixel.bits();
+        let bit_index = glyph.data_offset as usize + ((y * size.width + x) * bits) as usize;
+        let max_value = (1u16 << bits) - 1;
+
+        self.data.get(bit_index / 8).map_or(0, |byte| {
+            let shift = 8 - bits as usize - bit_index % 8;
+            let value = u16::from(*byte >> shift) & max_value;
+
+            (value * 255 / max_value) as u8
+        })
+    }
+}
+
+/// Number of bits per pixel in the bitmap data of a bitmap font.
+///
+/// Fonts with more than 1 bit per pixel store the coverage of each pixel, which is used to draw
+/// anti-aliased text.
+#[derive(Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Debug)]
+pub enum BitsPerPixel {
+    /// 1 bit per pixel.
+    One,
+
+    /// 2 bits per pixel.
+    Two,
+
+    /// 4 bits per pixel.
+    Four,
+
+    /// 8 bits per pixel.
+    Eight,
+}
+
+impl BitsPerPixel {
+    /// Returns the number of bits.
+    pub fn bits(self) -> u32 {
+        match self {
+            BitsPerPixel::One => 1,
+            BitsPerPixel::Two => 2,
+            BitsPerPixel::Four => 4,
+            BitsPerPixel::Eight => 8,
+        }
+    }
+}
+
+/// Glyph in a bitmap font.
+///
+/// See the [`BitmapFont`] documentation for more information.
+///
+/// [`BitmapFont`]: struct.BitmapFont.html
+#[derive(Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Debug, Default)]
+pub struct Glyph {
+    /// Character.
+    pub character: char,
+
+    /// Bounding box of the glyph bitmap, relative to the glyph origin.
+    ///
+    /// The Y axis points down and the row with `y == 0` contains the alphabetic baseline.
+    pub bounding_box: Rectangle,
+
+    /// Horizontal distance between the origins of this glyph and the next glyph.
+    pub advance: u32,
+
+    /// Offset of the first pixel of the glyph bitmap in the font data, in bits.
+    ///
+    /// The offset must be a multiple of the number of bits per pixel.
+    pub data_offset: u32,
+}
+
+impl Glyph {
+    /// Creates a new glyph.
+    pub const fn new(
+        character: char,
+        bounding_box: Rectangle,
+        advance: u32,
+        data_offset: u32,
+    ) -> Self {
+        Self {
+            character,
+            bounding_box,
+            advance,
+            data_offset,
+        }
+    }
+}
+
+/// Kerning pair.
+///
+/// The adjustment is added to the distance between the `left` and `right` characters if they
+/// appear next to each other in a text.
+#[derive(Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Debug, Default)]
+pub struct KerningPair {
+    /// Left character.
+    pub left: char,
+
+    /// Right character.
+    pub right: char,
+
+    /// Adjustment of the distance between the characters.
+    ///
+    /// Negative values move the characters closer together.
+    pub adjustment: i32,
+}
+
+impl KerningPair {
+    /// Creates a new kerning pair.
+    pub const fn new(left: char, right: char, adjustment: i32) -> Self {
+        Self {
+            left,
+            right,
+            adjustment,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        drawable::Drawable,
+        fonts::Text,
+        geometry::{Dimensions, Size},
+        mock_display::MockDisplay,
+        pixelcolor::{BinaryColor, Gray8, GrayColor},
+        style::TextStyleBuilder,
+        DrawTarget,
+    };
+
+    const GLYPHS: &[Glyph] = &[
+        // Replacement character: single pixel at the top
+        Glyph::new(
+            '?',
+            Rectangle::new(Point::new(0, -2), Size::new(1, 1)),
+            2,
+            0,
+        ),
+        // 2x2 block with the bottom row on the baseline
+        Glyph::new(
+            'a',
+            Rectangle::new(Point::new(0, -1), Size::new(2, 2)),
+            3,
+            1,
+        ),
+        // 1x3 vertical line with a descender
+        Glyph::new(
+            'j',
+            Rectangle::new(Point::new(1, -1), Size::new(1, 3)),
+            3,
+            5,
+        ),
+    ];
+
+    const FONT: BitmapFont = BitmapFont {
+        glyphs: GLYPHS,
+        data: &[0b1111_1111],
+        bits_per_pixel: BitsPerPixel::One,
+        ascent: 3,
+        descent: 1,
+        replacement_character: '?',
+        kerning: &[],
+    };
+
+    #[test]
+    fn glyph_lookup() {
+        assert_eq!(FONT.glyph('?'), Some(&GLYPHS[0]));
+        assert_eq!(FONT.glyph('a'), Some(&GLYPHS[1]));
+        assert_eq!(FONT.glyph('j'), Some(&GLYPHS[2]));
+        assert_eq!(FONT.glyph('x'), Some(&GLYPHS[0]));
+
+        let font = BitmapFont {
+            replacement_character: 'x',
+            ..FONT
+        };
+        assert_eq!(font.glyph('x'), None);
+        assert_eq!(font.glyph_metrics('x'), GlyphMetrics::default());
+        assert!(!font.glyph_pixel('x', 0, 0));
+    }
+
+    #[test]
+    fn metrics() {
+        assert_eq!(FONT.line_height(), 4);
+        assert_eq!(FONT.baseline(), 2);
+        assert_eq!(FONT.character_spacing(), 0);
+        assert_eq!(
+            FONT.glyph_metrics('?'),
+            GlyphMetrics::new(2, Rectangle::new(Point::new(0, 0), Size::new(1, 1)))
+        );
+        assert_eq!(
+            FONT.glyph_metrics('a'),
+            GlyphMetrics::new(3, Rectangle::new(Point::new(0, 1), Size::new(2, 2)))
+        );
+        assert_eq!(
+            FONT.glyph_metrics('j'),
+            GlyphMetrics::new(3, Rectangle::new(Point::new(1, 1), Size::new(1, 3)))
+        );
+    }
+
+    #[test]
+    fn kerning() {
+        let kerning = [
+            KerningPair::new('a', 'j', -2),
+            KerningPair::new('j', 'a', 1),
+        ];
+        let font = BitmapFont {
+            kerning: &kerning,
+            ..FONT
+        };
+
+        assert_eq!(font.kerning('a', 'j'), -2);
+        assert_eq!(font.kerning('j', 'a'), 1);
+        assert_eq!(font.kerning('a', 'a'), 0);
+
+        let style = TextStyleBuilder::new(font)
+            .text_color(BinaryColor::On)
+            .build();
+        let text = Text::new("aja", Point::zero()).into_styled(style);
+
+        let mut display = MockDisplay::new();
+        text.draw(&mut display).unwrap();
+
+        assert_eq!(
+            display,
+            MockDisplay::from_pattern(&[
+                "       ", //
+                "###  ##", //
+                "###  ##", //
+                "  #    ", //
+            ])
+        );
+        assert_eq!(
+            text.bounding_box(),
+            Rectangle::new(Point::zero(), Size::new(3 - 2 + 3 + 1 + 3, 4))
+        );
+    }
+
+    #[test]
+    fn glyph_outside_of_cell() {
+        // Glyph with a negative left side bearing which extends below the line.
+        let glyphs = [Glyph::new(
+            'f',
+            Rectangle::new(Point::new(-1, -1), Size::new(3, 4)),
+            2,
+            0,
+        )];
+        let font = BitmapFont {
+            glyphs: &glyphs,
+            data: &[0b1111_1111, 0b1111_0000],
+            ..FONT
+        };
+
+        let style = TextStyleBuilder::new(font)
+            .text_color(BinaryColor::On)
+            .background_color(BinaryColor::Off)
+            .build();
+        let text = Text::new("f", Point::new(1, 0)).into_styled(style);
+
+        let mut display = MockDisplay::new();
+        text.draw(&mut display).unwrap();
+
+        assert_eq!(
+            display,
+            MockDisplay::from_pattern(&[
+                " ..", //
+                "###", //
+                "###", //
+                "###", //
+                "###", //
+            ])
+        );
+        assert_eq!(
+            text.bounding_box(),
+            Rectangle::new(Point::new(0, 0), Size::new(3, 5))
+        );
+    }
+
+    #[test]
+    fn draw_text() {
+        let style = TextStyleBuilder::new(FONT)
+            .text_color(BinaryColor::On)
+            .build();
+
+        let mut display = MockDisplay::new();
+        Text::new("aj?b", Point::new(1, 0))
+            .into_styled(style)
+            .draw(&mut display)
+            .unwrap();
+
+        assert_eq!(
+            display,
+            MockDisplay::from_pattern(&[
+                "       # #", //
+                " ##  #    ", //
+                " ##  #    ", //
+                "     #    ", //
+            ])
+        );
+    }
+
+    #[test]
+    fn draw_text_with_background() {
+        let style = TextStyleBuilder::new(FONT)
+            .text_color(BinaryColor::On)
+            .background_color(BinaryColor::Off)
+            .build();
+
+        let mut display = MockDisplay::new();
+        Text::new("a?", Point::zero())
+            .into_styled(style)
+            .draw(&mut display)
+            .unwrap();
+
+        assert_eq!(
+            display,
+            MockDisplay::from_pattern(&[
+                "...#.", //
+                "##...", //
+                "##...", //
+                ".....", //
+            ])
+        );
+    }
+
+    /// Font with a single 4x1 glyph with increasing coverage.
+    const ANTIALIASED_FONT: BitmapFont = BitmapFont {
+        glyphs: &[Glyph::new(
+            'a',
+            Rectangle::new(Point::new(0, 0), Size::new(4, 1)),
+            4,
+            0,
+        )],
+        data: &[0b00_01_10_11],
+        bits_per_pixel: BitsPerPixel::Two,
+        ascent: 1,
+        descent: 0,
+        replacement_character: 'a',
+        kerning: &[],
+    };
+
+    #[test]
+    fn coverage() {
+        let coverage = |font: &BitmapFont| {
+            [
+                font.glyph_coverage('a', 0, 0),
+                font.glyph_coverage('a', 1, 0),
+                font.glyph_coverage('a', 2, 0),
+                font.glyph_coverage('a', 3, 0),
+            ]
+        };
+
+        assert_eq!(coverage(&ANTIALIASED_FONT), [0, 85, 170, 255]);
+        assert!(!ANTIALIASED_FONT.glyph_pixel('a', 1, 0));
+        assert!(ANTIALIASED_FONT.glyph_pixel('a', 2, 0));
+
+        let font = BitmapFont {
+            data: &[0x05, 0xAF],
+            bits_per_pixel: BitsPerPixel::Four,
+            ..ANTIALIASED_FONT
+        };
+        assert_eq!(coverage(&font), [0, 85, 170, 255]);
+
+        let font = BitmapFont {
+            data: &[0x00, 0x40, 0x80, 0xFF],
+            bits_per_pixel: BitsPerPixel::Eight,
+            ..ANTIALIASED_FONT
+        };
+        assert_eq!(coverage(&font), [0, 0x40, 0x80, 0xFF]);
+
+        let font = BitmapFont {
+            data: &[0b0101_0000],
+            bits_per_pixel: BitsPerPixel::One,
+            ..ANTIALIASED_FONT
+        };
+        assert_eq!(coverage(&font), [0, 255, 0, 255]);
+    }
+
+    #[test]
+    fn antialiased_with_background() {
+        let style = TextStyleBuilder::new(ANTIALIASED_FONT)
+            .text_color(Gray8::WHITE)
+            .background_color(Gray8::BLACK)
+            .build();
+
+        let mut display = MockDisplay::new();
+        display
+            .draw_iter(
+                Text::new("a", Point::zero())
+                    .into_styled(style)
+                    .antialiased(),
+            )
+            .unwrap();
+
+        assert_eq!(display, MockDisplay::from_pattern(&["05AF"]));
+    }
+
+    #[test]
+    fn antialiased_without_background() {
+        let style = TextStyleBuilder::new(ANTIALIASED_FONT)
+            .text_color(Gray8::WHITE)
+            .build();
+        let text = Text::new("a", Point::zero()).into_styled(style);
+
+        let mut display = MockDisplay::new();
+        display.draw_iter(text.antialiased()).unwrap();
+        assert_eq!(display, MockDisplay::from_pattern(&["  FF"]));
+
+        let mut display = MockDisplay::from_pattern(&["3333"]);
+        text.draw_antialiased(&mut display).unwrap();
+        assert_eq!(display, MockDisplay::from_pattern(&["37BF"]));
+    }
+}
@@ -1,4 +1,23 @@
-use crate::{fonts::Font, geometry::Size};
+use crate::{
+    fonts::{GlyphMapping, GlyphRange, ImageFont},
+    geometry::Size,
+};
+
+/// Glyph mapping for the characters in the font image.
+const GLYPH_MAPPING: GlyphMapping = GlyphMapping::new(
+    &[
+        GlyphRange::new(' ', '~', 0),
+        GlyphRange::new('¡', 'ÿ', 95),
+        GlyphRange::new('Ā', 'ſ', 190),
+        GlyphRange::new('Ά', 'Ά', 318),
+        GlyphRange::new('Έ', 'Ί', 319),
+        GlyphRange::new('Ό', 'Ό', 322),
+        GlyphRange::new('Ύ', 'Ρ', 323),
+        GlyphRange::new('Σ', 'ώ', 343),
+        GlyphRange::new('Ѐ', 'џ', 387),
+    ],
+    '?',
+);
 
 /// 12x16 pixel monospace font.
 ///
@@ -11,24 +30,15 @@ use crate::{fonts::Font, geometry::Size};
 pub struct Font12x16;
 
 /// Config for 12x16 font
-impl Font for Font12x16 {
+impl ImageFont for Font12x16 {
     const FONT_IMAGE: &'static [u8] = include_bytes!("../../data/font12x16_1bpp.raw");
     const FONT_IMAGE_WIDTH: u32 = 480;
 
     const CHARACTER_SIZE: Size = Size::new(12, 16);
+    const BASELINE: u32 = 13;
 
     fn char_offset(c: char) -> u32 {
-        let fallback = '?' as u32 - ' ' as u32;
-        if c < ' ' {
-            return fallback;
-        }
-        if c <= '~' {
-            return c as u32 - ' ' as u32;
-        }
-        if c < '¡' || c > 'ÿ' {
-            return fallback;
-        }
-        c as u32 - ' ' as u32 - 34
+        GLYPH_MAPPING.index(c)
     }
 }
 
@@ -37,7 +47,7 @@ mod tests {
     use super::*;
     use crate::{
         drawable::Drawable,
-        fonts::{Font, Text},
+        fonts::Text,
         geometry::{Dimensions, Point, Size},
         mock_display::MockDisplay,
         pixelcolor::BinaryColor,
@@ -214,6 +224,38 @@ mod tests {
         Ok(())
     }
 
+    #[test]
+    fn correct_extended_glyphs() -> Result<(), core::convert::Infallible> {
+        let mut display = MockDisplay::new();
+        Text::new("Āџ", Point::zero())
+            .into_styled(TextStyle::new(Font12x16, BinaryColor::On))
+            .draw(&mut display)?;
+
+        assert_eq!(
+            display,
+            MockDisplay::from_pattern(&[
+                "  ######              ",
+                "  ######              ",
+                "                      ",
+                "                      ",
+                "  ######    ##      ##",
+                "  ######    ##      ##",
+                "##      ##  ##      ##",
+                "##      ##  ##      ##",
+                "##########  ##      ##",
+                "##########  ##      ##",
+                "##      ##  ##      ##",
+                "##      ##  ##      ##",
+                "##      ##  ##########",
+                "##      ##  ##########",
+                "                ##    ",
+                "                ##    ",
+            ])
+        );
+
+        Ok(())
+    }
+
     #[test]
     fn dont_panic() -> Result<(), core::convert::Infallible> {
         let two_question_marks = MockDisplay::from_pattern(&[
@@ -250,7 +292,7 @@ mod tests {
         assert_eq!(display, two_question_marks);
 
         let mut display = MockDisplay::new();
-        Text::new("Ā💣", Point::zero())
+        Text::new("中💣", Point::zero())
             .into_styled(style)
             .draw(&mut display)?;
         assert_eq!(display, two_question_marks);
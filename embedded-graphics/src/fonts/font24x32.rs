@@ -1,4 +1,23 @@
-use crate::{fonts::Font, geometry::Size};
+use crate::{
+    fonts::{GlyphMapping, GlyphRange, ImageFont},
+    geometry::Size,
+};
+
+/// Glyph mapping for the characters in the font image.
+const GLYPH_MAPPING: GlyphMapping = GlyphMapping::new(
+    &[
+        GlyphRange::new(' ', '~', 0),
+        GlyphRange::new('¡', 'ÿ', 95),
+        GlyphRange::new('Ā', 'ſ', 190),
+        GlyphRange::new('Ά', 'Ά', 318),
+        GlyphRange::new('Έ', 'Ί', 319),
+        GlyphRange::new('Ό', 'Ό', 322),
+        GlyphRange::new('Ύ', 'Ρ', 323),
+        GlyphRange::new('Σ', 'ώ', 343),
+        GlyphRange::new('Ѐ', 'џ', 387),
+    ],
+    '?',
+);
 
 /// 24x32 pixel monospace font.
 ///
@@ -16,24 +35,15 @@ use crate::{fonts::Font, geometry::Size};
 #[derive(Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Debug, Default)]
 pub struct Font24x32;
 
-impl Font for Font24x32 {
+impl ImageFont for Font24x32 {
     const FONT_IMAGE: &'static [u8] = include_bytes!("../../data/font24x32_1bpp.raw");
     const FONT_IMAGE_WIDTH: u32 = 960;
 
     const CHARACTER_SIZE: Size = Size::new(24, 32);
+    const BASELINE: u32 = 27;
 
     fn char_offset(c: char) -> u32 {
-        let fallback = '?' as u32 - ' ' as u32;
-        if c < ' ' {
-            return fallback;
-        }
-        if c <= '~' {
-            return c as u32 - ' ' as u32;
-        }
-        if c < '¡' || c > 'ÿ' {
-            return fallback;
-        }
-        c as u32 - ' ' as u32 - 34
+        GLYPH_MAPPING.index(c)
     }
 }
 
@@ -42,7 +52,7 @@ mod tests {
     use super::*;
     use crate::{
         drawable::Drawable,
-        fonts::{Font, Text},
+        fonts::Text,
         geometry::{Dimensions, Point, Size},
         mock_display::MockDisplay,
         pixelcolor::BinaryColor,
@@ -255,6 +265,54 @@ mod tests {
         Ok(())
     }
 
+    #[test]
+    fn correct_extended_glyphs() -> Result<(), core::convert::Infallible> {
+        let mut display = MockDisplay::new();
+        Text::new("Āџ", Point::zero())
+            .into_styled(TextStyle::new(Font24x32, BinaryColor::On))
+            .draw(&mut display)?;
+
+        assert_eq!(
+            display,
+            MockDisplay::from_pattern(&[
+                "    ############                            ",
+                "    ############                            ",
+                "    ############                            ",
+                "    ############                            ",
+                "                                            ",
+                "                                            ",
+                "                                            ",
+                "                                            ",
+                "    ############        ####            ####",
+                "    ############        ####            ####",
+                "    ############        ####            ####",
+                "    ############        ####            ####",
+                "####            ####    ####            ####",
+                "####            ####    ####            ####",
+                "####            ####    ####            ####",
+                "####            ####    ####            ####",
+                "####################    ####            ####",
+                "####################    ####            ####",
+                "####################    ####            ####",
+                "####################    ####            ####",
+                "####            ####    ####            ####",
+                "####            ####    ####            ####",
+                "####            ####    ####            ####",
+                "####            ####    ####            ####",
+                "####            ####    ####################",
+                "####            ####    ####################",
+                "####            ####    ####################",
+                "####            ####    ####################",
+                "                                ####        ",
+                "                                ####        ",
+                "                                ####        ",
+                "                                ####        ",
+            ])
+        );
+
+        Ok(())
+    }
+
     #[test]
     fn dont_panic() -> Result<(), core::convert::Infallible> {
         let two_question_marks = MockDisplay::from_pattern(&[
@@ -303,7 +361,7 @@ mod tests {
         assert_eq!(display, two_question_marks);
 
         let mut display = MockDisplay::new();
-        Text::new("Ā💣", Point::zero())
+        Text::new("中💣", Point::zero())
             .into_styled(style)
             .draw(&mut display)?;
         assert_eq!(display, two_question_marks);
@@ -1,4 +1,22 @@
-use crate::{fonts::Font, geometry::Size};
+use crate::{
+    fonts::{GlyphMapping, GlyphRange, ImageFont},
+    geometry::Size,
+};
+
+/// Glyph mapping for the characters in the font image.
+const GLYPH_MAPPING: GlyphMapping = GlyphMapping::new(
+    &[
+        GlyphRange::new(' ', '~', 0),
+        GlyphRange::new('Ā', 'ſ', 95),
+        GlyphRange::new('Ά', 'Ά', 223),
+        GlyphRange::new('Έ', 'Ί', 224),
+        GlyphRange::new('Ό', 'Ό', 227),
+        GlyphRange::new('Ύ', 'Ρ', 228),
+        GlyphRange::new('Σ', 'ώ', 248),
+        GlyphRange::new('Ѐ', 'џ', 292),
+    ],
+    '?',
+);
 
 /// 6x12 pixel monospace font.
 ///
@@ -10,21 +28,15 @@ use crate::{fonts::Font, geometry::Size};
 #[derive(Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Debug, Default)]
 pub struct Font6x12;
 
-impl Font for Font6x12 {
+impl ImageFont for Font6x12 {
     const FONT_IMAGE: &'static [u8] = include_bytes!("../../data/font6x12_1bpp.raw");
     const FONT_IMAGE_WIDTH: u32 = 96;
 
     const CHARACTER_SIZE: Size = Size::new(6, 12);
+    const BASELINE: u32 = 9;
 
     fn char_offset(c: char) -> u32 {
-        let fallback = '?' as u32 - ' ' as u32;
-        if c < ' ' {
-            return fallback;
-        }
-        if c <= '~' {
-            return c as u32 - ' ' as u32;
-        }
-        fallback
+        GLYPH_MAPPING.index(c)
     }
 }
 
@@ -33,7 +45,7 @@ mod tests {
     use super::*;
     use crate::{
         drawable::Drawable,
-        fonts::{Font, Text},
+        fonts::Text,
         geometry::{Dimensions, Point, Size},
         mock_display::MockDisplay,
         pixelcolor::BinaryColor,
@@ -166,6 +178,33 @@ mod tests {
         Ok(())
     }
 
+    #[test]
+    fn correct_extended_glyphs() -> Result<(), core::convert::Infallible> {
+        let mut display = MockDisplay::new();
+        Text::new("Āџ", Point::zero())
+            .into_styled(TextStyle::new(Font6x12, BinaryColor::On))
+            .draw(&mut display)?;
+
+        assert_eq!(
+            display,
+            MockDisplay::from_pattern(&[
+                "           ",
+                " ####      ",
+                "           ",
+                "  ##       ",
+                " #  #  #  #",
+                " #  #  #  #",
+                " ####  #  #",
+                " #  #  #  #",
+                " #  #  #  #",
+                " #  #  ####",
+                "        ## ",
+            ])
+        );
+
+        Ok(())
+    }
+
     #[test]
     fn dont_panic() -> Result<(), core::convert::Infallible> {
         let two_question_marks = MockDisplay::from_pattern(&[
@@ -204,7 +243,7 @@ mod tests {
         assert_eq!(display, two_question_marks);
 
         let mut display = MockDisplay::new();
-        Text::new("Ā💣", Point::zero())
+        Text::new("中💣", Point::zero())
             .into_styled(style)
             .draw(&mut display)?;
         assert_eq!(display, two_question_marks);
@@ -218,6 +257,7 @@ mod tests {
             font: Font6x12,
             text_color: Some(BinaryColor::On),
             background_color: Some(BinaryColor::Off),
+            ..TextStyle::default()
         };
 
         let mut text = Text::new("Testing string", Point::zero()).into_styled(style);
@@ -232,6 +272,7 @@ mod tests {
             font: Font6x12,
             text_color: Some(BinaryColor::On),
             background_color: Some(BinaryColor::Off),
+            ..TextStyle::default()
         };
 
         let mut text = Text::new("A", Point::zero()).into_styled(style);
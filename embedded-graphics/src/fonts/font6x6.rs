@@ -1,4 +1,24 @@
-use crate::{fonts::Font, geometry::Size};
+use crate::{
+    fonts::{GlyphMapping, GlyphRange, ImageFont},
+    geometry::Size,
+};
+
+/// Glyph mapping for the characters in the font image.
+const GLYPH_MAPPING: GlyphMapping = GlyphMapping::new(
+    &[
+        GlyphRange::new(' ', '~', 0),
+        GlyphRange::new('¡', '¿', 97),
+        GlyphRange::new('Ā', 'ſ', 128),
+        GlyphRange::new('Ά', 'Ά', 256),
+        GlyphRange::new('Έ', 'Ί', 257),
+        GlyphRange::new('Ό', 'Ό', 260),
+        GlyphRange::new('Ύ', 'Ρ', 261),
+        GlyphRange::new('Σ', 'ώ', 281),
+        GlyphRange::new('Ѐ', 'џ', 325),
+        GlyphRange::new('€', '€', 100),
+    ],
+    '?',
+);
 
 /// 6x6 pixel variable width font.
 ///
@@ -10,29 +30,17 @@ use crate::{fonts::Font, geometry::Size};
 #[derive(Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Debug, Default)]
 pub struct Font6x6;
 
-impl Font for Font6x6 {
+impl ImageFont for Font6x6 {
     const FONT_IMAGE: &'static [u8] = include_bytes!("../../data/font6x6_1bpp.raw");
     const FONT_IMAGE_WIDTH: u32 = 192;
 
     const CHARACTER_SIZE: Size = Size::new(6, 6);
+    const BASELINE: u32 = 4;
     const VARIABLE_WIDTH: bool = true;
     const CHARACTER_SPACING: u32 = 1;
 
     fn char_offset(c: char) -> u32 {
-        let fallback = '?' as u32 - ' ' as u32;
-        if c < ' ' {
-            return fallback;
-        }
-        if c <= '~' {
-            return c as u32 - ' ' as u32;
-        }
-        if c == '€' {
-            return 100;
-        }
-        if c < '¡' || c > '¿' {
-            return fallback;
-        }
-        c as u32 - ' ' as u32 - 0x20
+        GLYPH_MAPPING.index(c)
     }
 }
 
@@ -41,7 +49,7 @@ mod tests {
     use super::*;
     use crate::{
         drawable::Drawable,
-        fonts::{Font, Text},
+        fonts::Text,
         geometry::{Dimensions, Point, Size},
         mock_display::MockDisplay,
         pixelcolor::BinaryColor,
@@ -119,6 +127,7 @@ mod tests {
             font: Font6x6,
             text_color: Some(BinaryColor::Off),
             background_color: Some(BinaryColor::On),
+            ..TextStyle::default()
         };
         Text::new("Mm", Point::zero())
             .into_styled(style)
@@ -147,6 +156,7 @@ mod tests {
             font: Font6x6,
             text_color: Some(BinaryColor::Off),
             background_color: Some(BinaryColor::On),
+            ..TextStyle::default()
         };
         Text::new("Mm", Point::zero())
             .into_styled(style_inverse)
@@ -157,6 +167,7 @@ mod tests {
             font: Font6x6,
             text_color: Some(BinaryColor::On),
             background_color: Some(BinaryColor::Off),
+            ..TextStyle::default()
         };
         Text::new("Mm", Point::zero())
             .into_styled(style_normal)
@@ -254,6 +265,27 @@ mod tests {
         Ok(())
     }
 
+    #[test]
+    fn correct_extended_glyphs() -> Result<(), core::convert::Infallible> {
+        let mut display = MockDisplay::new();
+        Text::new("Āџ", Point::zero())
+            .into_styled(TextStyle::new(Font6x6, BinaryColor::On))
+            .draw(&mut display)?;
+
+        assert_eq!(
+            display,
+            MockDisplay::from_pattern(&[
+                "####      ",
+                " ##  #   #",
+                "#  # #   #",
+                "#### #####",
+                "#  #   #  ",
+            ])
+        );
+
+        Ok(())
+    }
+
     #[test]
     fn dont_panic() -> Result<(), core::convert::Infallible> {
         let two_question_marks = MockDisplay::from_pattern(&[
@@ -280,7 +312,7 @@ mod tests {
         assert_eq!(display, two_question_marks);
 
         let mut display = MockDisplay::new();
-        Text::new("Ā💣", Point::zero())
+        Text::new("中💣", Point::zero())
             .into_styled(style)
             .draw(&mut display)?;
         assert_eq!(display, two_question_marks);
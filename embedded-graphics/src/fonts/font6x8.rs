@@ -1,4 +1,23 @@
-use crate::{fonts::Font, geometry::Size};
+use crate::{
+    fonts::{GlyphMapping, GlyphRange, ImageFont},
+    geometry::Size,
+};
+
+/// Glyph mapping for the characters in the font image.
+const GLYPH_MAPPING: GlyphMapping = GlyphMapping::new(
+    &[
+        GlyphRange::new(' ', '~', 0),
+        GlyphRange::new('¡', 'ÿ', 95),
+        GlyphRange::new('Ā', 'ſ', 190),
+        GlyphRange::new('Ά', 'Ά', 318),
+        GlyphRange::new('Έ', 'Ί', 319),
+        GlyphRange::new('Ό', 'Ό', 322),
+        GlyphRange::new('Ύ', 'Ρ', 323),
+        GlyphRange::new('Σ', 'ώ', 343),
+        GlyphRange::new('Ѐ', 'џ', 387),
+    ],
+    '?',
+);
 
 /// 6x8 pixel monospace font.
 ///
@@ -10,24 +29,15 @@ use crate::{fonts::Font, geometry::Size};
 #[derive(Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Debug, Default)]
 pub struct Font6x8;
 
-impl Font for Font6x8 {
+impl ImageFont for Font6x8 {
     const FONT_IMAGE: &'static [u8] = include_bytes!("../../data/font6x8_1bpp.raw");
     const FONT_IMAGE_WIDTH: u32 = 240;
 
     const CHARACTER_SIZE: Size = Size::new(6, 8);
+    const BASELINE: u32 = 6;
 
     fn char_offset(c: char) -> u32 {
-        let fallback = '?' as u32 - ' ' as u32;
-        if c < ' ' {
-            return fallback;
-        }
-        if c <= '~' {
-            return c as u32 - ' ' as u32;
-        }
-        if c < '¡' || c > 'ÿ' {
-            return fallback;
-        }
-        c as u32 - ' ' as u32 - 34
+        GLYPH_MAPPING.index(c)
     }
 }
 
@@ -36,7 +46,7 @@ mod tests {
     use super::*;
     use crate::{
         drawable::Drawable,
-        fonts::{Font, Text},
+        fonts::Text,
         geometry::{Dimensions, Point, Size},
         mock_display::MockDisplay,
         pixelcolor::BinaryColor,
@@ -116,6 +126,7 @@ mod tests {
             font: Font6x8,
             text_color: Some(BinaryColor::Off),
             background_color: Some(BinaryColor::On),
+            ..TextStyle::default()
         };
         Text::new("Mm", Point::zero())
             .into_styled(style)
@@ -146,6 +157,7 @@ mod tests {
             font: Font6x8,
             text_color: Some(BinaryColor::Off),
             background_color: Some(BinaryColor::On),
+            ..TextStyle::default()
         };
         Text::new("Mm", Point::zero())
             .into_styled(style_inverse)
@@ -156,6 +168,7 @@ mod tests {
             font: Font6x8,
             text_color: Some(BinaryColor::On),
             background_color: Some(BinaryColor::Off),
+            ..TextStyle::default()
         };
         Text::new("Mm", Point::zero())
             .into_styled(style_normal)
@@ -260,6 +273,30 @@ mod tests {
         Ok(())
     }
 
+    #[test]
+    fn correct_extended_glyphs() -> Result<(), core::convert::Infallible> {
+        let mut display = MockDisplay::new();
+        Text::new("Āџ", Point::zero())
+            .into_styled(TextStyle::new(Font6x8, BinaryColor::On))
+            .draw(&mut display)?;
+
+        assert_eq!(
+            display,
+            MockDisplay::from_pattern(&[
+                " ###       ",
+                "           ",
+                " ###  #   #",
+                "#   # #   #",
+                "##### #   #",
+                "#   # #   #",
+                "#   # #####",
+                "        #  ",
+            ])
+        );
+
+        Ok(())
+    }
+
     #[test]
     fn dont_panic() -> Result<(), core::convert::Infallible> {
         let two_question_marks = MockDisplay::from_pattern(&[
@@ -287,7 +324,7 @@ mod tests {
         assert_eq!(display, two_question_marks);
 
         let mut display = MockDisplay::new();
-        Text::new("Ā💣", Point::zero())
+        Text::new("中💣", Point::zero())
             .into_styled(style)
             .draw(&mut display)?;
         assert_eq!(display, two_question_marks);
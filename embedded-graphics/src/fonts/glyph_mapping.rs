@@ -0,0 +1,164 @@
+use core::cmp::Ordering;
+
+/// Mapping from characters to glyph indices.
+///
+/// The mapping is defined by a table of [`GlyphRange`]s, which map ranges of consecutive
+/// characters to consecutive glyphs in the font image. This makes it possible to support sparse
+/// subsets of Unicode, like ASCII, Latin Extended-A, Greek and Cyrillic, without wasting space in
+/// the font image for unused characters. Characters are looked up by using a binary search.
+///
+/// Characters which aren't included in any range are mapped to the glyph of the replacement
+/// character.
+///
+/// # Examples
+///
+/// ```rust
+/// use embedded_graphics::{
+///     fonts::{GlyphMapping, GlyphRange, ImageFont},
+///     geometry::Size,
+/// };
+///
+/// /// Glyph mapping for a font image that contains ASCII, followed by uppercase Greek letters.
+/// const MAPPING: GlyphMapping = GlyphMapping::new(
+///     &[GlyphRange::new(' ', '~', 0), GlyphRange::new('Α', 'Ω', 95)],
+///     '?',
+/// );
+///
+/// struct GreekFont;
+///
+/// impl ImageFont for GreekFont {
+///     const FONT_IMAGE: &'static [u8] = &[0; 122 * 8];
+///     const FONT_IMAGE_WIDTH: u32 = 8;
+///     const CHARACTER_SIZE: Size = Size::new(8, 8);
+///
+///     fn char_offset(c: char) -> u32 {
+///         MAPPING.index(c)
+///     }
+/// }
+///
+/// assert_eq!(GreekFont::char_offset('A'), 33);
+/// assert_eq!(GreekFont::char_offset('Δ'), 98);
+/// assert_eq!(GreekFont::char_offset('Ж'), 31);
+/// ```
+///
+/// [`GlyphRange`]: struct.GlyphRange.html
+#[derive(Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Debug)]
+pub struct GlyphMapping<'a> {
+    /// Character ranges.
+    ///
+    /// The ranges must be sorted by character and must not overlap.
+    pub ranges: &'a [GlyphRange],
+
+    /// Character which is used for characters that aren't included in any range.
+    pub replacement_character: char,
+}
+
+impl<'a> GlyphMapping<'a> {
+    /// Creates a new glyph mapping.
+    pub const fn new(ranges: &'a [GlyphRange], replacement_character: char) -> Self {
+        Self {
+            ranges,
+            replacement_character,
+        }
+    }
+
+    /// Returns the glyph index of a character.
+    ///
+    /// Returns `None` if the character isn't included in the mapping.
+    pub fn get(&self, c: char) -> Option<u32> {
+        self.ranges
+            .binary_search_by(|range| {
+                if range.end < c {
+                    Ordering::Less
+                } else if range.start > c {
+                    Ordering::Greater
+                } else {
+                    Ordering::Equal
+                }
+            })
+            .ok()
+            .map(|index| {
+                let range = &self.ranges[index];
+
+                range.index + (c as u32 - range.start as u32)
+            })
+    }
+
+    /// Returns the glyph index of a character.
+    ///
+    /// The index of the replacement character is returned for characters which aren't included
+    /// in the mapping. If the replacement character is also missing `0` is returned.
+    pub fn index(&self, c: char) -> u32 {
+        self.get(c)
+            .or_else(|| self.get(self.replacement_character))
+            .unwrap_or(0)
+    }
+}
+
+/// Range of characters in a glyph mapping.
+///
+/// See the [`GlyphMapping`] documentation for more information.
+///
+/// [`GlyphMapping`]: struct.GlyphMapping.html
+#[derive(Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Debug)]
+pub struct GlyphRange {
+    /// First character in the range.
+    pub start: char,
+
+    /// Last character in the range.
+    pub end: char,
+
+    /// Glyph index of the first character.
+    pub index: u32,
+}
+
+impl GlyphRange {
+    /// Creates a new glyph range.
+    ///
+    /// The range includes all characters from `start` to `end`, including `end`.
+    pub const fn new(start: char, end: char, index: u32) -> Self {
+        Self { start, end, index }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const MAPPING: GlyphMapping = GlyphMapping::new(
+        &[
+            GlyphRange::new(' ', '~', 0),
+            GlyphRange::new('Ą', 'ą', 95),
+            GlyphRange::new('€', '€', 97),
+        ],
+        '?',
+    );
+
+    #[test]
+    fn get() {
+        assert_eq!(MAPPING.get(' '), Some(0));
+        assert_eq!(MAPPING.get('A'), Some(33));
+        assert_eq!(MAPPING.get('~'), Some(94));
+        assert_eq!(MAPPING.get('Ą'), Some(95));
+        assert_eq!(MAPPING.get('ą'), Some(96));
+        assert_eq!(MAPPING.get('€'), Some(97));
+
+        assert_eq!(MAPPING.get('\n'), None);
+        assert_eq!(MAPPING.get('\u{7F}'), None);
+        assert_eq!(MAPPING.get('Ć'), None);
+        assert_eq!(MAPPING.get('😀'), None);
+    }
+
+    #[test]
+    fn replacement_character() {
+        assert_eq!(MAPPING.index('ą'), 96);
+        assert_eq!(MAPPING.index('Ж'), 31);
+
+        let mapping = GlyphMapping::new(MAPPING.ranges, '\u{FFFD}');
+        assert_eq!(mapping.index('Ж'), 0);
+
+        let empty = GlyphMapping::new(&[], '?');
+        assert_eq!(empty.get('?'), None);
+        assert_eq!(empty.index('?'), 0);
+    }
+}
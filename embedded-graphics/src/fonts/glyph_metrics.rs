@@ -0,0 +1,33 @@
+use crate::primitives::Rectangle;
+
+/// Glyph metrics.
+///
+/// The metrics define the size and position of a glyph. All coordinates are relative to the
+/// origin of the glyph, which is the top left corner of the character cell. The X offset of a
+/// glyph, often called the left side bearing, and the Y offset are given by the top left corner of
+/// the bounding box. Glyphs can extend outside the character cell, e.g. to the left for negative
+/// bearings or below the line for descenders.
+///
+/// Metrics are returned by [`Font::glyph_metrics`].
+///
+/// [`Font::glyph_metrics`]: trait.Font.html#method.glyph_metrics
+#[derive(Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Debug, Default)]
+pub struct GlyphMetrics {
+    /// Horizontal distance between the origins of this glyph and the next glyph.
+    ///
+    /// The advance width doesn't include the character spacing or kerning of the font.
+    pub advance: u32,
+
+    /// Bounding box of the glyph pixels, relative to the glyph origin.
+    pub bounding_box: Rectangle,
+}
+
+impl GlyphMetrics {
+    /// Creates new glyph metrics.
+    pub const fn new(advance: u32, bounding_box: Rectangle) -> Self {
+        Self {
+            advance,
+            bounding_box,
+        }
+    }
+}
@@ -1,7 +1,9 @@
-//! Monospaced bitmap fonts.
+//! Bitmap fonts and text.
 //!
-//! This module contains support for drawing monospaced bitmap fonts and provides
-//! several [built-in fonts].
+//! This module contains support for drawing text using bitmap fonts and provides several
+//! monospaced [built-in fonts]. Proportional fonts, whose glyphs have individual widths, offsets
+//! and optional kerning pairs, are supported by [`BitmapFont`], which can also be created at
+//! runtime by loading a font in the BDF format with [`BdfFont`].
 //!
 //! Additional custom fonts can be added by the application or other crates. This
 //! is demonstrated in the `text-custom-font` example in the simulator crate.
@@ -151,6 +153,23 @@
 //! # Ok::<(), core::convert::Infallible>(())
 //! ```
 //!
+//! ## Wrap text to fit into a box
+//!
+//! A [`TextBox`] wraps text at word boundaries to fit into a rectangle. See the [`TextBox`]
+//! documentation for an example.
+//!
+//! # Unicode support
+//!
+//! The built-in fonts contain glyphs for ASCII, Latin Extended-A, Greek and Cyrillic. [`Font6x8`],
+//! [`Font8x16`], [`Font12x16`] and [`Font24x32`] also contain the Latin-1 Supplement and
+//! [`Font6x6`] contains the Latin-1 punctuation and symbols from `¡` to `¿`. Characters which
+//! aren't included in a font are drawn as `?`.
+//!
+//! Custom fonts can use a [`GlyphMapping`] to map sparse ranges of Unicode characters to the
+//! glyphs in the font image, in the same way as the built-in fonts. Fonts in the BDF format, for
+//! example GNU Unifont or the X11 fonts, can be loaded by using a [`BdfFont`]. The resulting
+//! [`BitmapFont`] supports all characters that are included in the BDF file.
+//!
 //! # Built-in fonts
 //!
 //! | Type | Screenshot |
@@ -172,20 +191,36 @@
 //! [`Font12x16`]: struct.Font12x16.html
 //! [`Font24x32`]: struct.Font24x32.html
 //! [`Text`]: struct.Text.html
+//! [`TextBox`]: struct.TextBox.html
+//! [`GlyphMapping`]: struct.GlyphMapping.html
+//! [`BdfFont`]: struct.BdfFont.html
+//! [`BitmapFont`]: struct.BitmapFont.html
 //! [`Styled`]: ../style/struct.Styled.html
 //! [`TextStyle`]: ../style/struct.TextStyle.html
 //! [`ArrayString`]: https://docs.rs/arrayvec/0.4.11/arrayvec/struct.ArrayString.html
 //! [`write!()`]: https://doc.rust-lang.org/nightly/std/macro.write.html
 
+mod bdf;
+mod bitmap_font;
 mod font12x16;
 mod font24x32;
 mod font6x12;
 mod font6x6;
 mod font6x8;
 mod font8x16;
+mod glyph_mapping;
+mod glyph_metrics;
+mod scaled_font;
 mod text;
+mod text_box;
 
-pub use text::{StyledTextIterator, Text};
+pub use bdf::{BdfError, BdfFont, BdfGlyph, BdfGlyphs};
+pub use bitmap_font::{BitmapFont, BitsPerPixel, Glyph, KerningPair};
+pub use glyph_mapping::{GlyphMapping, GlyphRange};
+pub use glyph_metrics::GlyphMetrics;
+pub(crate) use scaled_font::ScaledFont;
+pub use text::{AntialiasedTextIterator, LineWidths, StyledTextIterator, Text};
+pub use text_box::{Overflow, StyledTextBoxIterator, TextBox, VerticalAlignment};
 
 pub use font12x16::Font12x16;
 pub use font24x32::Font24x32;
@@ -194,10 +229,125 @@ pub use font6x6::Font6x6;
 pub use font6x8::Font6x8;
 pub use font8x16::Font8x16;
 
-use crate::geometry::Size;
+use crate::{
+    geometry::{Point, Size},
+    primitives::Rectangle,
+};
 
-/// Monospaced bitmap font.
+/// Font.
+///
+/// This trait defines the metrics and glyphs of a font, which are used by all text drawing code.
+/// Most fonts, like the [built-in fonts], are stored in a single image which contains all
+/// characters in a grid. These fonts implement the [`ImageFont`] trait instead, which provides an
+/// implementation of this trait.
+///
+/// Fonts which can't be described by a single image, like fonts that are loaded at runtime or
+/// which use glyphs with different sizes, implement this trait directly. Proportional fonts
+/// describe the size and position of each glyph with [`GlyphMetrics`] and can adjust the distance
+/// between pairs of characters by using [`kerning`]. The [`BitmapFont`] type is an example for
+/// such a font.
+///
+/// [built-in fonts]: index.html#built-in-fonts
+/// [`ImageFont`]: trait.ImageFont.html
+/// [`kerning`]: #method.kerning
+/// [`GlyphMetrics`]: struct.GlyphMetrics.html
+/// [`BitmapFont`]: struct.BitmapFont.html
 pub trait Font {
+    /// Returns the height of a line of text.
+    fn line_height(&self) -> u32;
+
+    /// Returns the row which contains the alphabetic baseline.
+    ///
+    /// The baseline is given as the row inside a line which contains the bottom edge of
+    /// characters without a descender, like `H`. It is used to align text with
+    /// [`Baseline::Alphabetic`]. Defaults to the last row of a line.
+    ///
+    /// [`Baseline::Alphabetic`]: ../style/enum.Baseline.html#variant.Alphabetic
+    fn baseline(&self) -> u32 {
+        self.line_height().saturating_sub(1)
+    }
+
+    /// Returns the spacing between characters.
+    ///
+    /// The spacing defines how many empty pixels are added horizontally between adjacent
+    /// characters on a single line of text. Defaults to `0`.
+    fn character_spacing(&self) -> u32 {
+        0
+    }
+
+    /// Returns the first row of the underline.
+    ///
+    /// Defaults to the row below the [`baseline`], or the last row of a line if the baseline is
+    /// the last row.
+    ///
+    /// [`baseline`]: #method.baseline
+    fn underline_position(&self) -> u32 {
+        (self.baseline() + 1).min(self.line_height().saturating_sub(1))
+    }
+
+    /// Returns the first row of the strikethrough line.
+    ///
+    /// Defaults to the row halfway between the top of the line and the [`baseline`].
+    ///
+    /// [`baseline`]: #method.baseline
+    fn strikethrough_position(&self) -> u32 {
+        self.baseline() / 2
+    }
+
+    /// Returns the thickness of underlines and strikethrough lines.
+    ///
+    /// Defaults to `1`.
+    fn decoration_thickness(&self) -> u32 {
+        1
+    }
+
+    /// Returns the metrics of a character.
+    fn glyph_metrics(&self, c: char) -> GlyphMetrics;
+
+    /// Returns the kerning adjustment for a pair of characters.
+    ///
+    /// The adjustment is added to the distance between the origins of the `left` and `right`
+    /// characters. Negative values move the characters closer together. Defaults to `0`.
+    fn kerning(&self, _left: char, _right: char) -> i32 {
+        0
+    }
+
+    /// Returns the value of a pixel in a character.
+    ///
+    /// The coordinates are relative to the top left corner of the bounding box that is returned
+    /// by [`glyph_metrics`].
+    ///
+    /// [`glyph_metrics`]: #tymethod.glyph_metrics
+    fn glyph_pixel(&self, c: char, x: u32, y: u32) -> bool;
+
+    /// Returns the coverage of a pixel in a character.
+    ///
+    /// Anti-aliased fonts return the fraction of the pixel that is covered by the glyph, in the
+    /// range from `0` (not covered) to `255` (fully covered). The coordinates are the same as in
+    /// [`glyph_pixel`].
+    ///
+    /// The default implementation returns `255` for pixels which are set by [`glyph_pixel`] and
+    /// `0` for all other pixels.
+    ///
+    /// [`glyph_pixel`]: #tymethod.glyph_pixel
+    fn glyph_coverage(&self, c: char, x: u32, y: u32) -> u8 {
+        if self.glyph_pixel(c, x, y) {
+            255
+        } else {
+            0
+        }
+    }
+}
+
+/// Font which is stored in a single image.
+///
+/// The font image contains all characters in a grid of equally sized cells. The font is defined
+/// by the associated constants of this trait and the [`char_offset`] function. [`Font`] is
+/// implemented for all types which implement this trait.
+///
+/// [`char_offset`]: #tymethod.char_offset
+/// [`Font`]: trait.Font.html
+pub trait ImageFont {
     /// Raw image data containing the font.
     const FONT_IMAGE: &'static [u8];
 
@@ -209,6 +359,15 @@ pub trait Font {
     /// Size of a single character in pixel.
     const CHARACTER_SIZE: Size;
 
+    /// Vertical position of the alphabetic baseline.
+    ///
+    /// The baseline is given as the row inside a character which contains the bottom edge of
+    /// characters without a descender, like `H`. It is used to align text with
+    /// [`Baseline::Alphabetic`]. By default the baseline is the last row of the character.
+    ///
+    /// [`Baseline::Alphabetic`]: ../style/enum.Baseline.html#variant.Alphabetic
+    const BASELINE: u32 = Self::CHARACTER_SIZE.height - 1;
+
     /// Spacing between characters.
     ///
     /// The spacing defines how many empty pixels are added horizontally between adjacent characters
@@ -219,9 +378,19 @@ pub trait Font {
     ///
     /// Variable width characters have a maximum width of CHARACTER_SIZE.x, but the empty columns at
     /// the right of each characters are ignored, allowing some characters to be smaller than others.
+    ///
+    /// The width is calculated by scanning the pixels of each character. Fonts which need more
+    /// control over the placement of characters should implement [`Font`] instead.
+    ///
+    /// [`Font`]: trait.Font.html
     const VARIABLE_WIDTH: bool = false;
 
     /// Returns the position a character in the font.
+    ///
+    /// A [`GlyphMapping`] can be used to implement this function for fonts which contain sparse
+    /// ranges of Unicode characters.
+    ///
+    /// [`GlyphMapping`]: struct.GlyphMapping.html
     fn char_offset(_: char) -> u32;
 
     /// Returns the actual width of a character in the font.
@@ -269,6 +438,42 @@ pub trait Font {
     }
 }
 
+/// The metrics of image fonts are defined by the associated constants of `ImageFont`.
+///
+/// Each glyph covers the whole character cell, which is [`char_width`] pixels wide and
+/// `CHARACTER_SIZE.height` pixels high. The advance width is equal to the width of the cell.
+///
+/// [`char_width`]: trait.ImageFont.html#method.char_width
+impl<F> Font for F
+where
+    F: ImageFont,
+{
+    fn line_height(&self) -> u32 {
+        F::CHARACTER_SIZE.height
+    }
+
+    fn baseline(&self) -> u32 {
+        F::BASELINE
+    }
+
+    fn character_spacing(&self) -> u32 {
+        F::CHARACTER_SPACING
+    }
+
+    fn glyph_metrics(&self, c: char) -> GlyphMetrics {
+        let width = F::char_width(c);
+
+        GlyphMetrics::new(
+            width,
+            Rectangle::new(Point::zero(), Size::new(width, self.line_height())),
+        )
+    }
+
+    fn glyph_pixel(&self, c: char, x: u32, y: u32) -> bool {
+        F::character_pixel(c, x, y)
+    }
+}
+
 /// Creates a styled text.
 ///
 /// The `egtext` macro expects the text, the position and styling properties as arguments.
@@ -302,6 +507,64 @@ macro_rules! egtext {
     }};
 }
 
+/// Implements `Serialize` and `Deserialize` for built-in fonts.
+///
+/// Fonts are serialized as their type name, e.g. `"Font6x8"`. Deserialization fails if the name
+/// doesn't match the expected font.
+#[cfg(feature = "serde_support")]
+macro_rules! impl_font_serde {
+    ($($font:ident),*) => {
+        $(
+            impl serde::Serialize for $font {
+                fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+                where
+                    S: serde::Serializer,
+                {
+                    serializer.serialize_str(stringify!($font))
+                }
+            }
+
+            impl<'de> serde::Deserialize<'de> for $font {
+                fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+                where
+                    D: serde::Deserializer<'de>,
+                {
+                    deserializer.deserialize_str(FontNameVisitor(stringify!($font)))?;
+
+                    Ok($font)
+                }
+            }
+        )*
+    };
+}
+
+/// Visitor which checks that a deserialized font name matches the expected name.
+#[cfg(feature = "serde_support")]
+struct FontNameVisitor(&'static str);
+
+#[cfg(feature = "serde_support")]
+impl<'de> serde::de::Visitor<'de> for FontNameVisitor {
+    type Value = ();
+
+    fn expecting(&self, formatter: &mut core::fmt::Formatter) -> core::fmt::Result {
+        write!(formatter, "the font name \"{}\"", self.0)
+    }
+
+    fn visit_str<E>(self, value: &str) -> Result<Self::Value, E>
+    where
+        E: serde::de::Error,
+    {
+        if value == self.0 {
+            Ok(())
+        } else {
+            Err(E::invalid_value(serde::de::Unexpected::Str(value), &self))
+        }
+    }
+}
+
+#[cfg(feature = "serde_support")]
+impl_font_serde!(Font6x6, Font6x8, Font6x12, Font8x16, Font12x16, Font24x32);
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -379,4 +642,18 @@ mod tests {
             style = text_style!(font = Font24x32, text_color = Rgb565::MAGENTA)
         );
     }
+
+    #[test]
+    #[cfg(feature = "serde_support")]
+    fn serde_font_name() {
+        use serde_test::{assert_de_tokens_error, assert_tokens, Token};
+
+        assert_tokens(&Font6x8, &[Token::Str("Font6x8")]);
+        assert_tokens(&Font24x32, &[Token::Str("Font24x32")]);
+
+        assert_de_tokens_error::<Font6x8>(
+            &[Token::Str("Font6x12")],
+            "invalid value: string \"Font6x12\", expected the font name \"Font6x8\"",
+        );
+    }
 }
@@ -0,0 +1,125 @@
+use crate::{
+    fonts::{Font, GlyphMetrics},
+    primitives::Rectangle,
+};
+
+/// Font which draws each pixel of another font as a square block of pixels.
+///
+/// This is used to implement the `scale` property of text styles. All metrics of the wrapped font
+/// are multiplied by the scale factor.
+#[derive(Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Debug)]
+pub(crate) struct ScaledFont<'a, F> {
+    font: &'a F,
+    scale: u32,
+}
+
+impl<'a, F> ScaledFont<'a, F>
+where
+    F: Font,
+{
+    /// Creates a new scaled font.
+    ///
+    /// A scale of `0` is treated like a scale of `1`.
+    pub(crate) fn new(font: &'a F, scale: u8) -> Self {
+        Self {
+            font,
+            scale: u32::from(scale.max(1)),
+        }
+    }
+}
+
+impl<F> Font for ScaledFont<'_, F>
+where
+    F: Font,
+{
+    fn line_height(&self) -> u32 {
+        self.font.line_height() * self.scale
+    }
+
+    fn baseline(&self) -> u32 {
+        // The baseline is the last row of the scaled baseline row.
+        self.font.baseline() * self.scale + self.scale - 1
+    }
+
+    fn character_spacing(&self) -> u32 {
+        self.font.character_spacing() * self.scale
+    }
+
+    fn underline_position(&self) -> u32 {
+        self.font.underline_position() * self.scale
+    }
+
+    fn strikethrough_position(&self) -> u32 {
+        self.font.strikethrough_position() * self.scale
+    }
+
+    fn decoration_thickness(&self) -> u32 {
+        self.font.decoration_thickness() * self.scale
+    }
+
+    fn glyph_metrics(&self, c: char) -> GlyphMetrics {
+        let metrics = self.font.glyph_metrics(c);
+        let bounding_box = metrics.bounding_box;
+
+        GlyphMetrics::new(
+            metrics.advance * self.scale,
+            Rectangle::new(
+                bounding_box.top_left * self.scale as i32,
+                bounding_box.size * self.scale,
+            ),
+        )
+    }
+
+    fn kerning(&self, left: char, right: char) -> i32 {
+        self.font.kerning(left, right) * self.scale as i32
+    }
+
+    fn glyph_pixel(&self, c: char, x: u32, y: u32) -> bool {
+        self.font.glyph_pixel(c, x / self.scale, y / self.scale)
+    }
+
+    fn glyph_coverage(&self, c: char, x: u32, y: u32) -> u8 {
+        self.font.glyph_coverage(c, x / self.scale, y / self.scale)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        fonts::Font6x8,
+        geometry::{Point, Size},
+    };
+
+    #[test]
+    fn metrics() {
+        let font = ScaledFont::new(&Font6x8, 3);
+
+        assert_eq!(font.line_height(), 24);
+        assert_eq!(font.baseline(), 20);
+        assert_eq!(font.character_spacing(), 0);
+        assert_eq!(font.underline_position(), 21);
+        assert_eq!(font.strikethrough_position(), 9);
+        assert_eq!(font.decoration_thickness(), 3);
+        assert_eq!(
+            font.glyph_metrics('A'),
+            GlyphMetrics::new(18, Rectangle::new(Point::zero(), Size::new(18, 24)))
+        );
+
+        assert_eq!(ScaledFont::new(&Font6x8, 0), ScaledFont::new(&Font6x8, 1));
+    }
+
+    #[test]
+    fn pixels() {
+        let font = ScaledFont::new(&Font6x8, 2);
+
+        for y in 0..16 {
+            for x in 0..12 {
+                assert_eq!(
+                    font.glyph_pixel('A', x, y),
+                    Font6x8.glyph_pixel('A', x / 2, y / 2)
+                );
+            }
+        }
+    }
+}
@@ -1,9 +1,10 @@
 use crate::{
-    drawable::{Drawable, Pixel},
+    drawable::{Drawable, Pixel, Spans},
     fonts::Font,
     geometry::{Dimensions, Point, Size},
-    pixelcolor::PixelColor,
-    primitives::Rectangle,
+    image::GetPixel,
+    pixelcolor::{Blend, PixelColor},
+    primitives::{ContainsPoint, Rectangle},
     style::{Styled, TextStyle},
     transform::Transform,
     DrawTarget,
@@ -29,7 +30,12 @@ pub struct Text<'a> {
 
     /// The position.
     ///
-    /// Defines the top-left starting pixel of the text object.
+    /// Defines the position of the text object. By default the position is the top-left starting
+    /// pixel of the text, which can be changed by setting the [`horizontal_alignment`] and
+    /// [`baseline`] of the text style.
+    ///
+    /// [`horizontal_alignment`]: ../style/struct.TextStyle.html#structfield.horizontal_alignment
+    /// [`baseline`]: ../style/struct.TextStyle.html#structfield.baseline
     pub position: Point,
 }
 
@@ -70,7 +76,233 @@ where
     F: Font + Copy,
 {
     fn draw<D: DrawTarget<C>>(self, display: &mut D) -> Result<(), D::Error> {
-        display.draw_iter(self.into_iter())
+        display.draw_spans(self.spans())
+    }
+}
+
+impl<'a, C, F> Styled<Text<'a>, TextStyle<C, F>>
+where
+    C: PixelColor,
+    F: Font + Copy,
+{
+    /// Returns an iterator over the horizontal spans of the styled text.
+    ///
+    /// Consecutive pixels in a row of a character which have the same color are combined into a
+    /// single span. This reduces the number of drawing operations for texts with a background
+    /// color. Drawing the spans results in the same output as drawing the pixels of the styled
+    /// text.
+    pub fn spans(&self) -> Spans<StyledTextIterator<'a, C, F>, C> {
+        Spans::new(self)
+    }
+}
+
+impl<'a, C, F> Styled<Text<'a>, TextStyle<C, F>>
+where
+    C: PixelColor + Blend,
+    F: Font + Copy,
+{
+    /// Returns an iterator over the anti-aliased pixels of the styled text.
+    ///
+    /// Fonts which support anti-aliasing, like a [`BitmapFont`] with more than 1 bit per pixel,
+    /// return the coverage of each pixel. Partly covered pixels are drawn by blending the text
+    /// color with the background color of the style. Text without a background color can be
+    /// drawn on a display that supports reading pixels by using [`draw_antialiased`]. Otherwise
+    /// pixels which are less than half covered are skipped and the remaining pixels are drawn in
+    /// the text color.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use embedded_graphics::{
+    ///     fonts::{Font6x8, Text},
+    ///     pixelcolor::Rgb565,
+    ///     prelude::*,
+    ///     style::TextStyleBuilder,
+    /// };
+    /// # use embedded_graphics::mock_display::MockDisplay;
+    /// # let mut display = MockDisplay::default();
+    ///
+    /// let style = TextStyleBuilder::new(Font6x8)
+    ///     .text_color(Rgb565::WHITE)
+    ///     .background_color(Rgb565::BLUE)
+    ///     .build();
+    ///
+    /// let text = Text::new("Hello", Point::new(10, 10)).into_styled(style);
+    ///
+    /// display.draw_iter(text.antialiased())?;
+    /// # Ok::<(), core::convert::Infallible>(())
+    /// ```
+    ///
+    /// [`BitmapFont`]: ../fonts/struct.BitmapFont.html
+    /// [`draw_antialiased`]: #method.draw_antialiased
+    pub fn antialiased(&self) -> AntialiasedTextIterator<'a, C, F> {
+        AntialiasedTextIterator {
+            iter: self.into_iter(),
+        }
+    }
+
+    /// Draws anti-aliased text by blending it with the pixels of the display.
+    ///
+    /// Partly covered pixels are blended with the current color of the display pixel if the
+    /// style has no background color. Pixels outside the display are skipped. See [`antialiased`]
+    /// for more information.
+    ///
+    /// [`antialiased`]: #method.antialiased
+    pub fn draw_antialiased<D>(&self, display: &mut D) -> Result<(), D::Error>
+    where
+        D: DrawTarget<C> + GetPixel<C>,
+    {
+        let mut iter = self.antialiased();
+
+        while let Some(pixel) = iter.next_blended(|point| display.pixel(point)) {
+            display.draw_pixel(pixel)?;
+        }
+
+        Ok(())
+    }
+}
+
+impl<'a, C, F> Styled<Text<'a>, TextStyle<C, F>>
+where
+    C: PixelColor,
+    F: Font,
+{
+    /// Returns the number of lines.
+    ///
+    /// Lines are separated by `\n` characters. A trailing newline starts an additional empty
+    /// line, which is the line a caret is placed on after the newline was typed.
+    pub fn line_count(&self) -> usize {
+        lines(self.primitive.text).count()
+    }
+
+    /// Returns an iterator over the widths of all lines.
+    ///
+    /// The widths are measured along the direction of the text and don't include the character
+    /// spacing after the last character of a line. See [`line_count`] for the definition of lines.
+    ///
+    /// [`line_count`]: #method.line_count
+    pub fn line_widths(&self) -> LineWidths<'_, C, F> {
+        LineWidths {
+            lines: lines(self.primitive.text),
+            style: &self.style,
+        }
+    }
+
+    /// Returns the position of a character.
+    ///
+    /// The position is the top left corner of the character cell, which is the position of a
+    /// caret in front of the character. `index` is the index of the character in the text, not
+    /// the byte offset. Newline characters are counted as characters and an index equal to the
+    /// number of characters returns the position after the last character. `None` is returned
+    /// for larger indices.
+    ///
+    /// The position takes the alignment, baseline, kerning, scale and rotation of the text style
+    /// into account. For rotated text the returned point is the rotated corner of the character
+    /// cell.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use embedded_graphics::{
+    ///     fonts::{Font6x8, Text},
+    ///     pixelcolor::BinaryColor,
+    ///     prelude::*,
+    ///     primitives::Line,
+    ///     style::{PrimitiveStyle, TextStyle},
+    /// };
+    /// # use embedded_graphics::mock_display::MockDisplay;
+    /// # let mut display = MockDisplay::new();
+    ///
+    /// let text = Text::new("Hello", Point::new(5, 5))
+    ///     .into_styled(TextStyle::new(Font6x8, BinaryColor::On));
+    /// text.draw(&mut display)?;
+    ///
+    /// // Draw a caret in front of the second `l`.
+    /// let caret = text.char_position(3).unwrap();
+    /// assert_eq!(caret, Point::new(23, 5));
+    ///
+    /// Line::new(caret, caret + Size::new(0, 7))
+    ///     .into_styled(PrimitiveStyle::with_stroke(BinaryColor::On, 1))
+    ///     .draw(&mut display)?;
+    /// # Ok::<(), core::convert::Infallible>(())
+    /// ```
+    pub fn char_position(&self, mut index: usize) -> Option<Point> {
+        let font = &self.style.scaled_font();
+        let position = self.primitive.position;
+        let mut y = position.y + self.style.baseline.offset(font);
+
+        for line in lines(self.primitive.text) {
+            let mut origin = Point::new(line_start(&self.style, position, line.chars()), y);
+            let mut chars = line.chars().peekable();
+
+            while index > 0 {
+                let c = match chars.next() {
+                    Some(c) => c,
+                    None => break,
+                };
+
+                origin.x += char_advance(font, c, chars.peek().copied());
+                index -= 1;
+            }
+
+            if index == 0 {
+                return Some(position + self.style.rotation.rotate(origin - position));
+            }
+
+            // Skip the newline character.
+            index -= 1;
+            y += font.line_height() as i32;
+        }
+
+        None
+    }
+
+    /// Returns the index of the character at a point.
+    ///
+    /// Points inside the cell of a character, which includes the character spacing after it,
+    /// return the index of this character. Points to the left or right of a line are mapped to
+    /// the first character of the line or to the end of the line, which is the index of the
+    /// newline character or the number of characters for the last line. `None` is returned if
+    /// the point is above the first or below the last line.
+    ///
+    /// The returned index can be passed to [`char_position`] to get the position of the
+    /// character.
+    ///
+    /// [`char_position`]: #method.char_position
+    pub fn char_index_at(&self, point: Point) -> Option<usize> {
+        let font = &self.style.scaled_font();
+        let position = self.primitive.position;
+        let point = position + self.style.rotation.inverse().rotate(point - position);
+
+        let top = position.y + self.style.baseline.offset(font);
+        let row = point.y - top;
+        if row < 0 {
+            return None;
+        }
+        let line_index = row as usize / font.line_height().max(1) as usize;
+
+        // Index of the first character in the line.
+        let mut index = 0;
+        let mut lines = lines(self.primitive.text);
+        for line in lines.by_ref().take(line_index) {
+            index += line.chars().count() + 1;
+        }
+        let line = lines.next()?;
+
+        // Points to the left of the line are mapped to the first character, because they are
+        // in front of the end of the first cell.
+        let mut x = line_start(&self.style, position, line.chars());
+        let mut chars = line.chars().peekable();
+        while let Some(c) = chars.next() {
+            x += char_advance(font, c, chars.peek().copied());
+            if point.x < x {
+                return Some(index);
+            }
+
+            index += 1;
+        }
+
+        Some(index)
     }
 }
 
@@ -83,52 +315,178 @@ where
     type IntoIter = StyledTextIterator<'a, C, F>;
 
     fn into_iter(self) -> Self::IntoIter {
-        Self::IntoIter {
-            current_char: self.primitive.text.chars().next(),
-            idx: 0,
-            text: self.primitive.text,
-            char_width: 0,
-            char_walk_x: 0,
-            char_walk_y: 0,
-            top_left: self.primitive.position,
-            pos: self.primitive.position,
+        let text = self.primitive.text;
+        let position = self.primitive.position;
+
+        let first_line = text.chars().take_while(|&c| c != '\n');
+
+        let mut iter = Self::IntoIter {
+            remaining: text,
+            current_char: None,
+            advance: 0,
+            glyph: Rectangle::default(),
+            cell: Rectangle::default(),
+            underline: Rectangle::default(),
+            strikethrough: Rectangle::default(),
+            area: Rectangle::default(),
+            point: Point::zero(),
+            position,
+            pos: Point::new(
+                line_start(&self.style, position, first_line),
+                position.y + self.style.baseline.offset(&self.style.scaled_font()),
+            ),
             style: self.style,
-        }
+        };
+        iter.next_char();
+
+        iter
     }
 }
 
+/// Returns an iterator over the lines of a text.
+///
+/// This matches the way `StyledTextIterator` draws text: only `\n` separates lines, other control
+/// characters like `\r` are drawn as glyphs, and a trailing newline starts an additional empty
+/// line.
+fn lines(text: &str) -> core::str::Split<'_, char> {
+    text.split('\n')
+}
+
+/// Returns the horizontal distance between the origins of a character and the next character.
+///
+/// The distance includes the character spacing and the kerning between `c` and `next`.
+pub(crate) fn char_advance<F>(font: &F, c: char, next: Option<char>) -> i32
+where
+    F: Font,
+{
+    let kerning = next.map_or(0, |next| font.kerning(c, next));
+
+    font.glyph_metrics(c).advance as i32 + font.character_spacing() as i32 + kerning
+}
+
+/// Returns the width of a single line of text.
+pub(crate) fn line_width<F>(font: &F, line: impl Iterator<Item = char>) -> u32
+where
+    F: Font,
+{
+    let mut line = line.peekable();
+    let mut width = 0;
+
+    while let Some(c) = line.next() {
+        width += char_advance(font, c, line.peek().copied());
+    }
+
+    // The last character isn't followed by character spacing.
+    (width - font.character_spacing() as i32).max(0) as u32
+}
+
+/// Returns the width of a single line of styled text.
+///
+/// The background of a character includes the character spacing after it. If the text has a
+/// background the spacing after the last character is therefore included in the width.
+fn styled_line_width<C, F>(style: &TextStyle<C, F>, line: impl Iterator<Item = char>) -> u32
+where
+    C: PixelColor,
+    F: Font,
+{
+    let font = &style.scaled_font();
+    let mut line = line.peekable();
+
+    if style.background_color.is_some() && line.peek().is_some() {
+        line_width(font, line) + font.character_spacing()
+    } else {
+        line_width(font, line)
+    }
+}
+
+/// Returns the x coordinate of the first column of an aligned line of text.
+fn line_start<C, F>(
+    style: &TextStyle<C, F>,
+    position: Point,
+    line: impl Iterator<Item = char>,
+) -> i32
+where
+    C: PixelColor,
+    F: Font,
+{
+    position.x
+        + style
+            .horizontal_alignment
+            .offset(styled_line_width(style, line))
+}
+
 impl<C, F> Dimensions for Styled<Text<'_>, TextStyle<C, F>>
 where
     C: PixelColor,
     F: Font,
 {
     fn bounding_box(&self) -> Rectangle {
-        let width = if !self.primitive.text.is_empty() {
-            self.primitive
-                .text
-                .lines()
-                .map(|l| {
-                    l.chars()
-                        .map(|c| F::char_width(c) + F::CHARACTER_SPACING)
-                        .sum::<u32>()
-                        - F::CHARACTER_SPACING
-                })
-                .max()
-                .unwrap_or(0)
-        } else {
-            0
-        };
+        let position = self.primitive.position;
+        let bounding_box = unrotated_bounding_box(self);
 
-        let height = if width > 0 {
-            F::CHARACTER_SIZE.height * self.primitive.text.lines().count() as u32
-        } else {
-            0
-        };
+        match bounding_box.bottom_right() {
+            Some(bottom_right) => {
+                let rotation = self.style.rotation;
+
+                Rectangle::with_corners(
+                    position + rotation.rotate(bounding_box.top_left - position),
+                    position + rotation.rotate(bottom_right - position),
+                )
+            }
+            None => Rectangle::new(position, Size::zero()),
+        }
+    }
+}
+
+/// Returns the bounding box of a styled text before it is rotated.
+fn unrotated_bounding_box<C, F>(text: &Styled<Text<'_>, TextStyle<C, F>>) -> Rectangle
+where
+    C: PixelColor,
+    F: Font,
+{
+    let font = &text.style.scaled_font();
+    let position = text.primitive.position;
+    let top = position.y + text.style.baseline.offset(font);
+
+    let mut line_count = 0;
+    let mut extent: Option<(i32, i32)> = None;
+    let mut glyphs = Rectangle::default();
+
+    for line in lines(text.primitive.text) {
+        let width = styled_line_width(&text.style, line.chars());
+        let start = line_start(&text.style, position, line.chars());
+
+        if width > 0 {
+            let end = start + width as i32;
+
+            extent = Some(extent.map_or((start, end), |(left, right)| {
+                (left.min(start), right.max(end))
+            }));
+        }
 
-        let size = Size::new(width, height);
+        // Glyphs can extend outside the character cells.
+        let mut origin = Point::new(start, top + (font.line_height() * line_count) as i32);
+        let mut chars = line.chars().peekable();
+        while let Some(c) = chars.next() {
+            let glyph = font.glyph_metrics(c).bounding_box.translate(origin);
+            glyphs = glyphs.envelope(&glyph);
 
-        Rectangle::new(self.primitive.position, size)
+            origin.x += char_advance(font, c, chars.peek().copied());
+        }
+
+        line_count += 1;
     }
+
+    let (left, right) = match extent {
+        Some(extent) => extent,
+        None if glyphs.is_zero_sized() => return Rectangle::new(position, Size::zero()),
+        None => return glyphs,
+    };
+
+    let top_left = Point::new(left, top);
+    let size = Size::new((right - left) as u32, font.line_height() * line_count);
+
+    Rectangle::new(top_left, size).envelope(&glyphs)
 }
 
 /// Pixel iterator for styled text.
@@ -138,99 +496,322 @@ where
     C: PixelColor,
     F: Font,
 {
-    char_width: u32,
-    char_walk_x: i32,
-    char_walk_y: i32,
+    /// Text after the current character.
+    remaining: &'a str,
     current_char: Option<char>,
-    idx: usize,
-    top_left: Point,
+
+    /// Distance between the origins of the current and the next character.
+    advance: i32,
+
+    /// Bounding box of the current glyph, relative to `pos`.
+    glyph: Rectangle,
+
+    /// Background area of the current character, relative to `pos`.
+    cell: Rectangle,
+
+    /// Underline of the current character, relative to `pos`.
+    underline: Rectangle,
+
+    /// Strikethrough line of the current character, relative to `pos`.
+    strikethrough: Rectangle,
+
+    /// Area which is covered by the current character, relative to `pos`.
+    area: Rectangle,
+
+    /// Current point inside `area`.
+    point: Point,
+
+    position: Point,
     pos: Point,
-    text: &'a str,
     style: TextStyle<C, F>,
 }
 
-impl<C, F> Iterator for StyledTextIterator<'_, C, F>
+impl<C, F> StyledTextIterator<'_, C, F>
 where
     C: PixelColor,
     F: Font,
 {
-    type Item = Pixel<C>;
+    /// Moves to the next character and updates the areas covered by the character.
+    fn next_char(&mut self) {
+        let mut chars = self.remaining.chars();
+        self.current_char = chars.next();
+        self.remaining = chars.as_str();
 
-    fn next(&mut self) -> Option<Self::Item> {
+        let c = match self.current_char {
+            Some(c) if c != '\n' => c,
+            _ => return,
+        };
+
+        let font = &self.style.scaled_font();
+        let metrics = font.glyph_metrics(c);
+        let next = self.remaining.chars().next().filter(|&c| c != '\n');
+
+        self.advance = char_advance(font, c, next);
+        self.glyph = metrics.bounding_box;
+
+        // The background and decorations include the character spacing, but not the parts of the
+        // glyph outside of the character cell. The spacing after the last character of a line is
+        // only included if it is part of the line width, which requires a background.
+        let width = if next.is_some() || self.style.background_color.is_some() {
+            self.advance.max(metrics.advance as i32) as u32
+        } else {
+            metrics.advance
+        };
+        let decoration = |enabled: bool, row: u32| {
+            if enabled {
+                Rectangle::new(
+                    Point::new(0, row as i32),
+                    Size::new(width, font.decoration_thickness()),
+                )
+            } else {
+                Rectangle::default()
+            }
+        };
+
+        self.cell = if self.style.background_color.is_some() {
+            Rectangle::new(Point::zero(), Size::new(width, font.line_height()))
+        } else {
+            Rectangle::default()
+        };
+        self.underline = decoration(self.style.underline, font.underline_position());
+        self.strikethrough = decoration(self.style.strikethrough, font.strikethrough_position());
+
+        self.area = self
+            .cell
+            .envelope(&self.glyph)
+            .envelope(&self.underline)
+            .envelope(&self.strikethrough);
+        self.point = self.area.top_left;
+    }
+
+    /// Returns the coverage of a point, relative to `pos`, by the current glyph.
+    fn glyph_coverage(&self, c: char, point: Point) -> u8 {
+        if !self.glyph.contains(point) {
+            return 0;
+        }
+
+        let point = point - self.glyph.top_left;
+
+        self.style
+            .scaled_font()
+            .glyph_coverage(c, point.x as u32, point.y as u32)
+    }
+
+    /// Returns the next point of the text and the character at this point.
+    ///
+    /// The returned point is relative to `pos` and covers all points in the area of the current
+    /// character, including transparent points.
+    fn next_point(&mut self) -> Option<(char, Point)> {
         loop {
-            if self.current_char == Some('\n') {
-                self.pos.x = self.top_left.x;
-                self.pos.y += F::CHARACTER_SIZE.height as i32;
-                self.idx += 1;
-                self.current_char = self.text.chars().nth(self.idx);
-            } else if self.char_walk_x < 0 {
-                let x = self.pos.x + self.char_walk_x;
-                let y = self.pos.y + self.char_walk_y;
-
-                self.char_walk_y += 1;
-
-                if self.char_walk_y >= F::CHARACTER_SIZE.height as i32 {
-                    self.char_walk_y = 0;
-                    self.char_walk_x += 1;
-                }
+            let current_char = self.current_char?;
 
-                if let Some(color) = self.style.background_color {
-                    break Some(Pixel(Point::new(x, y), color));
-                }
-            } else if let Some(current_char) = self.current_char {
-                if self.char_width == 0 {
-                    self.char_width = F::char_width(current_char);
+            if current_char == '\n' {
+                let line = self.remaining.chars().take_while(|&c| c != '\n');
+
+                self.pos.x = line_start(&self.style, self.position, line);
+                self.pos.y += self.style.scaled_font().line_height() as i32;
+                self.next_char();
+            } else if self.point.y >= self.area.top_left.y + self.area.size.height as i32 {
+                // Done with this char, move on to the next one
+                self.pos.x += self.advance;
+                self.next_char();
+            } else {
+                let point = self.point;
+
+                self.point.x += 1;
+                if self.point.x >= self.area.top_left.x + self.area.size.width as i32 {
+                    self.point.x = self.area.top_left.x;
+                    self.point.y += 1;
                 }
 
-                let color = if F::character_pixel(
-                    current_char,
-                    self.char_walk_x as u32,
-                    self.char_walk_y as u32,
-                ) {
-                    self.style.text_color.or(self.style.background_color)
-                } else {
-                    self.style.background_color
-                };
+                return Some((current_char, point));
+            }
+        }
+    }
 
-                let x = self.pos.x + self.char_walk_x;
-                let y = self.pos.y + self.char_walk_y;
+    /// Returns the color of the decorations at a point, relative to `pos`.
+    ///
+    /// Returns `None` if the point isn't part of a decoration or if the decorations are
+    /// transparent.
+    fn decoration_color(&self, point: Point) -> Option<C> {
+        if self.underline.contains(point) || self.strikethrough.contains(point) {
+            self.style.decoration_color.or(self.style.text_color)
+        } else {
+            None
+        }
+    }
 
-                self.char_walk_x += 1;
+    /// Returns the display position of a point, relative to `pos`.
+    ///
+    /// The text is laid out without rotation and each point is rotated around the text position
+    /// afterwards, which makes the glyphs follow the rotated direction of the text.
+    fn display_position(&self, point: Point) -> Point {
+        let offset = self.pos + point - self.position;
 
-                if self.char_walk_x >= self.char_width as i32 {
-                    self.char_walk_x = 0;
-                    self.char_walk_y += 1;
+        self.position + self.style.rotation.rotate(offset)
+    }
 
-                    // Done with this char, move on to the next one
-                    if self.char_walk_y >= F::CHARACTER_SIZE.height as i32 {
-                        self.pos.x += (self.char_width + F::CHARACTER_SPACING) as i32;
-                        self.char_width = 0;
-                        self.char_walk_y = 0;
-                        self.char_walk_x -= F::CHARACTER_SPACING as i32;
-                        self.idx += 1;
-                        self.current_char = self.text.chars().nth(self.idx);
-                    }
-                }
+    /// Returns `true` if a point, relative to `pos`, is a set pixel of the current glyph.
+    fn is_glyph_pixel(&self, c: char, point: Point) -> bool {
+        if !self.glyph.contains(point) {
+            return false;
+        }
 
-                // Skip to next point if pixel is transparent
-                if let Some(color) = color {
-                    break Some(Pixel(Point::new(x, y), color));
-                }
+        let point = point - self.glyph.top_left;
+
+        self.style
+            .scaled_font()
+            .glyph_pixel(c, point.x as u32, point.y as u32)
+    }
+}
+
+impl<C, F> Iterator for StyledTextIterator<'_, C, F>
+where
+    C: PixelColor,
+    F: Font,
+{
+    type Item = Pixel<C>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        while let Some((c, point)) = self.next_point() {
+            let color = if let Some(color) = self.decoration_color(point) {
+                Some(color)
+            } else if self.is_glyph_pixel(c, point) {
+                self.style.text_color.or(self.style.background_color)
+            } else if self.cell.contains(point) {
+                self.style.background_color
+            } else {
+                None
+            };
+
+            // Skip to next point if pixel is transparent
+            if let Some(color) = color {
+                return Some(Pixel(self.display_position(point), color));
+            }
+        }
+
+        None
+    }
+}
+
+/// Anti-aliased pixel iterator for styled text.
+///
+/// This iterator is created by the [`antialiased`] method.
+///
+/// [`antialiased`]: ../style/struct.Styled.html#method.antialiased
+#[derive(Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Debug, Default)]
+pub struct AntialiasedTextIterator<'a, C, F>
+where
+    C: PixelColor,
+    F: Font,
+{
+    iter: StyledTextIterator<'a, C, F>,
+}
+
+impl<C, F> AntialiasedTextIterator<'_, C, F>
+where
+    C: PixelColor + Blend,
+    F: Font,
+{
+    /// Returns the next pixel.
+    ///
+    /// Partly covered pixels are blended with the background color of the text style. If the
+    /// style has no background color the pixel is blended with the color that is returned by
+    /// `background`. Pixels which are at least half covered are drawn in the text color if both
+    /// colors are unavailable.
+    fn next_blended<B>(&mut self, mut background: B) -> Option<Pixel<C>>
+    where
+        B: FnMut(Point) -> Option<C>,
+    {
+        let iter = &mut self.iter;
+
+        while let Some((c, point)) = iter.next_point() {
+            let position = iter.display_position(point);
+            let coverage = iter.glyph_coverage(c, point);
+
+            let background_color = if iter.cell.contains(point) || coverage > 0 {
+                iter.style.background_color
             } else {
-                break None;
+                None
+            };
+
+            let decoration_color = iter.decoration_color(point);
+
+            let color = match (iter.style.text_color, coverage) {
+                _ if decoration_color.is_some() => decoration_color,
+                (_, 0) | (None, _) => background_color,
+                (Some(text_color), 255) => Some(text_color),
+                (Some(text_color), coverage) => background_color
+                    .or_else(|| background(position))
+                    .map(|background_color| background_color.blend(text_color, coverage))
+                    .or(Some(text_color).filter(|_| coverage >= 128)),
+            };
+
+            if let Some(color) = color {
+                return Some(Pixel(position, color));
             }
         }
+
+        None
+    }
+}
+
+impl<C, F> Iterator for AntialiasedTextIterator<'_, C, F>
+where
+    C: PixelColor + Blend,
+    F: Font,
+{
+    type Item = Pixel<C>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.next_blended(|_| None)
     }
 }
+
+/// Iterator over the widths of the lines of a styled text.
+///
+/// This iterator is created by the [`line_widths`] method.
+///
+/// [`line_widths`]: ../style/struct.Styled.html#method.line_widths
+#[derive(Clone, Debug)]
+pub struct LineWidths<'a, C, F>
+where
+    C: PixelColor,
+    F: Font,
+{
+    lines: core::str::Split<'a, char>,
+    style: &'a TextStyle<C, F>,
+}
+
+impl<C, F> Iterator for LineWidths<'_, C, F>
+where
+    C: PixelColor,
+    F: Font,
+{
+    type Item = u32;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.lines
+            .next()
+            .map(|line| line_width(&self.style.scaled_font(), line.chars()))
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
-    use crate::{fonts::Font6x8, mock_display::MockDisplay, pixelcolor::BinaryColor};
+    use crate::{
+        fonts::{Font6x8, ImageFont},
+        mock_display::MockDisplay,
+        pixelcolor::BinaryColor,
+        primitives::Primitive,
+        style::{Baseline, HorizontalAlignment, PrimitiveStyle, TextRotation, TextStyleBuilder},
+    };
 
     #[derive(Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Debug, Default)]
     struct SpacedFont;
 
-    impl Font for SpacedFont {
+    impl ImageFont for SpacedFont {
         const FONT_IMAGE: &'static [u8] = &[0xF0, 0xA0, 0x50, 0x10];
         const FONT_IMAGE_WIDTH: u32 = 8;
         const CHARACTER_SIZE: Size = Size::new(4, 4);
@@ -337,4 +918,496 @@ mod tests {
 
         Ok(())
     }
+
+    #[test]
+    fn background_spans() {
+        use crate::{drawable::Span, style::TextStyleBuilder};
+
+        let style = TextStyleBuilder::new(Font6x8)
+            .text_color(BinaryColor::On)
+            .background_color(BinaryColor::Off)
+            .build();
+
+        // The first row of a space character only contains background pixels
+        let text = Text::new(" ", Point::new(3, 4)).into_styled(style);
+        assert_eq!(
+            text.spans().next(),
+            Some(Span::new(Point::new(3, 4), 6, BinaryColor::Off))
+        );
+        assert_eq!(text.spans().count(), 8);
+    }
+
+    #[test]
+    fn horizontal_alignment() -> Result<(), core::convert::Infallible> {
+        let style = TextStyleBuilder::new(Font6x8)
+            .text_color(BinaryColor::On)
+            .horizontal_alignment(HorizontalAlignment::Center)
+            .build();
+
+        let mut display = MockDisplay::new();
+        Text::new("-\n--", Point::new(8, 0))
+            .into_styled(style)
+            .draw(&mut display)?;
+
+        let mut expected = MockDisplay::new();
+        Text::new("-", Point::new(5, 0))
+            .into_styled(TextStyle::new(Font6x8, BinaryColor::On))
+            .draw(&mut expected)?;
+        Text::new("--", Point::new(2, 8))
+            .into_styled(TextStyle::new(Font6x8, BinaryColor::On))
+            .draw(&mut expected)?;
+
+        assert_eq!(display, expected);
+
+        let style = TextStyleBuilder::new(Font6x8)
+            .text_color(BinaryColor::On)
+            .horizontal_alignment(HorizontalAlignment::Right)
+            .build();
+
+        let text = Text::new("AB\nC", Point::new(20, 0)).into_styled(style);
+        assert_eq!(text.into_iter().map(|Pixel(p, _)| p.x).max(), Some(20 - 1));
+        assert_eq!(
+            text.bounding_box(),
+            Rectangle::new(Point::new(9, 0), Size::new(12, 16))
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn aligned_background_includes_character_spacing() {
+        let style = TextStyleBuilder::new(SpacedFont)
+            .text_color(BinaryColor::On)
+            .background_color(BinaryColor::Off)
+            .horizontal_alignment(HorizontalAlignment::Right)
+            .build();
+
+        let text = Text::new("##\n#", Point::new(20, 0)).into_styled(style);
+        assert_eq!(text.into_iter().map(|Pixel(p, _)| p.x).max(), Some(20));
+        assert_eq!(
+            text.bounding_box(),
+            Rectangle::new(Point::new(3, 0), Size::new(18, 8))
+        );
+
+        let mut display = MockDisplay::new();
+        text.draw(&mut display).unwrap();
+        assert_eq!(
+            display,
+            MockDisplay::from_pattern(&[
+                "   ####.....####.....",
+                "   #.#......#.#......",
+                "   .#.#......#.#.....",
+                "   ...#........#.....",
+                "            ####.....",
+                "            #.#......",
+                "            .#.#.....",
+                "            ...#.....",
+            ])
+        );
+    }
+
+    #[test]
+    fn baseline() {
+        let text = Text::new("H", Point::new(0, 10));
+
+        let top_left = |baseline| {
+            let style = TextStyleBuilder::new(Font6x8)
+                .text_color(BinaryColor::On)
+                .baseline(baseline)
+                .build();
+
+            text.into_styled(style).bounding_box().top_left
+        };
+
+        assert_eq!(top_left(Baseline::Top), Point::new(0, 10));
+        assert_eq!(top_left(Baseline::Middle), Point::new(0, 7));
+        assert_eq!(top_left(Baseline::Alphabetic), Point::new(0, 4));
+        assert_eq!(top_left(Baseline::Bottom), Point::new(0, 3));
+
+        // The bottom row of `H` is at the alphabetic baseline
+        let style = TextStyleBuilder::new(Font6x8)
+            .text_color(BinaryColor::On)
+            .baseline(Baseline::Alphabetic)
+            .build();
+        assert_eq!(
+            text.into_styled(style)
+                .into_iter()
+                .map(|Pixel(p, _)| p.y)
+                .max(),
+            Some(10)
+        );
+    }
+
+    #[test]
+    fn aligned_bounding_box() {
+        let alignments = [
+            HorizontalAlignment::Left,
+            HorizontalAlignment::Center,
+            HorizontalAlignment::Right,
+        ];
+        let baselines = [
+            Baseline::Top,
+            Baseline::Middle,
+            Baseline::Alphabetic,
+            Baseline::Bottom,
+        ];
+
+        for &horizontal_alignment in alignments.iter() {
+            for &baseline in baselines.iter() {
+                let style = TextStyleBuilder::new(Font6x8)
+                    .text_color(BinaryColor::On)
+                    .background_color(BinaryColor::Off)
+                    .horizontal_alignment(horizontal_alignment)
+                    .baseline(baseline)
+                    .build();
+
+                let text = Text::new("Wide line\n\nI\nmedium", Point::new(3, 4)).into_styled(style);
+                let bounding_box = text.bounding_box();
+
+                let (min, max) = text.into_iter().fold(
+                    (Point::new(1000, 1000), Point::new(-1000, -1000)),
+                    |(min, max), Pixel(p, _)| (min.component_min(p), max.component_max(p)),
+                );
+
+                assert_eq!(bounding_box, Rectangle::with_corners(min, max));
+            }
+        }
+    }
+
+    #[test]
+    fn scaled() -> Result<(), core::convert::Infallible> {
+        let style = TextStyleBuilder::new(Font6x8)
+            .text_color(BinaryColor::On)
+            .background_color(BinaryColor::Off)
+            .scale(2)
+            .build();
+
+        let mut display = MockDisplay::new();
+        Text::new("Hi\n!", Point::new(1, 2))
+            .into_styled(style)
+            .draw(&mut display)?;
+
+        // Draw each pixel of the unscaled text as a 2x2 block.
+        let mut expected = MockDisplay::new();
+        let unscaled = TextStyle { scale: 1, ..style };
+        for Pixel(point, color) in &Text::new("Hi\n!", Point::zero()).into_styled(unscaled) {
+            let top_left = Point::new(1, 2) + point * 2;
+
+            Rectangle::new(top_left, Size::new(2, 2))
+                .into_styled(PrimitiveStyle::with_fill(color))
+                .draw(&mut expected)?;
+        }
+
+        assert_eq!(display, expected);
+
+        Ok(())
+    }
+
+    #[test]
+    fn scaled_bounding_box() {
+        let style = TextStyleBuilder::new(Font6x8)
+            .text_color(BinaryColor::On)
+            .baseline(Baseline::Alphabetic)
+            .scale(3)
+            .build();
+
+        let text = Text::new("AB\nC", Point::new(10, 30)).into_styled(style);
+
+        // The baseline is located in the last row of the scaled baseline row.
+        assert_eq!(
+            text.bounding_box(),
+            Rectangle::new(
+                Point::new(10, 30 - 6 * 3 - 2),
+                Size::new(2 * 6 * 3, 2 * 8 * 3)
+            )
+        );
+
+        let (min, max) = text.into_iter().fold(
+            (Point::new(1000, 1000), Point::new(-1000, -1000)),
+            |(min, max), Pixel(p, _)| (min.component_min(p), max.component_max(p)),
+        );
+        assert!(text.bounding_box().contains(min));
+        assert!(text.bounding_box().contains(max));
+    }
+
+    #[test]
+    fn max_scale_bounding_box() {
+        let style = TextStyleBuilder::new(Font6x8)
+            .text_color(BinaryColor::On)
+            .scale(u8::max_value())
+            .build();
+
+        let text = Text::new("AB\nC", Point::zero()).into_styled(style);
+
+        assert_eq!(
+            text.bounding_box(),
+            Rectangle::new(Point::zero(), Size::new(2 * 6 * 255, 2 * 8 * 255))
+        );
+    }
+
+    #[test]
+    fn rotated() -> Result<(), core::convert::Infallible> {
+        let position = Point::new(30, 30);
+
+        let style = TextStyleBuilder::new(Font6x8)
+            .text_color(BinaryColor::On)
+            .build();
+        let text = Text::new("Ag\nx", position).into_styled(style);
+
+        for &rotation in &[
+            TextRotation::Rotate0,
+            TextRotation::Rotate90,
+            TextRotation::Rotate180,
+            TextRotation::Rotate270,
+        ] {
+            let mut display = MockDisplay::new();
+            let mut style = style;
+            style.rotation = rotation;
+            Text::new("Ag\nx", position)
+                .into_styled(style)
+                .draw(&mut display)?;
+
+            let mut expected = MockDisplay::new();
+            text.into_iter()
+                .map(|Pixel(p, c)| Pixel(position + rotation.rotate(p - position), c))
+                .draw(&mut expected)?;
+
+            assert_eq!(display, expected);
+        }
+
+        Ok(())
+    }
+
+    #[test]
+    fn rotated_bounding_box() {
+        let position = Point::new(30, 30);
+
+        let style = TextStyleBuilder::new(Font6x8)
+            .text_color(BinaryColor::On)
+            .background_color(BinaryColor::Off)
+            .build();
+
+        for &(rotation, expected) in &[
+            (
+                TextRotation::Rotate0,
+                Rectangle::new(Point::new(30, 30), Size::new(12, 8)),
+            ),
+            (
+                TextRotation::Rotate90,
+                Rectangle::new(Point::new(23, 30), Size::new(8, 12)),
+            ),
+            (
+                TextRotation::Rotate180,
+                Rectangle::new(Point::new(19, 23), Size::new(12, 8)),
+            ),
+            (
+                TextRotation::Rotate270,
+                Rectangle::new(Point::new(30, 19), Size::new(8, 12)),
+            ),
+        ] {
+            let mut style = style;
+            style.rotation = rotation;
+            let text = Text::new("Ag", position).into_styled(style);
+
+            assert_eq!(text.bounding_box(), expected, "{:?}", rotation);
+
+            // The background covers the whole bounding box.
+            assert_eq!(text.into_iter().count(), 12 * 8);
+            assert!(text.into_iter().all(|Pixel(p, _)| expected.contains(p)));
+        }
+
+        let mut style = style;
+        style.rotation = TextRotation::Rotate90;
+        assert_eq!(
+            Text::new("", position).into_styled(style).bounding_box(),
+            Rectangle::new(position, Size::zero())
+        );
+    }
+
+    #[test]
+    fn underline() -> Result<(), core::convert::Infallible> {
+        let style = TextStyleBuilder::new(Font6x8)
+            .text_color(BinaryColor::On)
+            .underline(true)
+            .build();
+
+        let mut display = MockDisplay::new();
+        Text::new("A", Point::zero())
+            .into_styled(style)
+            .draw(&mut display)?;
+
+        assert_eq!(
+            display,
+            MockDisplay::from_pattern(&[
+                " ###  ", "#   # ", "#   # ", "##### ", "#   # ", "#   # ", "#   # ", "######",
+            ])
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn decorations_include_character_spacing() -> Result<(), core::convert::Infallible> {
+        let style = TextStyleBuilder::new(SpacedFont)
+            .text_color(BinaryColor::On)
+            .underline(true)
+            .strikethrough(true)
+            .decoration_color(BinaryColor::Off)
+            .build();
+
+        let mut display = MockDisplay::new();
+        Text::new("##", Point::zero())
+            .into_styled(style)
+            .draw(&mut display)?;
+
+        assert_eq!(
+            display,
+            MockDisplay::from_pattern(&[
+                "####     ####",
+                ".............",
+                " # #      # #",
+                ".............",
+            ])
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn decorations_inside_bounding_box() {
+        for &background_color in [None, Some(BinaryColor::Off)].iter() {
+            let style = TextStyle {
+                background_color,
+                ..TextStyleBuilder::new(SpacedFont)
+                    .text_color(BinaryColor::On)
+                    .underline(true)
+                    .strikethrough(true)
+                    .horizontal_alignment(HorizontalAlignment::Right)
+                    .build()
+            };
+
+            let text = Text::new("##\n#", Point::new(20, 0)).into_styled(style);
+            let bounding_box = text.bounding_box();
+
+            let (min, max) = text.into_iter().fold(
+                (Point::new(1000, 1000), Point::new(-1000, -1000)),
+                |(min, max), Pixel(p, _)| (min.component_min(p), max.component_max(p)),
+            );
+
+            assert_eq!(max.x, 20);
+            assert_eq!(bounding_box, Rectangle::with_corners(min, max));
+        }
+    }
+
+    #[test]
+    fn line_widths() {
+        let style = TextStyle::new(Font6x8, BinaryColor::On);
+
+        let text = Text::new("AB\nC\n", Point::zero()).into_styled(style);
+        assert_eq!(text.line_count(), 3);
+        assert!(text.line_widths().eq([12, 6, 0].iter().copied()));
+
+        let text = Text::new("", Point::zero()).into_styled(style);
+        assert_eq!(text.line_count(), 1);
+        assert!(text.line_widths().eq([0].iter().copied()));
+
+        let text = Text::new("###", Point::zero())
+            .into_styled(TextStyle::new(SpacedFont, BinaryColor::On));
+        assert!(text.line_widths().eq([4 * 3 + 5 * 2].iter().copied()));
+    }
+
+    #[test]
+    fn bounding_box_matches_lines() {
+        let style = TextStyle::new(Font6x8, BinaryColor::On);
+
+        // The trailing newline starts an empty line, which contains the caret after the newline.
+        let text = Text::new("a\n", Point::zero()).into_styled(style);
+        assert_eq!(text.line_count(), 2);
+        assert_eq!(
+            text.bounding_box(),
+            Rectangle::new(Point::zero(), Size::new(6, 16))
+        );
+        assert!(text.bounding_box().contains(text.char_position(2).unwrap()));
+
+        // `\r` isn't a line separator and is drawn like any other character.
+        let text = Text::new("a\r\nb", Point::zero()).into_styled(style);
+        assert_eq!(text.line_count(), 2);
+        assert!(text.line_widths().eq([12, 6].iter().copied()));
+        assert_eq!(
+            text.bounding_box(),
+            Rectangle::new(Point::zero(), Size::new(12, 16))
+        );
+
+        // The `\r` is drawn as a `?` in the first line.
+        assert!(text.into_iter().any(|Pixel(p, _)| p.x >= 6 && p.y < 8));
+        assert!(text
+            .into_iter()
+            .all(|Pixel(p, _)| text.bounding_box().contains(p)));
+    }
+
+    #[test]
+    fn char_position() {
+        let style = TextStyle::new(Font6x8, BinaryColor::On);
+        let text = Text::new("AB\nC", Point::new(10, 20)).into_styled(style);
+
+        assert_eq!(text.char_position(0), Some(Point::new(10, 20)));
+        assert_eq!(text.char_position(1), Some(Point::new(16, 20)));
+        assert_eq!(text.char_position(2), Some(Point::new(22, 20)));
+        assert_eq!(text.char_position(3), Some(Point::new(10, 28)));
+        assert_eq!(text.char_position(4), Some(Point::new(16, 28)));
+        assert_eq!(text.char_position(5), None);
+
+        let text =
+            Text::new("##", Point::zero()).into_styled(TextStyle::new(SpacedFont, BinaryColor::On));
+        assert_eq!(text.char_position(1), Some(Point::new(9, 0)));
+        assert_eq!(text.char_position(2), Some(Point::new(18, 0)));
+    }
+
+    #[test]
+    fn char_position_aligned_and_rotated() {
+        let style = TextStyleBuilder::new(Font6x8)
+            .text_color(BinaryColor::On)
+            .horizontal_alignment(HorizontalAlignment::Right)
+            .baseline(Baseline::Bottom)
+            .build();
+        let text = Text::new("AB", Point::new(30, 30)).into_styled(style);
+
+        assert_eq!(text.char_position(0), Some(Point::new(19, 23)));
+        assert_eq!(text.char_index_at(Point::new(19, 23)), Some(0));
+
+        let mut style = style;
+        style.rotation = TextRotation::Rotate90;
+        let text = Text::new("AB", Point::new(30, 30)).into_styled(style);
+
+        // The text is rotated around its position, which is the bottom right corner.
+        assert_eq!(text.char_position(0), Some(Point::new(37, 19)));
+        assert_eq!(text.char_position(1), Some(Point::new(37, 25)));
+        assert_eq!(text.char_index_at(Point::new(30, 20)), Some(0));
+        assert_eq!(text.char_index_at(Point::new(30, 26)), Some(1));
+    }
+
+    #[test]
+    fn char_index_at() {
+        let style = TextStyle::new(Font6x8, BinaryColor::On);
+        let text = Text::new("AB\nC", Point::new(10, 20)).into_styled(style);
+
+        assert_eq!(text.char_index_at(Point::new(10, 20)), Some(0));
+        assert_eq!(text.char_index_at(Point::new(15, 27)), Some(0));
+        assert_eq!(text.char_index_at(Point::new(16, 20)), Some(1));
+        assert_eq!(text.char_index_at(Point::new(10, 28)), Some(3));
+
+        // Points left and right of a line.
+        assert_eq!(text.char_index_at(Point::new(0, 20)), Some(0));
+        assert_eq!(text.char_index_at(Point::new(30, 20)), Some(2));
+        assert_eq!(text.char_index_at(Point::new(50, 29)), Some(4));
+
+        // Points above and below the text.
+        assert_eq!(text.char_index_at(Point::new(10, 19)), None);
+        assert_eq!(text.char_index_at(Point::new(10, 36)), None);
+
+        for index in 0..=4 {
+            let position = text.char_position(index).unwrap();
+            assert_eq!(text.char_index_at(position), Some(index));
+        }
+    }
 }
@@ -0,0 +1,713 @@
+use crate::{
+    drawable::{Drawable, Pixel, Spans},
+    fonts::{text::line_width, Font, StyledTextIterator, Text},
+    geometry::{Dimensions, Point},
+    pixelcolor::PixelColor,
+    primitives::{ContainsPoint, Rectangle},
+    style::{Baseline, HorizontalAlignment, Styled, TextRotation, TextStyle},
+    transform::Transform,
+    DrawTarget,
+};
+
+/// Text which is wrapped to fit into a rectangle.
+///
+/// Lines are wrapped at word boundaries to fit into the width of the `bounds` rectangle. Words
+/// which are too long to fit into a single line are broken between two characters, without adding
+/// a hyphen. Explicit line breaks (`\n`) in the text are honored.
+///
+/// The horizontal alignment of each line is defined by the [`horizontal_alignment`] of the text
+/// style, relative to the left and right edges of the box. The [`baseline`] setting of the style is
+/// ignored, use [`vertical_alignment`] to position the text inside the box instead. The
+/// [`rotation`] setting of the style is also ignored and the text is always drawn unrotated.
+///
+/// If the text doesn't fit into the box the [`overflow`] mode defines how the text is truncated.
+/// The part of the text which wasn't drawn completely can be retrieved by using
+/// [`overflowing_text`], for example to display it on the next page.
+///
+/// # Examples
+///
+/// ## Draw centered text into a box
+///
+/// ```rust
+/// use embedded_graphics::{
+///     fonts::{Font6x8, Overflow, TextBox, VerticalAlignment},
+///     pixelcolor::Rgb565,
+///     prelude::*,
+///     primitives::Rectangle,
+///     style::{HorizontalAlignment, TextStyleBuilder},
+/// };
+/// # use embedded_graphics::mock_display::MockDisplay;
+/// # let mut display: MockDisplay<Rgb565> = MockDisplay::default();
+///
+/// let style = TextStyleBuilder::new(Font6x8)
+///     .text_color(Rgb565::WHITE)
+///     .horizontal_alignment(HorizontalAlignment::Center)
+///     .build();
+///
+/// let text_box = TextBox {
+///     overflow: Overflow::Ellipsis,
+///     vertical_alignment: VerticalAlignment::Middle,
+///     ..TextBox::new(
+///         "Text which is too long for a single line is wrapped at word boundaries.",
+///         Rectangle::new(Point::new(2, 2), Size::new(60, 40)),
+///     )
+/// }
+/// .into_styled(style);
+///
+/// text_box.draw(&mut display)?;
+///
+/// // The last line didn't fit and was replaced by an ellipsis.
+/// assert!(text_box.overflowing_text().is_some());
+/// # Ok::<(), core::convert::Infallible>(())
+/// ```
+///
+/// [`horizontal_alignment`]: ../style/struct.TextStyle.html#structfield.horizontal_alignment
+/// [`baseline`]: ../style/struct.TextStyle.html#structfield.baseline
+/// [`rotation`]: ../style/struct.TextStyle.html#structfield.rotation
+/// [`vertical_alignment`]: #structfield.vertical_alignment
+/// [`overflow`]: #structfield.overflow
+/// [`overflowing_text`]: ../style/struct.Styled.html#method.overflowing_text
+#[derive(Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Debug)]
+pub struct TextBox<'a> {
+    /// The string.
+    pub text: &'a str,
+
+    /// The box the text is drawn into.
+    pub bounds: Rectangle,
+
+    /// Defines how text which doesn't fit into the box is handled.
+    pub overflow: Overflow,
+
+    /// The vertical alignment of the text inside the box.
+    pub vertical_alignment: VerticalAlignment,
+}
+
+impl<'a> TextBox<'a> {
+    /// Creates a text box.
+    ///
+    /// The text is aligned to the top of the box and text which doesn't fit into the box is
+    /// clipped.
+    pub const fn new(text: &'a str, bounds: Rectangle) -> Self {
+        Self {
+            text,
+            bounds,
+            overflow: Overflow::Clip,
+            vertical_alignment: VerticalAlignment::Top,
+        }
+    }
+
+    /// Attaches a text style to the text box.
+    pub fn into_styled<C, F>(self, style: TextStyle<C, F>) -> Styled<Self, TextStyle<C, F>>
+    where
+        C: PixelColor,
+        F: Font,
+    {
+        Styled::new(self, style)
+    }
+}
+
+impl Transform for TextBox<'_> {
+    fn translate(&self, by: Point) -> Self {
+        Self {
+            bounds: self.bounds.translate(by),
+            ..*self
+        }
+    }
+
+    fn translate_mut(&mut self, by: Point) -> &mut Self {
+        self.bounds.translate_mut(by);
+
+        self
+    }
+}
+
+/// Overflow mode of a text box.
+///
+/// The overflow mode defines how a [`TextBox`] handles text which doesn't fit into the box.
+///
+/// [`TextBox`]: struct.TextBox.html
+#[derive(Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Debug)]
+pub enum Overflow {
+    /// Clip the text at the edges of the box.
+    ///
+    /// All lines are drawn, but pixels outside the box are discarded. This can result in a partly
+    /// visible line at the bottom of the box.
+    Clip,
+
+    /// Replace the end of the last visible line by an ellipsis.
+    ///
+    /// Only lines which fit completely into the box are drawn. If the text doesn't fit into the box
+    /// the last visible line is shortened and ends with `...`.
+    Ellipsis,
+
+    /// Only draw complete lines.
+    ///
+    /// Only lines which fit completely into the box are drawn and the remaining text is not drawn
+    /// at all. The remaining text can be retrieved by using [`overflowing_text`], for example to
+    /// display it on the next page.
+    ///
+    /// [`overflowing_text`]: ../style/struct.Styled.html#method.overflowing_text
+    Report,
+}
+
+/// Vertical alignment of the text inside a text box.
+///
+/// Text which doesn't fit into the box is always aligned to the top of the box.
+#[derive(Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Debug)]
+pub enum VerticalAlignment {
+    /// Align the first line with the top of the box.
+    Top,
+
+    /// Center the text vertically inside the box.
+    Middle,
+
+    /// Align the last line with the bottom of the box.
+    Bottom,
+}
+
+/// The string which is appended to a truncated line in the `Ellipsis` overflow mode.
+const ELLIPSIS: &str = "...";
+
+/// Removes the spaces and up to one line break at the start of a text.
+fn skip_break(text: &str) -> &str {
+    let text = text.trim_start_matches(' ');
+
+    let mut chars = text.chars();
+    if chars.next() == Some('\n') {
+        chars.as_str()
+    } else {
+        text
+    }
+}
+
+/// Returns the first line of a word wrapped text and the remaining text.
+///
+/// Returns `None` if the text is empty. The returned line doesn't contain trailing spaces and the
+/// remaining text doesn't start with the spaces or line break which ended the line.
+fn next_line<'a, F>(font: &F, text: &'a str, max_width: u32) -> Option<(&'a str, &'a str)>
+where
+    F: Font,
+{
+    if text.is_empty() {
+        return None;
+    }
+
+    let mut width = 0;
+    let mut previous = None;
+    let mut in_word = false;
+    let mut word_end = None;
+    let mut word_break = None;
+
+    for (index, c) in text.char_indices() {
+        if c == '\n' {
+            return Some((text[..index].trim_end_matches(' '), &text[index + 1..]));
+        }
+
+        if c == ' ' {
+            if in_word {
+                word_end = Some(index);
+            }
+        } else if let Some(end) = word_end.take() {
+            word_break = Some((end, index));
+        }
+        in_word = c != ' ';
+
+        if let Some(previous) = previous {
+            width = (width as i32 + font.kerning(previous, c)).max(0) as u32;
+        }
+        previous = Some(c);
+
+        let char_width = font.glyph_metrics(c).advance;
+
+        if width + char_width > max_width {
+            if c == ' ' {
+                return Some((
+                    text[..index].trim_end_matches(' '),
+                    skip_break(&text[index..]),
+                ));
+            } else if let Some((end, start)) = word_break {
+                return Some((&text[..end], &text[start..]));
+            } else {
+                // Break the word if it is too long to fit into a single line. At least one
+                // character is always used to make sure that the text makes progress.
+                let index = if index > 0 { index } else { c.len_utf8() };
+
+                return Some((&text[..index], skip_break(&text[index..])));
+            }
+        }
+
+        width += char_width + font.character_spacing();
+    }
+
+    Some((text.trim_end_matches(' '), ""))
+}
+
+/// Returns the longest prefix of a line that can be followed by an ellipsis.
+fn ellipsis_prefix<'a, F>(font: &F, line: &'a str, max_width: u32) -> &'a str
+where
+    F: Font,
+{
+    let ellipsis_width = line_width(font, ELLIPSIS.chars()) + font.character_spacing();
+
+    let mut width = 0;
+    let mut end = 0;
+
+    for (index, c) in line.char_indices() {
+        width += font.glyph_metrics(c).advance;
+
+        if width + ellipsis_width > max_width {
+            break;
+        }
+
+        width += font.character_spacing();
+        end = index + c.len_utf8();
+    }
+
+    line[..end].trim_end_matches(' ')
+}
+
+impl<'a, C, F> Styled<TextBox<'a>, TextStyle<C, F>>
+where
+    C: PixelColor,
+    F: Font + Copy,
+{
+    /// Returns the number of lines after word wrapping.
+    ///
+    /// This includes lines which don't fit into the box.
+    pub fn line_count(&self) -> u32 {
+        let max_width = self.primitive.bounds.size.width;
+
+        let mut text = self.primitive.text;
+        let mut lines = 0;
+
+        while let Some((_, rest)) = next_line(&self.style.scaled_font(), text, max_width) {
+            text = rest;
+            lines += 1;
+        }
+
+        lines
+    }
+
+    /// Returns the part of the text which isn't drawn completely.
+    ///
+    /// The returned text starts with the first line which doesn't fit into the box or which is
+    /// shortened by an ellipsis. `None` is returned if the whole text fits into the box.
+    pub fn overflowing_text(&self) -> Option<&'a str> {
+        let max_width = self.primitive.bounds.size.width;
+
+        let mut lines = self.visible_lines();
+        if self.primitive.overflow == Overflow::Ellipsis && self.line_count() > lines {
+            lines = lines.saturating_sub(1);
+        }
+
+        let mut text = self.primitive.text;
+        for _ in 0..lines {
+            text = next_line(&self.style.scaled_font(), text, max_width)?.1;
+        }
+
+        if text.is_empty() {
+            None
+        } else {
+            Some(text)
+        }
+    }
+
+    /// Returns an iterator over the horizontal spans of the styled text box.
+    ///
+    /// Consecutive pixels in a row of a character which have the same color are combined into a
+    /// single span. Drawing the spans results in the same output as drawing the pixels of the
+    /// styled text box.
+    pub fn spans(&self) -> Spans<StyledTextBoxIterator<'a, C, F>, C> {
+        Spans::new(self)
+    }
+
+    /// Returns the maximum number of complete lines which fit into the box.
+    fn visible_lines(&self) -> u32 {
+        self.primitive.bounds.size.height / self.style.scaled_font().line_height().max(1)
+    }
+}
+
+impl<C, F> Dimensions for Styled<TextBox<'_>, TextStyle<C, F>>
+where
+    C: PixelColor,
+    F: Font,
+{
+    fn bounding_box(&self) -> Rectangle {
+        self.primitive.bounds
+    }
+}
+
+impl<'a, C, F> IntoIterator for &Styled<TextBox<'a>, TextStyle<C, F>>
+where
+    C: PixelColor,
+    F: Font + Copy,
+{
+    type Item = Pixel<C>;
+    type IntoIter = StyledTextBoxIterator<'a, C, F>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        let bounds = self.primitive.bounds;
+        let line_count = self.line_count();
+        let visible_lines = self.visible_lines();
+
+        let (drawn_lines, max_lines, ellipsis_line) = match self.primitive.overflow {
+            Overflow::Clip => (line_count, None, None),
+            Overflow::Ellipsis if line_count > visible_lines => (
+                visible_lines,
+                Some(visible_lines),
+                visible_lines.checked_sub(1),
+            ),
+            _ => (line_count.min(visible_lines), Some(visible_lines), None),
+        };
+
+        let free_space = bounds
+            .size
+            .height
+            .saturating_sub(drawn_lines * self.style.scaled_font().line_height());
+
+        let offset = match self.primitive.vertical_alignment {
+            VerticalAlignment::Top => 0,
+            VerticalAlignment::Middle => free_space / 2,
+            VerticalAlignment::Bottom => free_space,
+        };
+
+        StyledTextBoxIterator {
+            remaining: self.primitive.text,
+            bounds,
+            y: bounds.top_left.y + offset as i32,
+            line: 0,
+            max_lines,
+            ellipsis_line,
+            horizontal_alignment: self.style.horizontal_alignment,
+            style: TextStyle {
+                horizontal_alignment: HorizontalAlignment::Left,
+                baseline: Baseline::Top,
+                rotation: TextRotation::Rotate0,
+                ..self.style
+            },
+            text: None,
+            ellipsis: None,
+        }
+    }
+}
+
+impl<C, F> Drawable<C> for &Styled<TextBox<'_>, TextStyle<C, F>>
+where
+    C: PixelColor,
+    F: Font + Copy,
+{
+    fn draw<D: DrawTarget<C>>(self, display: &mut D) -> Result<(), D::Error> {
+        display.draw_spans(self.spans())
+    }
+}
+
+/// Pixel iterator for a styled text box.
+#[derive(Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Debug)]
+pub struct StyledTextBoxIterator<'a, C, F>
+where
+    C: PixelColor,
+    F: Font,
+{
+    remaining: &'a str,
+    bounds: Rectangle,
+    y: i32,
+    line: u32,
+    max_lines: Option<u32>,
+    ellipsis_line: Option<u32>,
+    horizontal_alignment: HorizontalAlignment,
+    style: TextStyle<C, F>,
+    text: Option<StyledTextIterator<'a, C, F>>,
+    ellipsis: Option<StyledTextIterator<'static, C, F>>,
+}
+
+impl<'a, C, F> StyledTextBoxIterator<'a, C, F>
+where
+    C: PixelColor,
+    F: Font + Copy,
+{
+    /// Starts drawing the next line.
+    ///
+    /// Returns `false` if there are no more lines to draw.
+    fn next_line(&mut self) -> bool {
+        if let Some(max_lines) = self.max_lines {
+            if self.line >= max_lines {
+                return false;
+            }
+        }
+
+        if self.y >= self.bounds.top_left.y + self.bounds.size.height as i32 {
+            return false;
+        }
+
+        let max_width = self.bounds.size.width;
+
+        let (mut line, rest) = match next_line(&self.style.scaled_font(), self.remaining, max_width)
+        {
+            Some(line) => line,
+            None => return false,
+        };
+
+        let is_ellipsis_line = self.ellipsis_line == Some(self.line);
+        if is_ellipsis_line {
+            line = ellipsis_prefix(&self.style.scaled_font(), line, max_width);
+        }
+
+        let mut width = line_width(&self.style.scaled_font(), line.chars());
+        if is_ellipsis_line {
+            let spacing = if line.is_empty() {
+                0
+            } else {
+                self.style.scaled_font().character_spacing()
+            };
+
+            width += spacing + line_width(&self.style.scaled_font(), ELLIPSIS.chars());
+        }
+
+        let free_space = max_width.saturating_sub(width) as i32;
+        let x = self.bounds.top_left.x
+            + match self.horizontal_alignment {
+                HorizontalAlignment::Left => 0,
+                HorizontalAlignment::Center => free_space / 2,
+                HorizontalAlignment::Right => free_space,
+            };
+
+        let position = Point::new(x, self.y);
+        self.text = Some(
+            Text::new(line, position)
+                .into_styled(self.style)
+                .into_iter(),
+        );
+
+        if is_ellipsis_line {
+            let offset = if line.is_empty() {
+                0
+            } else {
+                line_width(&self.style.scaled_font(), line.chars())
+                    + self.style.scaled_font().character_spacing()
+            };
+
+            self.ellipsis = Some(
+                Text::new(ELLIPSIS, position + Point::new(offset as i32, 0))
+                    .into_styled(self.style)
+                    .into_iter(),
+            );
+        }
+
+        self.remaining = rest;
+        self.line += 1;
+        self.y += self.style.scaled_font().line_height() as i32;
+
+        true
+    }
+}
+
+impl<C, F> Iterator for StyledTextBoxIterator<'_, C, F>
+where
+    C: PixelColor,
+    F: Font + Copy,
+{
+    type Item = Pixel<C>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        loop {
+            let pixel = self
+                .text
+                .as_mut()
+                .and_then(Iterator::next)
+                .or_else(|| self.ellipsis.as_mut().and_then(Iterator::next));
+
+            match pixel {
+                Some(Pixel(point, color)) => {
+                    if self.bounds.contains(point) {
+                        return Some(Pixel(point, color));
+                    }
+                }
+                None => {
+                    if !self.next_line() {
+                        return None;
+                    }
+                }
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        fonts::Font6x8, geometry::Size, mock_display::MockDisplay, pixelcolor::BinaryColor,
+        style::TextStyleBuilder,
+    };
+
+    /// Returns all lines of a word wrapped text.
+    fn lines(text: &str, max_width: u32) -> [&str; 8] {
+        let mut lines = [""; 8];
+
+        let mut text = text;
+        let mut index = 0;
+        while let Some((line, rest)) = next_line(&Font6x8, text, max_width) {
+            lines[index] = line;
+            text = rest;
+            index += 1;
+        }
+
+        lines
+    }
+
+    #[test]
+    fn word_wrapping() {
+        assert_eq!(
+            lines("Hello world, this is e-g", 6 * 12),
+            ["Hello world,", "this is e-g", "", "", "", "", "", ""]
+        );
+
+        // Explicit line breaks, empty lines and spaces at the end of a line
+        assert_eq!(
+            lines("abc    \ndef\n\n ghi jkl", 6 * 4),
+            ["abc", "def", "", " ghi", "jkl", "", "", ""]
+        );
+
+        // Long words are broken without a hyphen
+        assert_eq!(
+            lines("a abcdefghij", 6 * 4),
+            ["a", "abcd", "efgh", "ij", "", "", "", ""]
+        );
+
+        // At least one character is used per line
+        assert_eq!(lines("ab", 0), ["a", "b", "", "", "", "", "", ""]);
+
+        assert_eq!(next_line(&Font6x8, "", 100), None);
+    }
+
+    #[test]
+    fn ellipsis() {
+        assert_eq!(ellipsis_prefix(&Font6x8, "abc def", 6 * 7), "abc");
+        assert_eq!(ellipsis_prefix(&Font6x8, "abcdef", 6 * 7), "abcd");
+        assert_eq!(ellipsis_prefix(&Font6x8, "abc", 6 * 2), "");
+    }
+
+    #[test]
+    fn overflow_modes() {
+        let style = TextStyleBuilder::new(Font6x8)
+            .text_color(BinaryColor::On)
+            .build();
+
+        let text_box = |overflow| {
+            TextBox {
+                overflow,
+                ..TextBox::new(
+                    "one two three four",
+                    Rectangle::new(Point::zero(), Size::new(6 * 5, 8 * 2 + 4)),
+                )
+            }
+            .into_styled(style)
+        };
+
+        let clip = text_box(Overflow::Clip);
+        assert_eq!(clip.line_count(), 4);
+        assert_eq!(clip.overflowing_text(), Some("three four"));
+        assert!(clip.into_iter().any(|Pixel(p, _)| p.y == 16));
+        assert!(clip.into_iter().all(|Pixel(p, _)| p.y < 20));
+
+        let report = text_box(Overflow::Report);
+        assert_eq!(report.overflowing_text(), Some("three four"));
+        assert!(report.into_iter().all(|Pixel(p, _)| p.y < 16));
+
+        let ellipsis = text_box(Overflow::Ellipsis);
+        assert_eq!(ellipsis.overflowing_text(), Some("two three four"));
+
+        let mut display = MockDisplay::new();
+        ellipsis.draw(&mut display).unwrap();
+
+        let mut expected = MockDisplay::new();
+        Text::new("one\ntw...", Point::zero())
+            .into_styled(style)
+            .draw(&mut expected)
+            .unwrap();
+
+        assert_eq!(display, expected);
+
+        let fits = TextBox::new(
+            "one two",
+            Rectangle::new(Point::zero(), Size::new(6 * 5, 8 * 2)),
+        )
+        .into_styled(style);
+        assert_eq!(fits.overflowing_text(), None);
+    }
+
+    #[test]
+    fn alignment() -> Result<(), core::convert::Infallible> {
+        let style = TextStyleBuilder::new(Font6x8)
+            .text_color(BinaryColor::On)
+            .horizontal_alignment(HorizontalAlignment::Right)
+            .build();
+
+        let mut display = MockDisplay::new();
+        TextBox {
+            vertical_alignment: VerticalAlignment::Bottom,
+            ..TextBox::new(
+                "ab c",
+                Rectangle::new(Point::new(1, 2), Size::new(6 * 3, 8 * 3)),
+            )
+        }
+        .into_styled(style)
+        .draw(&mut display)?;
+
+        let mut expected = MockDisplay::new();
+        Text::new("ab\n c", Point::new(7, 10))
+            .into_styled(TextStyle::new(Font6x8, BinaryColor::On))
+            .draw(&mut expected)?;
+
+        assert_eq!(display, expected);
+
+        let style = TextStyleBuilder::new(Font6x8)
+            .text_color(BinaryColor::On)
+            .background_color(BinaryColor::Off)
+            .horizontal_alignment(HorizontalAlignment::Center)
+            .build();
+
+        let text_box = TextBox {
+            vertical_alignment: VerticalAlignment::Middle,
+            ..TextBox::new("a", Rectangle::new(Point::new(1, 2), Size::new(10, 20)))
+        }
+        .into_styled(style);
+
+        let (min, max) = text_box.into_iter().fold(
+            (Point::new(1000, 1000), Point::new(-1000, -1000)),
+            |(min, max), Pixel(p, _)| (min.component_min(p), max.component_max(p)),
+        );
+        assert_eq!(min, Point::new(3, 8));
+        assert_eq!(max, Point::new(8, 15));
+
+        Ok(())
+    }
+
+    #[test]
+    fn rotation_is_ignored() -> Result<(), core::convert::Infallible> {
+        let text_box = TextBox::new(
+            "Rotated text",
+            Rectangle::new(Point::new(1, 2), Size::new(6 * 8, 8 * 3)),
+        );
+
+        let style = TextStyleBuilder::new(Font6x8)
+            .text_color(BinaryColor::On)
+            .background_color(BinaryColor::Off)
+            .build();
+
+        let mut display = MockDisplay::new();
+        text_box
+            .into_styled(TextStyle {
+                rotation: TextRotation::Rotate90,
+                ..style
+            })
+            .draw(&mut display)?;
+
+        let mut expected = MockDisplay::new();
+        expected.draw_iter(&text_box.into_styled(style))?;
+
+        assert_eq!(display, expected);
+
+        Ok(())
+    }
+}
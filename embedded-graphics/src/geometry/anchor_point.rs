@@ -0,0 +1,65 @@
+/// Anchor point.
+///
+/// Anchor points are used to refer to points on the edges or the center of a [`Rectangle`], for
+/// example to resize a rectangle while keeping one of its corners fixed.
+///
+/// [`Rectangle`]: ../primitives/rectangle/struct.Rectangle.html
+#[derive(Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Debug)]
+pub enum AnchorPoint {
+    /// Top left.
+    TopLeft,
+    /// Top center.
+    TopCenter,
+    /// Top right.
+    TopRight,
+    /// Center left.
+    CenterLeft,
+    /// Center.
+    Center,
+    /// Center right.
+    CenterRight,
+    /// Bottom left.
+    BottomLeft,
+    /// Bottom center.
+    BottomCenter,
+    /// Bottom right.
+    BottomRight,
+}
+
+/// Position of an anchor point along one axis.
+#[derive(Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Debug)]
+pub(crate) enum AxisAnchor {
+    Start,
+    Center,
+    End,
+}
+
+impl AnchorPoint {
+    /// Returns the horizontal and vertical position of the anchor point.
+    pub(crate) fn axes(self) -> (AxisAnchor, AxisAnchor) {
+        use AxisAnchor::{Center, End, Start};
+
+        match self {
+            AnchorPoint::TopLeft => (Start, Start),
+            AnchorPoint::TopCenter => (Center, Start),
+            AnchorPoint::TopRight => (End, Start),
+            AnchorPoint::CenterLeft => (Start, Center),
+            AnchorPoint::Center => (Center, Center),
+            AnchorPoint::CenterRight => (End, Center),
+            AnchorPoint::BottomLeft => (Start, End),
+            AnchorPoint::BottomCenter => (Center, End),
+            AnchorPoint::BottomRight => (End, End),
+        }
+    }
+}
+
+impl AxisAnchor {
+    /// Returns the offset of the anchor from the start of a line with the given length.
+    pub(crate) fn offset(self, length: u32) -> i32 {
+        match self {
+            AxisAnchor::Start => 0,
+            AxisAnchor::Center => (length.saturating_sub(1) / 2) as i32,
+            AxisAnchor::End => length.saturating_sub(1) as i32,
+        }
+    }
+}
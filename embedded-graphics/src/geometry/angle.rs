@@ -0,0 +1,388 @@
+use core::ops::{Add, AddAssign, Div, Mul, Neg, Sub, SubAssign};
+
+/// Number of fractional bits in the fixed point representation.
+const FRACTIONAL_BITS: u32 = 16;
+
+/// Fixed point representation of `1.0`.
+const ONE: i32 = 1 << FRACTIONAL_BITS;
+
+/// Full turn in the internal representation.
+const FULL_TURN: i32 = 360 * ONE;
+
+/// Largest multiple of a full turn, in degrees, which fits into the internal representation.
+///
+/// Angles outside of this range are reduced by multiples of this value, which doesn't change
+/// the direction of the angle.
+const MAX_DEGREES: i32 = 90 * 360;
+
+/// Values of `sin(x)` for `x` in `0..=90` degrees in 16.16 fixed point format.
+const SIN_TABLE: [i32; 91] = [
+    0, 1144, 2287, 3430, 4572, 5712, 6850, 7987, 9121, 10252, 11380, 12505, 13626, 14742, 15855,
+    16962, 18064, 19161, 20252, 21336, 22415, 23486, 24550, 25607, 26656, 27697, 28729, 29753,
+    30767, 31772, 32768, 33754, 34729, 35693, 36647, 37590, 38521, 39441, 40348, 41243, 42126,
+    42995, 43852, 44695, 45525, 46341, 47143, 47930, 48703, 49461, 50203, 50931, 51643, 52339,
+    53020, 53684, 54332, 54963, 55578, 56175, 56756, 57319, 57865, 58393, 58903, 59396, 59870,
+    60326, 60764, 61183, 61584, 61966, 62328, 62672, 62997, 63303, 63589, 63856, 64104, 64332,
+    64540, 64729, 64898, 65048, 65177, 65287, 65376, 65446, 65496, 65526, 65536,
+];
+
+/// Angle.
+///
+/// `Angle` stores an angle in degrees as a fixed point number with 16 fractional bits, which
+/// allows angles between about ±32767° to be represented with a resolution of 1/65536°. The
+/// constructors and arithmetic operators reduce angles outside of ±32400°, which are 90 full
+/// turns, by whole multiples of 32400° to an equivalent angle inside this range. This makes it
+/// possible to keep adding an increment to an angle, for example to animate a clock hand.
+///
+/// The trigonometric functions [`sin`], [`cos`] and [`tan`] use a lookup table with linear
+/// interpolation and don't require floating point support. Their results are returned as 16.16
+/// fixed point numbers, which means that `65536` represents `1.0`.
+///
+/// # Examples
+///
+/// ## Calculate the position of a clock hand
+///
+/// ```rust
+/// use embedded_graphics::{geometry::Angle, prelude::*};
+///
+/// let center = Point::new(32, 32);
+/// let length = 20;
+///
+/// // The minute hand at 10 minutes past the hour
+/// let angle = Angle::from_degrees(10 * 360 / 60);
+///
+/// let end = center
+///     + Point::new(
+///         (angle.sin() * length) >> 16,
+///         -(angle.cos() * length) >> 16,
+///     );
+///
+/// assert_eq!(end, Point::new(49, 22));
+/// ```
+///
+/// [`sin`]: #method.sin
+/// [`cos`]: #method.cos
+/// [`tan`]: #method.tan
+#[derive(Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Debug, Default)]
+pub struct Angle(i32);
+
+impl Angle {
+    /// Creates an angle of zero degrees.
+    pub const fn zero() -> Self {
+        Self(0)
+    }
+
+    /// Creates an angle from a value in degrees.
+    ///
+    /// Angles outside of ±32400° are reduced to an equivalent angle inside this range.
+    pub const fn from_degrees(degrees: i32) -> Self {
+        Self((degrees % MAX_DEGREES) * ONE)
+    }
+
+    /// Creates an angle from a fraction of degrees.
+    ///
+    /// The resulting angle is `numerator / denominator` degrees, for example
+    /// `Angle::from_fractional_degrees(45, 2)` is an angle of 22.5°.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `denominator` is zero.
+    pub fn from_fractional_degrees(numerator: i32, denominator: i32) -> Self {
+        Self::reduce(i64::from(numerator) * i64::from(ONE) / i64::from(denominator))
+    }
+
+    /// Creates an angle from a value in radians.
+    ///
+    /// This only uses a floating point multiplication and doesn't require any math library.
+    pub fn from_radians(radians: f32) -> Self {
+        let degrees = radians * (180.0 / core::f32::consts::PI);
+
+        Self::reduce((degrees * ONE as f32) as i64)
+    }
+
+    /// Creates an angle from a 16.16 fixed point value which might be outside of ±32400°.
+    fn reduce(angle: i64) -> Self {
+        Self((angle % i64::from(MAX_DEGREES * ONE)) as i32)
+    }
+
+    /// Returns the angle in whole degrees, rounded towards negative infinity.
+    pub const fn to_degrees(self) -> i32 {
+        self.0 >> FRACTIONAL_BITS
+    }
+
+    /// Returns the angle in radians.
+    pub fn to_radians(self) -> f32 {
+        self.0 as f32 / ONE as f32 * (core::f32::consts::PI / 180.0)
+    }
+
+    /// Returns an equivalent angle in the range `0°..360°`.
+    pub fn normalized(self) -> Self {
+        Self(self.0.rem_euclid(FULL_TURN))
+    }
+
+    /// Returns the sine of the angle as a 16.16 fixed point number.
+    pub fn sin(self) -> i32 {
+        let angle = self.normalized().0;
+
+        if angle <= 90 * ONE {
+            sin_first_quadrant(angle)
+        } else if angle <= 180 * ONE {
+            sin_first_quadrant(180 * ONE - angle)
+        } else if angle <= 270 * ONE {
+            -sin_first_quadrant(angle - 180 * ONE)
+        } else {
+            -sin_first_quadrant(FULL_TURN - angle)
+        }
+    }
+
+    /// Returns the cosine of the angle as a 16.16 fixed point number.
+    pub fn cos(self) -> i32 {
+        Self(self.normalized().0 + 90 * ONE).sin()
+    }
+
+    /// Returns the tangent of the angle as a 16.16 fixed point number.
+    ///
+    /// `None` is returned if the tangent is undefined or doesn't fit into the return type.
+    pub fn tan(self) -> Option<i32> {
+        let tan = (i64::from(self.sin()) << FRACTIONAL_BITS).checked_div(i64::from(self.cos()))?;
+
+        if tan.abs() <= i64::from(i32::max_value()) {
+            Some(tan as i32)
+        } else {
+            None
+        }
+    }
+}
+
+/// Returns the sine for an angle in the range `0°..=90°`.
+fn sin_first_quadrant(angle: i32) -> i32 {
+    let index = (angle >> FRACTIONAL_BITS) as usize;
+    let fraction = angle & (ONE - 1);
+
+    if fraction == 0 {
+        return SIN_TABLE[index];
+    }
+
+    let start = SIN_TABLE[index];
+    let delta = SIN_TABLE[index + 1] - start;
+
+    start + ((delta * fraction + ONE / 2) >> FRACTIONAL_BITS)
+}
+
+impl Add for Angle {
+    type Output = Angle;
+
+    fn add(self, other: Angle) -> Angle {
+        Angle::reduce(i64::from(self.0) + i64::from(other.0))
+    }
+}
+
+impl AddAssign for Angle {
+    fn add_assign(&mut self, other: Angle) {
+        *self = *self + other;
+    }
+}
+
+impl Sub for Angle {
+    type Output = Angle;
+
+    fn sub(self, other: Angle) -> Angle {
+        Angle::reduce(i64::from(self.0) - i64::from(other.0))
+    }
+}
+
+impl SubAssign for Angle {
+    fn sub_assign(&mut self, other: Angle) {
+        *self = *self - other;
+    }
+}
+
+impl Neg for Angle {
+    type Output = Angle;
+
+    fn neg(self) -> Angle {
+        Angle::reduce(-i64::from(self.0))
+    }
+}
+
+impl Mul<i32> for Angle {
+    type Output = Angle;
+
+    fn mul(self, rhs: i32) -> Angle {
+        Angle::reduce(i64::from(self.0) * i64::from(rhs))
+    }
+}
+
+/// Divides an angle by an integer.
+///
+/// # Panics
+///
+/// Panics if `rhs` is zero.
+impl Div<i32> for Angle {
+    type Output = Angle;
+
+    fn div(self, rhs: i32) -> Angle {
+        Angle::reduce(i64::from(self.0) / i64::from(rhs))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn constructors() {
+        assert_eq!(Angle::from_degrees(90).to_degrees(), 90);
+        assert_eq!(Angle::from_degrees(-90).to_degrees(), -90);
+        assert_eq!(
+            Angle::from_fractional_degrees(45, 2),
+            Angle::from_degrees(45) / 2
+        );
+        assert_eq!(Angle::from_radians(core::f32::consts::PI).to_degrees(), 180);
+        assert_eq!(Angle::from_radians(-1.0).to_degrees(), -58);
+
+        let error = Angle::from_degrees(90).to_radians() - core::f32::consts::FRAC_PI_2;
+        assert!(error < 1e-6 && error > -1e-6);
+    }
+
+    #[test]
+    fn large_angles() {
+        assert_eq!(Angle::from_degrees(32400).to_degrees(), 0);
+        assert_eq!(Angle::from_degrees(32399).to_degrees(), 32399);
+        assert_eq!(
+            Angle::from_degrees(100_000).normalized(),
+            Angle::from_degrees(100_000 % 360)
+        );
+        assert_eq!(
+            Angle::from_degrees(i32::min_value()).normalized(),
+            Angle::from_degrees(i32::min_value() % 360).normalized()
+        );
+        assert_eq!(
+            Angle::from_fractional_degrees(i32::max_value(), 1).normalized(),
+            Angle::from_degrees(i32::max_value() % 360)
+        );
+        assert!(Angle::from_radians(1.0e6).to_degrees().abs() < 32400);
+    }
+
+    #[test]
+    fn division() {
+        assert_eq!(Angle::from_degrees(90) / 2, Angle::from_degrees(45));
+        assert_eq!(Angle::from_degrees(90) / -1, Angle::from_degrees(-90));
+        assert_eq!(
+            Angle::from_degrees(-32399) / i32::min_value(),
+            Angle::zero()
+        );
+    }
+
+    #[test]
+    #[should_panic]
+    fn division_by_zero() {
+        let _ = Angle::from_degrees(90) / 0;
+    }
+
+    #[test]
+    fn accumulate_past_range() {
+        let step = Angle::from_degrees(6);
+
+        let mut angle = Angle::zero();
+        for _ in 0..100 * 60 {
+            angle += step;
+        }
+        assert_eq!(angle.normalized(), Angle::zero());
+        assert!(angle.to_degrees().abs() < 32400);
+
+        let mut angle = Angle::zero();
+        for i in 0..100 * 60 {
+            angle -= step;
+            assert_eq!(angle, -(step * (i + 1)));
+        }
+        assert_eq!(angle.normalized(), Angle::zero());
+
+        let large = Angle::from_degrees(32000);
+        assert_eq!((large + large).to_degrees(), 64000 - 32400);
+        assert_eq!((-large - large).to_degrees(), -(64000 - 32400));
+        assert_eq!(
+            (large * 1000).normalized(),
+            Angle::from_degrees(32000 * 1000 % 360)
+        );
+    }
+
+    #[test]
+    fn normalized() {
+        assert_eq!(Angle::from_degrees(0).normalized(), Angle::zero());
+        assert_eq!(Angle::from_degrees(360).normalized(), Angle::zero());
+        assert_eq!(
+            Angle::from_degrees(-90).normalized(),
+            Angle::from_degrees(270)
+        );
+        assert_eq!(
+            Angle::from_degrees(725).normalized(),
+            Angle::from_degrees(5)
+        );
+    }
+
+    #[test]
+    fn arithmetic() {
+        let mut angle = Angle::from_degrees(10) + Angle::from_degrees(20);
+        assert_eq!(angle, Angle::from_degrees(30));
+
+        angle -= Angle::from_degrees(40);
+        assert_eq!(angle, -Angle::from_degrees(10));
+
+        angle += Angle::from_degrees(15);
+        assert_eq!(angle * 3, Angle::from_degrees(15));
+        assert_eq!(angle - Angle::from_degrees(5), Angle::zero());
+    }
+
+    #[test]
+    fn sin_cos_exact() {
+        assert_eq!(Angle::from_degrees(0).sin(), 0);
+        assert_eq!(Angle::from_degrees(30).sin(), ONE / 2);
+        assert_eq!(Angle::from_degrees(90).sin(), ONE);
+        assert_eq!(Angle::from_degrees(180).sin(), 0);
+        assert_eq!(Angle::from_degrees(270).sin(), -ONE);
+        assert_eq!(Angle::from_degrees(-30).sin(), -ONE / 2);
+        assert_eq!(Angle::from_degrees(0).cos(), ONE);
+        assert_eq!(Angle::from_degrees(60).cos(), ONE / 2);
+        assert_eq!(Angle::from_degrees(180).cos(), -ONE);
+        assert_eq!(Angle::from_degrees(-90).cos(), 0);
+    }
+
+    #[test]
+    fn sin_cos_interpolated() {
+        // Expected values are `round(sin(angle) * 65536)`.
+        let values = [
+            (Angle::from_fractional_degrees(45, 2).sin(), 25080),
+            (Angle::from_fractional_degrees(45, 2).cos(), 60547),
+            (Angle::from_fractional_degrees(-401, 4).sin(), -64490),
+            (Angle::from_fractional_degrees(1, 3).sin(), 381),
+        ];
+
+        for (actual, expected) in values.iter() {
+            assert!((actual - expected).abs() <= 3, "{} != {}", actual, expected);
+        }
+    }
+
+    #[test]
+    fn sin_cos_identity() {
+        for tenth_degrees in -3600..=3600 {
+            let angle = Angle::from_fractional_degrees(tenth_degrees, 10);
+
+            let sin = i64::from(angle.sin());
+            let cos = i64::from(angle.cos());
+            let one = i64::from(ONE);
+
+            let error = (sin * sin + cos * cos - one * one) / one;
+            assert!(error.abs() <= 6, "{:?}: {}", angle, error);
+        }
+    }
+
+    #[test]
+    fn tan() {
+        assert_eq!(Angle::from_degrees(0).tan(), Some(0));
+        assert_eq!(Angle::from_degrees(45).tan(), Some(ONE));
+        assert_eq!(Angle::from_degrees(-45).tan(), Some(-ONE));
+        assert_eq!(Angle::from_degrees(90).tan(), None);
+        assert_eq!(Angle::from_degrees(270).tan(), None);
+    }
+}
@@ -1,10 +1,17 @@
 //! Geometry module.
 
+mod anchor_point;
+mod angle;
 mod point;
 mod size;
+mod subpixel_point;
 
+pub use anchor_point::AnchorPoint;
+pub(crate) use anchor_point::AxisAnchor;
+pub use angle::Angle;
 pub use point::Point;
 pub use size::Size;
+pub use subpixel_point::SubpixelPoint;
 
 use crate::primitives::Rectangle;
 
@@ -13,7 +20,134 @@ use crate::primitives::Rectangle;
 /// This **should** be implemented for all builtin embedded-graphics primitives and fonts. Third party
 /// implementations do not have to implement this trait as an object may not have a known size. If
 /// the object _does_ have a known size, this trait **should** be implemented.
+///
+/// `Dimensions` is also implemented for groups of objects, like tuples, arrays, slices and
+/// `Option`s. The bounding box of a group is the smallest rectangle that contains the bounding
+/// boxes of all objects in the group. Empty groups and `None` return a zero sized rectangle at the
+/// origin, which is ignored when the bounding boxes are combined.
 pub trait Dimensions {
     /// Returns the bounding box.
     fn bounding_box(&self) -> Rectangle;
 }
+
+/// Bounding box of an empty group of objects.
+const EMPTY_BOUNDING_BOX: Rectangle = Rectangle::new(Point::zero(), Size::zero());
+
+impl<T> Dimensions for &T
+where
+    T: Dimensions + ?Sized,
+{
+    fn bounding_box(&self) -> Rectangle {
+        (**self).bounding_box()
+    }
+}
+
+impl<T> Dimensions for Option<T>
+where
+    T: Dimensions,
+{
+    fn bounding_box(&self) -> Rectangle {
+        self.as_ref()
+            .map_or(EMPTY_BOUNDING_BOX, Dimensions::bounding_box)
+    }
+}
+
+impl<T> Dimensions for [T]
+where
+    T: Dimensions,
+{
+    fn bounding_box(&self) -> Rectangle {
+        self.iter().fold(EMPTY_BOUNDING_BOX, |bounding_box, item| {
+            bounding_box.envelope(&item.bounding_box())
+        })
+    }
+}
+
+/// Implements `Dimensions` for arrays.
+macro_rules! dimensions_array {
+    ($($n:expr),*) => {
+        $(
+            impl<T> Dimensions for [T; $n]
+            where
+                T: Dimensions,
+            {
+                fn bounding_box(&self) -> Rectangle {
+                    self[..].bounding_box()
+                }
+            }
+        )*
+    };
+}
+
+dimensions_array!(
+    0, 1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12, 13, 14, 15, 16, 17, 18, 19, 20, 21, 22, 23, 24, 25,
+    26, 27, 28, 29, 30, 31, 32
+);
+
+/// Implements `Dimensions` for tuples.
+macro_rules! dimensions_tuple {
+    ($(($($t:ident: $i:tt),+)),*) => {
+        $(
+            impl<$($t),+> Dimensions for ($($t,)+)
+            where
+                $($t: Dimensions,)+
+            {
+                fn bounding_box(&self) -> Rectangle {
+                    EMPTY_BOUNDING_BOX$(.envelope(&self.$i.bounding_box()))+
+                }
+            }
+        )*
+    };
+}
+
+dimensions_tuple!(
+    (T1: 0),
+    (T1: 0, T2: 1),
+    (T1: 0, T2: 1, T3: 2),
+    (T1: 0, T2: 1, T3: 2, T4: 3),
+    (T1: 0, T2: 1, T3: 2, T4: 3, T5: 4),
+    (T1: 0, T2: 1, T3: 2, T4: 3, T5: 4, T6: 5),
+    (T1: 0, T2: 1, T3: 2, T4: 3, T5: 4, T6: 5, T7: 6),
+    (T1: 0, T2: 1, T3: 2, T4: 3, T5: 4, T6: 5, T7: 6, T8: 7),
+    (T1: 0, T2: 1, T3: 2, T4: 3, T5: 4, T6: 5, T7: 6, T8: 7, T9: 8),
+    (T1: 0, T2: 1, T3: 2, T4: 3, T5: 4, T6: 5, T7: 6, T8: 7, T9: 8, T10: 9),
+    (T1: 0, T2: 1, T3: 2, T4: 3, T5: 4, T6: 5, T7: 6, T8: 7, T9: 8, T10: 9, T11: 10),
+    (T1: 0, T2: 1, T3: 2, T4: 3, T5: 4, T6: 5, T7: 6, T8: 7, T9: 8, T10: 9, T11: 10, T12: 11)
+);
+
+/// Integer square root.
+///
+/// Returns the largest integer `r` for which `r * r <= value`.
+pub(crate) fn isqrt(value: u64) -> u32 {
+    if value < 2 {
+        return value as u32;
+    }
+
+    // Newton's method, starting with an estimate that is guaranteed to be too large.
+    let mut x = 1u64 << (32 - value.leading_zeros() / 2);
+    loop {
+        let y = (x + value / x) / 2;
+        if y >= x {
+            return x as u32;
+        }
+        x = y;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn integer_square_root() {
+        for value in 0..10_000u64 {
+            let root = u64::from(isqrt(value));
+
+            assert!(root * root <= value, "{}", value);
+            assert!((root + 1) * (root + 1) > value, "{}", value);
+        }
+
+        assert_eq!(isqrt(u64::from(u32::max_value()).pow(2)), u32::max_value());
+        assert_eq!(isqrt(u64::max_value()), u32::max_value());
+    }
+}
@@ -15,6 +15,13 @@ use core::{
 /// of Nalgebra's [`Vector2`] with embedded-graphics where `i8`, `i16`, `i32`, `u16` or `u8` is used
 /// for value storage.
 ///
+/// Two-way conversions to the point and vector types of other crates can be enabled with the
+/// following features:
+///
+/// * `mint_support`: [`mint`]'s `Point2<i32>` and `Vector2<i32>`
+/// * `euclid_support`: [`euclid`]'s `Point2D<i32, U>` and `Vector2D<i32, U>`
+/// * `glam_support`: [`glam`]'s `IVec2`
+///
 /// # Examples
 ///
 /// ## Create a `Point` from two integers
@@ -63,7 +70,14 @@ use core::{
 /// [`Vector2<N>`]: https://docs.rs/nalgebra/0.18.0/nalgebra/base/type.Vector2.html
 /// [`Vector2`]: https://docs.rs/nalgebra/0.18.0/nalgebra/base/type.Vector2.html
 /// [Nalgebra]: https://docs.rs/nalgebra
+/// [`mint`]: https://docs.rs/mint
+/// [`euclid`]: https://docs.rs/euclid
+/// [`glam`]: https://docs.rs/glam
 #[derive(Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Debug, Default)]
+#[cfg_attr(
+    feature = "serde_support",
+    derive(serde::Serialize, serde::Deserialize)
+)]
 pub struct Point {
     /// The x coordinate.
     pub x: i32,
@@ -95,6 +109,32 @@ impl Point {
     pub fn abs(self) -> Self {
         Point::new(self.x.abs(), self.y.abs())
     }
+
+    /// Returns the componentwise minimum of two `Point`s
+    ///
+    /// ```
+    /// # use embedded_graphics::geometry::Point;
+    /// #
+    /// let min = Point::new(20, 30).component_min(Point::new(15, 50));
+    ///
+    /// assert_eq!(min, Point::new(15, 30));
+    /// ```
+    pub fn component_min(self, other: Self) -> Self {
+        Point::new(self.x.min(other.x), self.y.min(other.y))
+    }
+
+    /// Returns the componentwise maximum of two `Point`s
+    ///
+    /// ```
+    /// # use embedded_graphics::geometry::Point;
+    /// #
+    /// let max = Point::new(20, 30).component_max(Point::new(15, 50));
+    ///
+    /// assert_eq!(max, Point::new(20, 50));
+    /// ```
+    pub fn component_max(self, other: Self) -> Self {
+        Point::new(self.x.max(other.x), self.y.max(other.y))
+    }
 }
 
 impl Add for Point {
@@ -363,6 +403,82 @@ where
     }
 }
 
+#[cfg(feature = "mint_support")]
+impl From<mint::Point2<i32>> for Point {
+    fn from(other: mint::Point2<i32>) -> Self {
+        Self::new(other.x, other.y)
+    }
+}
+
+#[cfg(feature = "mint_support")]
+impl From<Point> for mint::Point2<i32> {
+    fn from(other: Point) -> Self {
+        Self {
+            x: other.x,
+            y: other.y,
+        }
+    }
+}
+
+#[cfg(feature = "mint_support")]
+impl From<mint::Vector2<i32>> for Point {
+    fn from(other: mint::Vector2<i32>) -> Self {
+        Self::new(other.x, other.y)
+    }
+}
+
+#[cfg(feature = "mint_support")]
+impl From<Point> for mint::Vector2<i32> {
+    fn from(other: Point) -> Self {
+        Self {
+            x: other.x,
+            y: other.y,
+        }
+    }
+}
+
+#[cfg(feature = "euclid_support")]
+impl<U> From<euclid::Point2D<i32, U>> for Point {
+    fn from(other: euclid::Point2D<i32, U>) -> Self {
+        Self::new(other.x, other.y)
+    }
+}
+
+#[cfg(feature = "euclid_support")]
+impl<U> From<Point> for euclid::Point2D<i32, U> {
+    fn from(other: Point) -> Self {
+        Self::new(other.x, other.y)
+    }
+}
+
+#[cfg(feature = "euclid_support")]
+impl<U> From<euclid::Vector2D<i32, U>> for Point {
+    fn from(other: euclid::Vector2D<i32, U>) -> Self {
+        Self::new(other.x, other.y)
+    }
+}
+
+#[cfg(feature = "euclid_support")]
+impl<U> From<Point> for euclid::Vector2D<i32, U> {
+    fn from(other: Point) -> Self {
+        Self::new(other.x, other.y)
+    }
+}
+
+#[cfg(feature = "glam_support")]
+impl From<glam::IVec2> for Point {
+    fn from(other: glam::IVec2) -> Self {
+        Self::new(other.x, other.y)
+    }
+}
+
+#[cfg(feature = "glam_support")]
+impl From<Point> for glam::IVec2 {
+    fn from(other: Point) -> Self {
+        Self::new(other.x, other.y)
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -611,4 +727,63 @@ mod tests {
 
         assert_eq!(Point::from(&c), Point::new(20, 20));
     }
+
+    #[test]
+    #[cfg(feature = "mint_support")]
+    fn mint_support() {
+        let point = Point::new(10, -20);
+
+        let mint_point: mint::Point2<i32> = point.into();
+        assert_eq!(mint_point, mint::Point2 { x: 10, y: -20 });
+        assert_eq!(Point::from(mint_point), point);
+
+        let mint_vector: mint::Vector2<i32> = point.into();
+        assert_eq!(mint_vector, mint::Vector2 { x: 10, y: -20 });
+        assert_eq!(Point::from(mint_vector), point);
+    }
+
+    #[test]
+    #[cfg(feature = "euclid_support")]
+    fn euclid_support() {
+        let point = Point::new(10, -20);
+
+        let euclid_point: euclid::default::Point2D<i32> = point.into();
+        assert_eq!(euclid_point, euclid::point2(10, -20));
+        assert_eq!(Point::from(euclid_point), point);
+
+        let euclid_vector: euclid::default::Vector2D<i32> = point.into();
+        assert_eq!(euclid_vector, euclid::vec2(10, -20));
+        assert_eq!(Point::from(euclid_vector), point);
+    }
+
+    #[test]
+    #[cfg(feature = "glam_support")]
+    fn glam_support() {
+        let point = Point::new(10, -20);
+
+        let glam_vector: glam::IVec2 = point.into();
+        assert_eq!(glam_vector, glam::ivec2(10, -20));
+        assert_eq!(Point::from(glam_vector), point);
+    }
+
+    #[test]
+    #[cfg(feature = "serde_support")]
+    fn serde_support() {
+        use serde_test::{assert_tokens, Token};
+
+        assert_tokens(
+            &Point::new(10, -20),
+            &[
+                Token::Struct {
+                    name: "Point",
+                    len: 2,
+                },
+                Token::Str("x"),
+                Token::I32(10),
+                Token::Str("y"),
+                Token::I32(-20),
+                Token::StructEnd,
+            ],
+        );
+    }
 }
@@ -10,6 +10,13 @@ use core::ops::{Add, AddAssign, Div, DivAssign, Index, Mul, MulAssign, Sub, SubA
 /// of Nalgebra's [`Vector2`] with embedded-graphics where `u32`, `u16` or `u8` is used for value
 /// storage.
 ///
+/// Two-way conversions to the size and vector types of other crates can be enabled with the
+/// following features:
+///
+/// * `mint_support`: [`mint`]'s `Vector2<u32>`
+/// * `euclid_support`: [`euclid`]'s `Size2D<u32, U>`
+/// * `glam_support`: [`glam`]'s `UVec2`
+///
 /// # Examples
 ///
 /// ## Create a `Size` from two integers
@@ -57,7 +64,14 @@ use core::ops::{Add, AddAssign, Div, DivAssign, Index, Mul, MulAssign, Sub, SubA
 /// [`Vector2<N>`]: https://docs.rs/nalgebra/0.18.0/nalgebra/base/type.Vector2.html
 /// [`Vector2`]: https://docs.rs/nalgebra/0.18.0/nalgebra/base/type.Vector2.html
 /// [Nalgebra]: https://docs.rs/nalgebra
+/// [`mint`]: https://docs.rs/mint
+/// [`euclid`]: https://docs.rs/euclid
+/// [`glam`]: https://docs.rs/glam
 #[derive(Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Debug, Default)]
+#[cfg_attr(
+    feature = "serde_support",
+    derive(serde::Serialize, serde::Deserialize)
+)]
 pub struct Size {
     /// The width.
     pub width: u32,
@@ -84,7 +98,15 @@ impl Size {
     ///
     /// Returns `0` for `width` and/or `height` instead of overflowing, if the
     /// value in `other` is larger then in `self`.
-    pub(crate) fn saturating_sub(self, other: Self) -> Self {
+    ///
+    /// ```
+    /// # use embedded_graphics::geometry::Size;
+    /// #
+    /// let size = Size::new(10, 20).saturating_sub(Size::new(5, 30));
+    ///
+    /// assert_eq!(size, Size::new(5, 0));
+    /// ```
+    pub fn saturating_sub(self, other: Self) -> Self {
         Self {
             width: self.width.saturating_sub(other.width),
             height: self.height.saturating_sub(other.height),
@@ -92,7 +114,17 @@ impl Size {
     }
 
     /// Creates a size from two corner points of a bounding box.
-    pub(crate) fn from_bounding_box(corner_1: Point, corner_2: Point) -> Self {
+    ///
+    /// Both corners are included in the bounding box.
+    ///
+    /// ```
+    /// # use embedded_graphics::geometry::{Point, Size};
+    /// #
+    /// let size = Size::from_bounding_box(Point::new(10, 20), Point::new(5, 30));
+    ///
+    /// assert_eq!(size, Size::new(6, 11));
+    /// ```
+    pub fn from_bounding_box(corner_1: Point, corner_2: Point) -> Self {
         let width = (corner_1.x - corner_2.x).abs() as u32 + 1;
         let height = (corner_1.y - corner_2.y).abs() as u32 + 1;
 
@@ -231,6 +263,51 @@ where
     }
 }
 
+#[cfg(feature = "mint_support")]
+impl From<mint::Vector2<u32>> for Size {
+    fn from(other: mint::Vector2<u32>) -> Self {
+        Self::new(other.x, other.y)
+    }
+}
+
+#[cfg(feature = "mint_support")]
+impl From<Size> for mint::Vector2<u32> {
+    fn from(other: Size) -> Self {
+        Self {
+            x: other.width,
+            y: other.height,
+        }
+    }
+}
+
+#[cfg(feature = "euclid_support")]
+impl<U> From<euclid::Size2D<u32, U>> for Size {
+    fn from(other: euclid::Size2D<u32, U>) -> Self {
+        Self::new(other.width, other.height)
+    }
+}
+
+#[cfg(feature = "euclid_support")]
+impl<U> From<Size> for euclid::Size2D<u32, U> {
+    fn from(other: Size) -> Self {
+        Self::new(other.width, other.height)
+    }
+}
+
+#[cfg(feature = "glam_support")]
+impl From<glam::UVec2> for Size {
+    fn from(other: glam::UVec2) -> Self {
+        Self::new(other.x, other.y)
+    }
+}
+
+#[cfg(feature = "glam_support")]
+impl From<Size> for glam::UVec2 {
+    fn from(other: Size) -> Self {
+        Self::new(other.width, other.height)
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -326,4 +403,34 @@ mod tests {
 
         assert_eq!(Size::from(left - right), Size::new(20, 20));
     }
+
+    #[test]
+    #[cfg(feature = "mint_support")]
+    fn mint_support() {
+        let size = Size::new(10, 20);
+
+        let mint_vector: mint::Vector2<u32> = size.into();
+        assert_eq!(mint_vector, mint::Vector2 { x: 10, y: 20 });
+        assert_eq!(Size::from(mint_vector), size);
+    }
+
+    #[test]
+    #[cfg(feature = "euclid_support")]
+    fn euclid_support() {
+        let size = Size::new(10, 20);
+
+        let euclid_size: euclid::default::Size2D<u32> = size.into();
+        assert_eq!(euclid_size, euclid::size2(10, 20));
+        assert_eq!(Size::from(euclid_size), size);
+    }
+
+    #[test]
+    #[cfg(feature = "glam_support")]
+    fn glam_support() {
+        let size = Size::new(10, 20);
+
+        let glam_vector: glam::UVec2 = size.into();
+        assert_eq!(glam_vector, glam::uvec2(10, 20));
+        assert_eq!(Size::from(glam_vector), size);
+    }
 }
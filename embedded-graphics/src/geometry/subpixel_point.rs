@@ -0,0 +1,261 @@
+use crate::geometry::Point;
+use core::ops::{Add, AddAssign, Div, Mul, Neg, Sub, SubAssign};
+
+/// Number of fractional bits in the fixed point representation.
+const FRACTIONAL_BITS: u32 = 8;
+
+/// Fixed point representation of `1.0`.
+const ONE: i32 = 1 << FRACTIONAL_BITS;
+
+/// 2D point with subpixel precision.
+///
+/// The coordinates of a `SubpixelPoint` are stored as 24.8 fixed point numbers, which means that
+/// one pixel is divided into 256 steps. The integer coordinates of a [`Point`] refer to the center
+/// of a pixel, so `SubpixelPoint::from_point(Point::new(1, 2))` is at the center of that pixel.
+///
+/// Subpixel points can be used to construct a [`SubpixelLine`], [`SubpixelCircle`] or
+/// [`SubpixelTriangle`], whose rasterization will take the fractional part of the coordinates
+/// into account. This allows smooth motion in animations, even on displays with a low resolution.
+///
+/// Because 8 bits are used for the fractional part, the range of the coordinates is limited to
+/// ±8,388,607 pixels (`i32::max_value() / 256`), compared to the full `i32` range of a [`Point`].
+///
+/// # Examples
+///
+/// ## Move a line by a quarter pixel per frame
+///
+/// ```rust
+/// use embedded_graphics::{
+///     geometry::SubpixelPoint, pixelcolor::BinaryColor, prelude::*, primitives::SubpixelLine,
+///     style::PrimitiveStyle,
+/// };
+/// # use embedded_graphics::mock_display::MockDisplay;
+/// # let mut display: MockDisplay<BinaryColor> = MockDisplay::default();
+///
+/// let start = SubpixelPoint::from_point(Point::new(2, 2));
+/// let end = SubpixelPoint::from_point(Point::new(40, 12));
+///
+/// // A quarter pixel is 64 in the 24.8 fixed point format
+/// let velocity = SubpixelPoint::new(0, 64);
+///
+/// for frame in 0..4 {
+///     SubpixelLine::new(start + velocity * frame, end + velocity * frame)
+///         .into_styled(PrimitiveStyle::with_stroke(BinaryColor::On, 1))
+///         .draw(&mut display)?;
+/// #   display = MockDisplay::default();
+/// }
+/// # Ok::<(), core::convert::Infallible>(())
+/// ```
+///
+/// [`Point`]: struct.Point.html
+/// [`SubpixelLine`]: ../primitives/line/struct.SubpixelLine.html
+/// [`SubpixelCircle`]: ../primitives/circle/struct.SubpixelCircle.html
+/// [`SubpixelTriangle`]: ../primitives/triangle/struct.SubpixelTriangle.html
+#[derive(Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Debug, Default)]
+#[cfg_attr(
+    feature = "serde_support",
+    derive(serde::Serialize, serde::Deserialize)
+)]
+pub struct SubpixelPoint {
+    /// The x coordinate in 1/256 pixels.
+    pub x: i32,
+
+    /// The y coordinate in 1/256 pixels.
+    pub y: i32,
+}
+
+impl SubpixelPoint {
+    /// Creates a subpixel point from X and Y coordinates in 1/256 pixels.
+    pub const fn new(x: i32, y: i32) -> Self {
+        Self { x, y }
+    }
+
+    /// Creates a subpixel point at the origin.
+    pub const fn zero() -> Self {
+        Self::new(0, 0)
+    }
+
+    /// Creates a subpixel point from an integer point.
+    ///
+    /// Coordinates outside the range of subpixel points saturate at the bounds of `i32`.
+    pub fn from_point(point: Point) -> Self {
+        Self::new(point.x.saturating_mul(ONE), point.y.saturating_mul(ONE))
+    }
+
+    /// Creates a subpixel point from floating point coordinates in pixels.
+    ///
+    /// This only uses a floating point multiplication and doesn't require any math library.
+    pub fn from_f32(x: f32, y: f32) -> Self {
+        Self::new((x * ONE as f32) as i32, (y * ONE as f32) as i32)
+    }
+
+    /// Returns the nearest integer point.
+    ///
+    /// Coordinates exactly halfway between two pixels are rounded up.
+    pub const fn round(self) -> Point {
+        Point::new(
+            (self.x + ONE / 2) >> FRACTIONAL_BITS,
+            (self.y + ONE / 2) >> FRACTIONAL_BITS,
+        )
+    }
+
+    /// Returns the integer part of the coordinates, rounded towards negative infinity.
+    pub const fn floor(self) -> Point {
+        Point::new(self.x >> FRACTIONAL_BITS, self.y >> FRACTIONAL_BITS)
+    }
+
+    /// Returns the fractional part of the coordinates.
+    ///
+    /// The components of the returned point are in the range `0..256`.
+    pub const fn fract(self) -> Self {
+        Self::new(self.x & (ONE - 1), self.y & (ONE - 1))
+    }
+}
+
+impl From<Point> for SubpixelPoint {
+    fn from(point: Point) -> Self {
+        Self::from_point(point)
+    }
+}
+
+impl Add for SubpixelPoint {
+    type Output = SubpixelPoint;
+
+    fn add(self, other: SubpixelPoint) -> SubpixelPoint {
+        SubpixelPoint::new(self.x + other.x, self.y + other.y)
+    }
+}
+
+impl Add<Point> for SubpixelPoint {
+    type Output = SubpixelPoint;
+
+    fn add(self, other: Point) -> SubpixelPoint {
+        self + SubpixelPoint::from_point(other)
+    }
+}
+
+impl AddAssign for SubpixelPoint {
+    fn add_assign(&mut self, other: SubpixelPoint) {
+        self.x += other.x;
+        self.y += other.y;
+    }
+}
+
+impl AddAssign<Point> for SubpixelPoint {
+    fn add_assign(&mut self, other: Point) {
+        *self += SubpixelPoint::from_point(other);
+    }
+}
+
+impl Sub for SubpixelPoint {
+    type Output = SubpixelPoint;
+
+    fn sub(self, other: SubpixelPoint) -> SubpixelPoint {
+        SubpixelPoint::new(self.x - other.x, self.y - other.y)
+    }
+}
+
+impl Sub<Point> for SubpixelPoint {
+    type Output = SubpixelPoint;
+
+    fn sub(self, other: Point) -> SubpixelPoint {
+        self - SubpixelPoint::from_point(other)
+    }
+}
+
+impl SubAssign for SubpixelPoint {
+    fn sub_assign(&mut self, other: SubpixelPoint) {
+        self.x -= other.x;
+        self.y -= other.y;
+    }
+}
+
+impl SubAssign<Point> for SubpixelPoint {
+    fn sub_assign(&mut self, other: Point) {
+        *self -= SubpixelPoint::from_point(other);
+    }
+}
+
+impl Mul<i32> for SubpixelPoint {
+    type Output = SubpixelPoint;
+
+    fn mul(self, rhs: i32) -> SubpixelPoint {
+        SubpixelPoint::new(self.x * rhs, self.y * rhs)
+    }
+}
+
+impl Div<i32> for SubpixelPoint {
+    type Output = SubpixelPoint;
+
+    fn div(self, rhs: i32) -> SubpixelPoint {
+        SubpixelPoint::new(self.x / rhs, self.y / rhs)
+    }
+}
+
+impl Neg for SubpixelPoint {
+    type Output = SubpixelPoint;
+
+    fn neg(self) -> SubpixelPoint {
+        SubpixelPoint::new(-self.x, -self.y)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn from_point() {
+        assert_eq!(
+            SubpixelPoint::from_point(Point::new(2, -3)),
+            SubpixelPoint::new(512, -768)
+        );
+        assert_eq!(
+            SubpixelPoint::from(Point::new(1, 1)),
+            SubpixelPoint::new(256, 256)
+        );
+        assert_eq!(
+            SubpixelPoint::from_f32(1.5, -0.25),
+            SubpixelPoint::new(384, -64)
+        );
+    }
+
+    #[test]
+    fn from_point_saturates() {
+        assert_eq!(
+            SubpixelPoint::from_point(Point::new(8_388_607, -8_388_608)),
+            SubpixelPoint::new(8_388_607 * 256, i32::min_value())
+        );
+        assert_eq!(
+            SubpixelPoint::from_point(Point::new(10_000_000, i32::min_value())),
+            SubpixelPoint::new(i32::max_value(), i32::min_value())
+        );
+    }
+
+    #[test]
+    fn round_and_floor() {
+        let point = SubpixelPoint::new(383, 384);
+        assert_eq!(point.round(), Point::new(1, 2));
+        assert_eq!(point.floor(), Point::new(1, 1));
+        assert_eq!(point.fract(), SubpixelPoint::new(127, 128));
+
+        let point = SubpixelPoint::new(-129, -128);
+        assert_eq!(point.round(), Point::new(-1, 0));
+        assert_eq!(point.floor(), Point::new(-1, -1));
+        assert_eq!(point.fract(), SubpixelPoint::new(127, 128));
+    }
+
+    #[test]
+    fn arithmetic() {
+        let mut point = SubpixelPoint::new(64, 128) + Point::new(1, 0);
+        assert_eq!(point, SubpixelPoint::new(320, 128));
+
+        point -= SubpixelPoint::new(20, 30);
+        assert_eq!(point, SubpixelPoint::new(300, 98));
+
+        point += Point::new(0, 1);
+        assert_eq!(point * 2, SubpixelPoint::new(600, 708));
+        assert_eq!(point / 2, SubpixelPoint::new(150, 177));
+        assert_eq!(-point - Point::new(1, 1), SubpixelPoint::new(-556, -610));
+    }
+}
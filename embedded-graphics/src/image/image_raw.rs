@@ -1,7 +1,7 @@
 use crate::{
     drawable::Pixel,
     geometry::{Point, Size},
-    image::{ImageDimensions, IntoPixelIter},
+    image::{GetPixel, ImageDimensions, IntoPixelIter},
     pixelcolor::{
         raw::{BigEndian, ByteOrder, LittleEndian, RawData, RawDataIter},
         PixelColor,
@@ -178,6 +178,32 @@ where
     }
 }
 
+impl<'a, C, BO> GetPixel<C> for ImageRaw<'a, C, BO>
+where
+    C: PixelColor + From<<C as PixelColor>::Raw>,
+    BO: ByteOrder,
+    RawDataIter<'a, C::Raw, BO>: Iterator<Item = C::Raw>,
+{
+    fn pixel(&self, point: Point) -> Option<C> {
+        if point.x < 0
+            || point.y < 0
+            || point.x as u32 >= self.size.width
+            || point.y as u32 >= self.size.height
+        {
+            return None;
+        }
+
+        let bits_per_pixel = C::Raw::BITS_PER_PIXEL;
+        let bit_offset = point.x as usize * bits_per_pixel;
+
+        let mut data = RawDataIter::<C::Raw, BO>::new(self.data);
+        data.set_byte_position(point.y as usize * self.bytes_per_row() + bit_offset / 8);
+
+        // Skip preceding pixels in the same byte for color types with less than 8 bits per pixel
+        data.nth(bit_offset % 8 / bits_per_pixel).map(Into::into)
+    }
+}
+
 impl<'a, 'b, C, BO> IntoIterator for &'a ImageRaw<'b, C, BO>
 where
     C: PixelColor + From<<C as PixelColor>::Raw>,
@@ -517,6 +543,46 @@ mod tests {
         assert!(iter.next().is_none());
     }
 
+    fn assert_get_pixel_matches_iter<C, BO>(image: &ImageRaw<C, BO>)
+    where
+        C: PixelColor + From<<C as PixelColor>::Raw> + core::fmt::Debug,
+        BO: ByteOrder,
+        for<'a> RawDataIter<'a, C::Raw, BO>: Iterator<Item = C::Raw>,
+    {
+        for Pixel(point, color) in image {
+            assert_eq!(image.pixel(point), Some(color), "pixel at {:?}", point);
+        }
+
+        let width = image.size.width as i32;
+        let height = image.size.height as i32;
+
+        assert_eq!(image.pixel(Point::new(-1, 0)), None);
+        assert_eq!(image.pixel(Point::new(0, -1)), None);
+        assert_eq!(image.pixel(Point::new(width, 0)), None);
+        assert_eq!(image.pixel(Point::new(0, height)), None);
+    }
+
+    #[test]
+    fn get_pixel() {
+        let data = [0xAA, 0x00, 0x55, 0xFF, 0xAA, 0x00];
+        assert_get_pixel_matches_iter(&ImageRaw::<BinaryColor>::new(&data, 9, 3));
+
+        let data = [0b00011011, 0x0, 0b11100100, 0xFF];
+        assert_get_pixel_matches_iter(&ImageRaw::<Gray2>::new(&data, 5, 2));
+
+        let data = [0b00011000, 0b11110000, 0b01011010, 0x0];
+        assert_get_pixel_matches_iter(&ImageRaw::<Gray4>::new(&data, 3, 2));
+
+        let data = [0x12, 0x34, 0x56, 0x78, 0x9A, 0xBC];
+        assert_get_pixel_matches_iter(&ImageRaw::<Gray8>::new(&data, 3, 2));
+
+        let data = [
+            0xFF, 0x00, 0x00, 0x00, 0xFF, 0x00, 0x00, 0x00, 0xFF, 0x00, 0x00, 0x00,
+        ];
+        assert_get_pixel_matches_iter(&ImageRawLE::<Bgr888>::new(&data, 2, 2));
+        assert_get_pixel_matches_iter(&ImageRawBE::<Rgb565>::new(&data, 3, 2));
+    }
+
     #[test]
     #[should_panic]
     fn panics_if_length_of_data_is_too_short() {
@@ -77,6 +77,25 @@ pub trait ImageDimensions {
     fn height(&self) -> u32;
 }
 
+/// Random access to the pixels of an image.
+///
+/// This trait is used by [`ImageFill`] to look up the color of individual pixels without iterating
+/// over the whole image. Draw targets that can read back their pixels, like [`MockDisplay`], also
+/// implement this trait, which is required for [`flood_fill`].
+///
+/// [`ImageFill`]: ../style/struct.ImageFill.html
+/// [`MockDisplay`]: ../mock_display/struct.MockDisplay.html
+/// [`flood_fill`]: ../flood_fill/index.html
+pub trait GetPixel<C>
+where
+    C: PixelColor,
+{
+    /// Returns the color of the pixel at `point`.
+    ///
+    /// `None` is returned if `point` is outside the image.
+    fn pixel(&self, point: Point) -> Option<C>;
+}
+
 /// Image drawable.
 ///
 /// The `Image` struct serves as a wrapper around other image types that provide pixel data decoded
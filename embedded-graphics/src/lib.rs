@@ -82,6 +82,14 @@
 //!
 //! * `nalgebra_support` - use the [Nalgebra](https://crates.io/crates/nalgebra) crate with `no_std`
 //! support to enable conversions from `nalgebra::Vector2` to [`Point`] and [`Size`].
+//! * `mint_support` - enable two-way conversions between [`Point`] and [`Size`] and the types of
+//!   the [mint](https://crates.io/crates/mint) crate.
+//! * `euclid_support` - enable two-way conversions between [`Point`], [`Size`] and [`Rectangle`]
+//!   and the types of the [euclid](https://crates.io/crates/euclid) crate.
+//! * `glam_support` - enable two-way conversions between [`Point`] and [`Size`] and the integer
+//!   vector types of the [glam](https://crates.io/crates/glam) crate.
+//! * `serde_support` - derive [serde](https://crates.io/crates/serde) `Serialize` and
+//!   `Deserialize` for geometry types, primitives, colors and styles. Fonts are serialized by name.
 //!
 //! # Implementing `embedded_graphics` in a driver
 //!
@@ -383,6 +391,7 @@
 //! [`Circle`]: ./primitives/circle/struct.Circle.html
 //! [`Point`]: ./geometry/struct.Point.html
 //! [`Size`]: ./geometry/struct.Size.html
+//! [`Rectangle`]: ./primitives/rectangle/struct.Rectangle.html
 //! [`Font6x8`]: ./fonts/struct.Font6x8.html
 //! [`DrawTarget`]: ./draw_target/trait.DrawTarget.html
 //! [`Drawable`]: ./drawable/trait.Drawable.html
@@ -406,6 +415,7 @@ extern crate nalgebra;
 
 mod draw_target;
 pub mod drawable;
+pub mod flood_fill;
 pub mod fonts;
 pub mod geometry;
 pub mod image;
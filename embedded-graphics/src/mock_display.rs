@@ -113,6 +113,7 @@
 use crate::{
     drawable::Pixel,
     geometry::{Point, Size},
+    image::GetPixel,
     pixelcolor::{BinaryColor, Gray8, GrayColor, PixelColor, Rgb888, RgbColor},
     DrawTarget,
 };
@@ -284,6 +285,23 @@ where
     }
 }
 
+impl<C> GetPixel<C> for MockDisplay<C>
+where
+    C: PixelColor,
+{
+    /// Returns the color of a pixel.
+    ///
+    /// `None` is returned for pixels outside the display and for pixels which have not been set.
+    fn pixel(&self, point: Point) -> Option<C> {
+        let Point { x, y } = point;
+        if !(0..SIZE).contains(&(x as usize)) || !(0..SIZE).contains(&(y as usize)) {
+            return None;
+        }
+
+        self.get_pixel(point)
+    }
+}
+
 /// Mapping between `char`s and colors.
 ///
 /// See the [module-level documentation] for a table of implemented mappings.
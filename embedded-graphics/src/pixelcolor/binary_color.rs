@@ -40,6 +40,10 @@ use crate::pixelcolor::{
 /// assert_eq!(color, BinaryColor::On);
 /// ```
 #[derive(Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Debug)]
+#[cfg_attr(
+    feature = "serde_support",
+    derive(serde::Serialize, serde::Deserialize)
+)]
 pub enum BinaryColor {
     /// Inactive pixel.
     Off,
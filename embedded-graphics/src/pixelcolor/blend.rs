@@ -0,0 +1,169 @@
+use crate::pixelcolor::{gray_color::*, rgb_color::*, GrayColor, PixelColor, RgbColor};
+
+/// Blending between two colors.
+///
+/// This trait is used to mix two colors of the same type, for example to draw anti-aliased edges
+/// or to interpolate the colors of a gradient. It is implemented for all grayscale and RGB color
+/// types.
+///
+/// # Examples
+///
+/// ```rust
+/// use embedded_graphics::pixelcolor::{Blend, Gray8, GrayColor};
+///
+/// let color = Gray8::BLACK.blend(Gray8::WHITE, 128);
+///
+/// assert_eq!(color, Gray8::new(128));
+/// ```
+pub trait Blend: PixelColor {
+    /// Blends this color with another color.
+    ///
+    /// `ratio` defines the amount of `other` in the result. A ratio of `0` returns `self`
+    /// unchanged and a ratio of `255` returns `other`.
+    fn blend(self, other: Self, ratio: u8) -> Self;
+
+    /// Returns the weighted average of three colors.
+    ///
+    /// The weights don't need to be normalized, the result is rounded to the nearest color. If all
+    /// weights are `0` the first color is returned.
+    fn interpolate(colors: [Self; 3], weights: [u32; 3]) -> Self;
+}
+
+/// Linear interpolation between two channel values.
+///
+/// The result is rounded to the nearest integer.
+pub(crate) const fn blend_channel(from: u8, to: u8, ratio: u8) -> u8 {
+    let ratio = ratio as u16;
+
+    ((from as u16 * (255 - ratio) + to as u16 * ratio + 127) / 255) as u8
+}
+
+/// Weighted average of three channel values.
+///
+/// The result is rounded to the nearest integer.
+pub(crate) fn interpolate_channel(values: [u8; 3], weights: [u32; 3]) -> u8 {
+    let total: u64 = weights.iter().copied().map(u64::from).sum();
+    if total == 0 {
+        return values[0];
+    }
+
+    let sum: u64 = values
+        .iter()
+        .zip(weights.iter())
+        .map(|(&value, &weight)| u64::from(value) * u64::from(weight))
+        .sum();
+
+    ((sum + total / 2) / total) as u8
+}
+
+macro_rules! impl_blend_gray {
+    ($($type:ident),+) => {
+        $(impl Blend for $type {
+            fn blend(self, other: Self, ratio: u8) -> Self {
+                Self::new(blend_channel(self.luma(), other.luma(), ratio))
+            }
+
+            fn interpolate(colors: [Self; 3], weights: [u32; 3]) -> Self {
+                let [c1, c2, c3] = colors;
+
+                Self::new(interpolate_channel([c1.luma(), c2.luma(), c3.luma()], weights))
+            }
+        })+
+    };
+}
+
+impl_blend_gray!(Gray2, Gray4, Gray8);
+
+macro_rules! impl_blend_rgb {
+    ($($type:ident),+) => {
+        $(impl Blend for $type {
+            fn blend(self, other: Self, ratio: u8) -> Self {
+                Self::new(
+                    blend_channel(self.r(), other.r(), ratio),
+                    blend_channel(self.g(), other.g(), ratio),
+                    blend_channel(self.b(), other.b(), ratio),
+                )
+            }
+
+            fn interpolate(colors: [Self; 3], weights: [u32; 3]) -> Self {
+                let [c1, c2, c3] = colors;
+
+                Self::new(
+                    interpolate_channel([c1.r(), c2.r(), c3.r()], weights),
+                    interpolate_channel([c1.g(), c2.g(), c3.g()], weights),
+                    interpolate_channel([c1.b(), c2.b(), c3.b()], weights),
+                )
+            }
+        })+
+    };
+}
+
+impl_blend_rgb!(Rgb555, Bgr555, Rgb565, Bgr565, Rgb888, Bgr888);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn blend_channel_limits() {
+        assert_eq!(blend_channel(10, 200, 0), 10);
+        assert_eq!(blend_channel(10, 200, 255), 200);
+        assert_eq!(blend_channel(200, 10, 0), 200);
+        assert_eq!(blend_channel(200, 10, 255), 10);
+        assert_eq!(blend_channel(0, 255, 128), 128);
+        assert_eq!(blend_channel(0, 0x1F, 128), 0x10);
+    }
+
+    #[test]
+    fn interpolate_channel_weights() {
+        assert_eq!(interpolate_channel([10, 20, 30], [0, 0, 0]), 10);
+        assert_eq!(interpolate_channel([10, 20, 30], [0, 5, 0]), 20);
+        assert_eq!(interpolate_channel([0, 255, 0], [1, 1, 1]), 85);
+        assert_eq!(interpolate_channel([0, 100, 255], [2, 1, 1]), 89);
+        assert_eq!(
+            interpolate_channel(
+                [255, 255, 255],
+                [u32::max_value(), u32::max_value(), u32::max_value()]
+            ),
+            255
+        );
+    }
+
+    #[test]
+    fn interpolate_colors() {
+        assert_eq!(
+            Rgb888::interpolate([Rgb888::RED, Rgb888::GREEN, Rgb888::BLUE], [1, 1, 2]),
+            Rgb888::new(64, 64, 128)
+        );
+        assert_eq!(
+            Gray4::interpolate([Gray4::BLACK, Gray4::WHITE, Gray4::new(3)], [1, 0, 1]),
+            Gray4::new(2)
+        );
+    }
+
+    #[test]
+    fn blend_gray() {
+        assert_eq!(Gray2::BLACK.blend(Gray2::WHITE, 0), Gray2::BLACK);
+        assert_eq!(Gray2::BLACK.blend(Gray2::WHITE, 255), Gray2::WHITE);
+        assert_eq!(Gray4::BLACK.blend(Gray4::WHITE, 128), Gray4::new(8));
+        assert_eq!(
+            Gray8::new(0x20).blend(Gray8::new(0x40), 128),
+            Gray8::new(0x30)
+        );
+    }
+
+    #[test]
+    fn blend_rgb() {
+        assert_eq!(Rgb888::RED.blend(Rgb888::BLUE, 0), Rgb888::RED);
+        assert_eq!(Rgb888::RED.blend(Rgb888::BLUE, 255), Rgb888::BLUE);
+        assert_eq!(
+            Rgb888::RED.blend(Rgb888::BLUE, 128),
+            Rgb888::new(127, 0, 128)
+        );
+        assert_eq!(
+            Rgb565::BLACK.blend(Rgb565::WHITE, 128),
+            Rgb565::new(16, 32, 16)
+        );
+        assert_eq!(Bgr555::GREEN.blend(Bgr555::GREEN, 77), Bgr555::GREEN);
+    }
+}
@@ -59,6 +59,36 @@ macro_rules! gray_color {
                 color.0
             }
         }
+
+        #[cfg(feature = "serde_support")]
+        impl serde::Serialize for $type {
+            fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+            where
+                S: serde::Serializer,
+            {
+                serializer.serialize_u8(self.luma())
+            }
+        }
+
+        #[cfg(feature = "serde_support")]
+        impl<'de> serde::Deserialize<'de> for $type {
+            fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+            where
+                D: serde::Deserializer<'de>,
+            {
+                let luma = <u8 as serde::Deserialize>::deserialize(deserializer)?;
+                let color = Self::new(luma);
+
+                if color.luma() != luma {
+                    return Err(serde::de::Error::invalid_value(
+                        serde::de::Unexpected::Unsigned(luma.into()),
+                        &"a luma value in the range of the color type",
+                    ));
+                }
+
+                Ok(color)
+            }
+        }
     };
 }
 
@@ -109,4 +139,20 @@ mod tests {
 
         assert_eq!(color.into_storage(), 0xAAu8);
     }
+
+    #[test]
+    #[cfg(feature = "serde_support")]
+    fn serde_luma() {
+        use serde_test::{assert_de_tokens, assert_de_tokens_error, assert_tokens, Token};
+
+        assert_tokens(&Gray2::new(2), &[Token::U8(2)]);
+        assert_tokens(&Gray8::new(0xAB), &[Token::U8(0xAB)]);
+
+        // Too large luma values are rejected
+        assert_de_tokens(&Gray4::new(0xF), &[Token::U8(0xF)]);
+        assert_de_tokens_error::<Gray4>(
+            &[Token::U8(0x10)],
+            "invalid value: integer `16`, expected a luma value in the range of the color type",
+        );
+    }
 }
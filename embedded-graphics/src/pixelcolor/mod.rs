@@ -84,12 +84,14 @@
 //! [`raw` module]: raw/index.html
 
 mod binary_color;
+mod blend;
 mod conversion;
 mod gray_color;
 pub mod raw;
 mod rgb_color;
 
 pub use binary_color::*;
+pub use blend::Blend;
 pub use gray_color::*;
 use raw::RawData;
 pub use rgb_color::*;
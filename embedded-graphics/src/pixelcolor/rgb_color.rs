@@ -49,6 +49,33 @@ pub trait RgbColor: PixelColor {
     const WHITE: Self;
 }
 
+/// Serialized representation of RGB colors.
+///
+/// All RGB color types are serialized as a struct with separate `r`, `g` and `b` fields to make
+/// the serialized data independent of the channel order and the layout of the storage type. The
+/// channel values are stored in the bit depth of the color type, e.g. the red channel of `Rgb565`
+/// is in the range `0..=31`, and aren't converted between color types. Values which are out of
+/// range for the bit depth are rejected during deserialization.
+#[cfg(feature = "serde_support")]
+#[derive(serde::Serialize, serde::Deserialize)]
+#[serde(rename = "Rgb")]
+struct RgbChannels {
+    r: u8,
+    g: u8,
+    b: u8,
+}
+
+#[cfg(feature = "serde_support")]
+impl RgbChannels {
+    fn from_color<C: RgbColor>(color: &C) -> Self {
+        Self {
+            r: color.r(),
+            g: color.g(),
+            b: color.b(),
+        }
+    }
+}
+
 /// Macro to implement a RgbColor type with the given channel bit positions.
 macro_rules! impl_rgb_color {
     (
@@ -161,6 +188,37 @@ macro_rules! impl_rgb_color {
                 Self::new(color.0)
             }
         }
+
+        #[cfg(feature = "serde_support")]
+        impl serde::Serialize for $type {
+            fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+            where
+                S: serde::Serializer,
+            {
+                serde::Serialize::serialize(&RgbChannels::from_color(self), serializer)
+            }
+        }
+
+        #[cfg(feature = "serde_support")]
+        impl<'de> serde::Deserialize<'de> for $type {
+            fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+            where
+                D: serde::Deserializer<'de>,
+            {
+                let RgbChannels { r, g, b } = serde::Deserialize::deserialize(deserializer)?;
+
+                for &(value, max) in &[(r, Self::MAX_R), (g, Self::MAX_G), (b, Self::MAX_B)] {
+                    if value > max {
+                        return Err(serde::de::Error::invalid_value(
+                            serde::de::Unexpected::Unsigned(value.into()),
+                            &"a channel value in the range of the color type",
+                        ));
+                    }
+                }
+
+                Ok(Self::new(r, g, b))
+            }
+        }
     };
 
     // Recursive macro to stringify the type.
@@ -303,4 +361,40 @@ mod tests {
 
         assert_eq!(color.into_storage(), 0x00AABBCC);
     }
+
+    #[test]
+    #[cfg(feature = "serde_support")]
+    fn serde_channels() {
+        use serde_test::{assert_de_tokens, assert_de_tokens_error, assert_tokens, Token};
+
+        fn tokens(r: u8, g: u8, b: u8) -> [Token; 8] {
+            [
+                Token::Struct {
+                    name: "Rgb",
+                    len: 3,
+                },
+                Token::Str("r"),
+                Token::U8(r),
+                Token::Str("g"),
+                Token::U8(g),
+                Token::Str("b"),
+                Token::U8(b),
+                Token::StructEnd,
+            ]
+        }
+
+        assert_tokens(&Rgb888::new(1, 2, 3), &tokens(1, 2, 3));
+        assert_tokens(&Bgr565::new(31, 2, 3), &tokens(31, 2, 3));
+
+        // Too large channel values are rejected
+        assert_de_tokens(&Rgb555::new(31, 0, 31), &tokens(31, 0, 31));
+        assert_de_tokens_error::<Rgb565>(
+            &tokens(32, 0, 0),
+            "invalid value: integer `32`, expected a channel value in the range of the color type",
+        );
+        assert_de_tokens_error::<Bgr555>(
+            &tokens(0, 0, 0xFF),
+            "invalid value: integer `255`, expected a channel value in the range of the color type",
+        );
+    }
 }
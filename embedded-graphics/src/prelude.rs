@@ -2,11 +2,11 @@
 
 pub use crate::{
     drawable::{Drawable, Pixel},
-    fonts::Font,
+    fonts::{Font, ImageFont},
     geometry::{Dimensions, Point, Size},
-    image::{ImageDimensions, IntoPixelIter},
+    image::{GetPixel, ImageDimensions, IntoPixelIter},
     pixelcolor::{raw::RawData, GrayColor, IntoStorage, PixelColor, RgbColor},
     primitives::Primitive,
-    transform::Transform,
+    transform::{AffineTransformable, Transform},
     DrawTarget,
 };
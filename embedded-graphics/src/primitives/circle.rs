@@ -1,10 +1,10 @@
 //! The circle primitive
 
 use crate::{
-    drawable::{Drawable, Pixel},
-    geometry::{Dimensions, Point, Size},
-    pixelcolor::PixelColor,
-    primitives::{ContainsPoint, Primitive, Rectangle, Styled},
+    drawable::{Drawable, Pixel, Spans},
+    geometry::{isqrt, Dimensions, Point, Size, SubpixelPoint},
+    pixelcolor::{Blend, PixelColor},
+    primitives::{ContainsPoint, DistanceToPoint, Primitive, Rectangle, Styled},
     style::PrimitiveStyle,
     transform::Transform,
     DrawTarget,
@@ -52,6 +52,10 @@ use crate::{
 /// # Ok::<(), core::convert::Infallible>(())
 /// ```
 #[derive(Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Debug, Default)]
+#[cfg_attr(
+    feature = "serde_support",
+    derive(serde::Serialize, serde::Deserialize)
+)]
 pub struct Circle {
     /// Top-left point of circle's bounding box
     pub top_left: Point,
@@ -80,15 +84,12 @@ impl Circle {
         self.bounding_box().center()
     }
 
-    /// Return the center point of the circle scaled by a factor of 2
-    ///
-    /// This method is used to accurately calculate the outside edge of the circle.
-    /// The result is not equivalent to `self.center() * 2` because of rounding.
-    fn center_2x(&self) -> Point {
-        // The radius scaled up by a factor of 2 is equal to the diameter
-        let radius = self.diameter.saturating_sub(1);
-
-        self.top_left * 2 + Size::new(radius, radius)
+    fn geometry(&self) -> Geometry {
+        Geometry {
+            top_left: self.top_left,
+            diameter: self.diameter,
+            offset: SubpixelPoint::zero(),
+        }
     }
 }
 
@@ -96,18 +97,19 @@ impl Primitive for Circle {
     type PointsIter = Points;
 
     fn points(&self) -> Self::PointsIter {
-        Points::new(self)
+        Points::new(&self.geometry())
     }
 }
 
 impl ContainsPoint for Circle {
     fn contains(&self, point: Point) -> bool {
-        let delta = self.center_2x() - point * 2;
-        let distance = delta.x.pow(2) as u32 + delta.y.pow(2) as u32;
-
-        let threshold = diameter_to_threshold(self.diameter);
+        self.geometry().contains(point)
+    }
+}
 
-        return distance < threshold;
+impl DistanceToPoint for Circle {
+    fn distance(&self, point: Point) -> u32 {
+        self.geometry().distance(point)
     }
 }
 
@@ -161,11 +163,11 @@ pub struct Points {
 }
 
 impl Points {
-    fn new(circle: &Circle) -> Self {
-        let threshold = diameter_to_threshold(circle.diameter);
+    fn new(geometry: &Geometry) -> Self {
+        let threshold = diameter_to_threshold(geometry.diameter);
 
         Self {
-            iter: DistanceIterator::new(&circle),
+            iter: DistanceIterator::new(geometry),
             threshold,
         }
     }
@@ -201,17 +203,12 @@ impl<C> StyledCircleIterator<C>
 where
     C: PixelColor,
 {
-    fn new(styled: &Styled<Circle, PrimitiveStyle<C>>) -> Self {
-        let stroke_width = styled.style.effective_stroke_width();
-
-        let outer_diameter = styled.primitive.diameter;
-        let inner_diameter = outer_diameter.saturating_sub(2 * stroke_width);
-
-        let inner_threshold = diameter_to_threshold(inner_diameter);
-        let outer_threshold = diameter_to_threshold(outer_diameter);
+    fn new(geometry: &Geometry, style: &PrimitiveStyle<C>) -> Self {
+        let inner_threshold = diameter_to_threshold(inner_diameter(geometry.diameter, style));
+        let outer_threshold = diameter_to_threshold(geometry.diameter);
 
-        let iter = if !styled.style.is_transparent() {
-            DistanceIterator::new(&styled.primitive)
+        let iter = if !style.is_transparent() {
+            DistanceIterator::new(geometry)
         } else {
             DistanceIterator::empty()
         };
@@ -219,9 +216,9 @@ where
         Self {
             iter,
             outer_threshold,
-            outer_color: styled.style.stroke_color,
+            outer_color: style.stroke_color,
             inner_threshold,
-            inner_color: styled.style.fill_color,
+            inner_color: style.fill_color,
         }
     }
 }
@@ -251,6 +248,120 @@ where
     }
 }
 
+impl<C> Styled<Circle, PrimitiveStyle<C>>
+where
+    C: PixelColor + Blend,
+{
+    /// Returns an iterator over the anti-aliased pixels of the circle.
+    ///
+    /// The edges of the stroke and fill are smoothed by blending the pixel colors with the
+    /// neighbouring color, based on how much of each pixel is covered by the circle. Pixels on the
+    /// outside edge are blended with `background_color`, which should be set to the color the
+    /// circle is drawn on top of.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use embedded_graphics::{
+    ///     pixelcolor::Rgb565, prelude::*, primitives::Circle, style::PrimitiveStyle,
+    /// };
+    /// # use embedded_graphics::mock_display::MockDisplay;
+    /// # let mut display = MockDisplay::default();
+    ///
+    /// let circle = Circle::new(Point::new(10, 10), 30)
+    ///     .into_styled(PrimitiveStyle::with_fill(Rgb565::YELLOW));
+    ///
+    /// display.draw_iter(circle.antialiased(Rgb565::BLACK))?;
+    /// # Ok::<(), core::convert::Infallible>(())
+    /// ```
+    pub fn antialiased(&self, background_color: C) -> AntialiasedCircleIterator<C> {
+        AntialiasedCircleIterator::new(&self.primitive.geometry(), &self.style, background_color)
+    }
+}
+
+/// Anti-aliased pixel iterator for a styled circle.
+///
+/// This iterator is created by the [`antialiased`] method.
+///
+/// [`antialiased`]: ../../style/struct.Styled.html#method.antialiased
+#[derive(Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Debug)]
+pub struct AntialiasedCircleIterator<C>
+where
+    C: PixelColor,
+{
+    iter: DistanceIterator,
+
+    outer_diameter: u32,
+    stroke_color: Option<C>,
+
+    inner_diameter: u32,
+    fill_color: Option<C>,
+
+    background_color: C,
+}
+
+impl<C> AntialiasedCircleIterator<C>
+where
+    C: PixelColor + Blend,
+{
+    fn new(geometry: &Geometry, style: &PrimitiveStyle<C>, background_color: C) -> Self {
+        let stroke_width = style.effective_stroke_width();
+
+        let outer_diameter = geometry.diameter;
+        let inner_diameter = inner_diameter(outer_diameter, style);
+
+        let iter = if !style.is_transparent() {
+            DistanceIterator::new(geometry)
+        } else {
+            DistanceIterator::empty()
+        };
+
+        let stroke_color = if stroke_width > 0 {
+            style.stroke_color
+        } else {
+            None
+        };
+
+        Self {
+            iter,
+            outer_diameter,
+            stroke_color,
+            inner_diameter,
+            fill_color: style.fill_color,
+            background_color,
+        }
+    }
+}
+
+impl<C> Iterator for AntialiasedCircleIterator<C>
+where
+    C: PixelColor + Blend,
+{
+    type Item = Pixel<C>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        for (point, distance) in &mut self.iter {
+            let outer = coverage(distance, self.outer_diameter);
+            let inner = coverage(distance, self.inner_diameter);
+
+            let (color, alpha) = match (self.stroke_color, self.fill_color) {
+                (Some(stroke), Some(fill)) => (stroke.blend(fill, inner), outer),
+                (Some(stroke), None) => (stroke, outer.saturating_sub(inner)),
+                (None, Some(fill)) => (fill, outer),
+                (None, None) => continue,
+            };
+
+            match alpha {
+                0 => {}
+                255 => return Some(Pixel(point, color)),
+                alpha => return Some(Pixel(point, self.background_color.blend(color, alpha))),
+            }
+        }
+
+        None
+    }
+}
+
 impl<'a, C: 'a> Drawable<C> for &Styled<Circle, PrimitiveStyle<C>>
 where
     C: PixelColor,
@@ -268,6 +379,69 @@ fn diameter_to_threshold(diameter: u32) -> u32 {
     }
 }
 
+/// Returns the approximate coverage of a pixel by a circle.
+///
+/// `distance` is the squared distance to the center as returned by `DistanceIterator`, which uses
+/// coordinates that are scaled by a factor of 2. The distance of the pixel center to the edge of
+/// the circle is approximated by `(diameter² - distance) / (4 * diameter)` to avoid calculating a
+/// square root. The result is in the range from `0` (not covered) to `255` (fully covered).
+fn coverage(distance: u32, diameter: u32) -> u8 {
+    if diameter == 0 {
+        return 0;
+    }
+
+    let diameter = i64::from(diameter);
+    let edge_distance = (diameter.pow(2) - i64::from(distance)) * 255 / (4 * diameter);
+
+    (128 + edge_distance).max(0).min(255) as u8
+}
+
+impl<C> Styled<Circle, PrimitiveStyle<C>>
+where
+    C: PixelColor,
+{
+    /// Returns an iterator over the horizontal spans of the styled circle.
+    ///
+    /// Consecutive pixels in a row which have the same color are combined into a single span.
+    /// Drawing the spans results in the same output as drawing the pixels of the styled circle.
+    pub fn spans(&self) -> Spans<StyledCircleIterator<C>, C> {
+        Spans::new(self)
+    }
+}
+
+impl<C> ContainsPoint for Styled<Circle, PrimitiveStyle<C>>
+where
+    C: PixelColor,
+{
+    /// Returns `true` if the point is one of the drawn pixels of the circle.
+    fn contains(&self, point: Point) -> bool {
+        self.primitive
+            .geometry()
+            .styled_contains(&self.style, point)
+    }
+}
+
+impl<C> DistanceToPoint for Styled<Circle, PrimitiveStyle<C>>
+where
+    C: PixelColor,
+{
+    fn distance(&self, point: Point) -> u32 {
+        self.primitive
+            .geometry()
+            .styled_distance(&self.style, point)
+    }
+}
+
+/// Returns the diameter of the area of a styled circle which is drawn using the fill color.
+fn inner_diameter<C>(diameter: u32, style: &PrimitiveStyle<C>) -> u32
+where
+    C: PixelColor,
+{
+    let stroke_width = style.effective_stroke_width();
+
+    diameter.saturating_sub(2 * stroke_width)
+}
+
 impl<'a, C> IntoIterator for &'a Styled<Circle, PrimitiveStyle<C>>
 where
     C: PixelColor,
@@ -276,28 +450,323 @@ where
     type IntoIter = StyledCircleIterator<C>;
 
     fn into_iter(self) -> Self::IntoIter {
-        StyledCircleIterator::new(self)
+        StyledCircleIterator::new(&self.primitive.geometry(), &self.style)
+    }
+}
+
+/// Circle primitive with subpixel precision.
+///
+/// The center of a `SubpixelCircle` is a [`SubpixelPoint`], which can be positioned between
+/// pixels. The pixels are drawn if their center is inside the circle, which allows smooth motion
+/// in animations.
+///
+/// A [`Circle`] can be converted into a `SubpixelCircle` by using `From`/`Into`. Both circles are
+/// drawn identically.
+///
+/// The bounding box of a circle that isn't aligned to the pixel grid is one pixel larger than
+/// `diameter` in the direction of the subpixel offset.
+///
+/// # Examples
+///
+/// ```rust
+/// use embedded_graphics::{
+///     geometry::SubpixelPoint, pixelcolor::BinaryColor, prelude::*,
+///     primitives::SubpixelCircle, style::PrimitiveStyle,
+/// };
+/// # use embedded_graphics::mock_display::MockDisplay;
+/// # let mut display = MockDisplay::default();
+///
+/// SubpixelCircle::new(SubpixelPoint::from_f32(10.25, 12.5), 15)
+///     .into_styled(PrimitiveStyle::with_stroke(BinaryColor::On, 1))
+///     .draw(&mut display)?;
+/// # Ok::<(), core::convert::Infallible>(())
+/// ```
+///
+/// [`SubpixelPoint`]: ../../geometry/struct.SubpixelPoint.html
+/// [`Circle`]: struct.Circle.html
+#[derive(Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Debug, Default)]
+#[cfg_attr(
+    feature = "serde_support",
+    derive(serde::Serialize, serde::Deserialize)
+)]
+pub struct SubpixelCircle {
+    /// Center of the circle
+    pub center: SubpixelPoint,
+
+    /// Diameter of the circle
+    pub diameter: u32,
+}
+
+impl SubpixelCircle {
+    /// Creates a new subpixel circle centered around a given point with a specific diameter.
+    pub const fn new(center: SubpixelPoint, diameter: u32) -> Self {
+        Self { center, diameter }
+    }
+
+    /// Returns the radius in subpixel units, measured to the center of the outermost pixels.
+    fn radius(diameter: u32) -> SubpixelPoint {
+        let radius = diameter.saturating_sub(1) as i32 * 128;
+
+        SubpixelPoint::new(radius, radius)
+    }
+
+    fn geometry(&self) -> Geometry {
+        // Position of the top left pixel center for an integer circle with the same diameter
+        let top_left = self.center - Self::radius(self.diameter);
+
+        Geometry {
+            top_left: top_left.floor(),
+            diameter: self.diameter,
+            offset: top_left.fract(),
+        }
+    }
+}
+
+impl From<Circle> for SubpixelCircle {
+    fn from(circle: Circle) -> Self {
+        let center = SubpixelPoint::from_point(circle.top_left) + Self::radius(circle.diameter);
+
+        Self::new(center, circle.diameter)
+    }
+}
+
+impl Primitive for SubpixelCircle {
+    type PointsIter = Points;
+
+    fn points(&self) -> Self::PointsIter {
+        Points::new(&self.geometry())
+    }
+}
+
+impl ContainsPoint for SubpixelCircle {
+    fn contains(&self, point: Point) -> bool {
+        self.geometry().contains(point)
+    }
+}
+
+impl DistanceToPoint for SubpixelCircle {
+    fn distance(&self, point: Point) -> u32 {
+        self.geometry().distance(point)
+    }
+}
+
+impl Dimensions for SubpixelCircle {
+    fn bounding_box(&self) -> Rectangle {
+        self.geometry().bounding_box()
+    }
+}
+
+impl Transform for SubpixelCircle {
+    /// Translate the circle from its current position to a new position by (x, y) pixels,
+    /// returning a new `SubpixelCircle`. For a mutating transform, see `translate_mut`.
+    fn translate(&self, by: Point) -> Self {
+        Self::new(self.center + by, self.diameter)
+    }
+
+    /// Translate the circle from its current position to a new position by (x, y) pixels.
+    fn translate_mut(&mut self, by: Point) -> &mut Self {
+        self.center += by;
+
+        self
+    }
+}
+
+impl<C> Styled<SubpixelCircle, PrimitiveStyle<C>>
+where
+    C: PixelColor,
+{
+    /// Returns an iterator over the horizontal spans of the styled circle.
+    ///
+    /// Consecutive pixels in a row which have the same color are combined into a single span.
+    /// Drawing the spans results in the same output as drawing the pixels of the styled circle.
+    pub fn spans(&self) -> Spans<StyledCircleIterator<C>, C> {
+        Spans::new(self)
+    }
+}
+
+impl<C> Styled<SubpixelCircle, PrimitiveStyle<C>>
+where
+    C: PixelColor + Blend,
+{
+    /// Returns an iterator over the anti-aliased pixels of the circle.
+    ///
+    /// Pixels on the outside edge are blended with `background_color`, like for anti-aliased
+    /// [`Circle`]s.
+    ///
+    /// [`Circle`]: struct.Circle.html
+    pub fn antialiased(&self, background_color: C) -> AntialiasedCircleIterator<C> {
+        AntialiasedCircleIterator::new(&self.primitive.geometry(), &self.style, background_color)
+    }
+}
+
+impl<C> ContainsPoint for Styled<SubpixelCircle, PrimitiveStyle<C>>
+where
+    C: PixelColor,
+{
+    /// Returns `true` if the point is one of the drawn pixels of the circle.
+    fn contains(&self, point: Point) -> bool {
+        self.primitive
+            .geometry()
+            .styled_contains(&self.style, point)
+    }
+}
+
+impl<C> DistanceToPoint for Styled<SubpixelCircle, PrimitiveStyle<C>>
+where
+    C: PixelColor,
+{
+    fn distance(&self, point: Point) -> u32 {
+        self.primitive
+            .geometry()
+            .styled_distance(&self.style, point)
+    }
+}
+
+impl<'a, C> IntoIterator for &'a Styled<SubpixelCircle, PrimitiveStyle<C>>
+where
+    C: PixelColor,
+{
+    type Item = Pixel<C>;
+    type IntoIter = StyledCircleIterator<C>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        StyledCircleIterator::new(&self.primitive.geometry(), &self.style)
+    }
+}
+
+impl<'a, C: 'a> Drawable<C> for &Styled<SubpixelCircle, PrimitiveStyle<C>>
+where
+    C: PixelColor,
+{
+    fn draw<D: DrawTarget<C>>(self, display: &mut D) -> Result<(), D::Error> {
+        display.draw_spans(self.spans())
+    }
+}
+
+/// Position and size of a circle, which is shared by `Circle` and `SubpixelCircle`.
+#[derive(Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Debug, Default)]
+struct Geometry {
+    /// Top-left point of the bounding box
+    top_left: Point,
+
+    /// Diameter of the circle
+    diameter: u32,
+
+    /// Subpixel offset of the circle relative to `top_left`.
+    ///
+    /// The components are in the range `0..256` and are zero for circles which are aligned to
+    /// the pixel grid.
+    offset: SubpixelPoint,
+}
+
+impl Geometry {
+    /// Returns the bounding box of the drawn pixels.
+    fn bounding_box(&self) -> Rectangle {
+        let extra = |offset: i32| if offset != 0 { 1 } else { 0 };
+
+        let size = Size::new(
+            self.diameter + extra(self.offset.x),
+            self.diameter + extra(self.offset.y),
+        );
+
+        Rectangle::new(self.top_left, size)
+    }
+
+    /// Returns the squared distance between `point` and the center of the circle.
+    ///
+    /// The distance is calculated in coordinates that are scaled by a factor of 2, which is used
+    /// to accurately calculate the outside edge of the circle. Circles with a subpixel offset are
+    /// calculated with subpixel precision and the fractional part of the result is discarded.
+    fn squared_distance(&self, point: Point) -> u32 {
+        // The radius scaled up by a factor of 2 is equal to the diameter
+        let radius = i64::from(self.diameter.saturating_sub(1));
+
+        let dx = i64::from(self.top_left.x) * 2 + radius - i64::from(point.x) * 2;
+        let dy = i64::from(self.top_left.y) * 2 + radius - i64::from(point.y) * 2;
+
+        let distance = if self.offset == SubpixelPoint::zero() {
+            dx.saturating_mul(dx).saturating_add(dy.saturating_mul(dy))
+        } else {
+            let dx = dx * 256 + i64::from(self.offset.x) * 2;
+            let dy = dy * 256 + i64::from(self.offset.y) * 2;
+
+            dx.saturating_mul(dx).saturating_add(dy.saturating_mul(dy)) >> 16
+        };
+
+        distance.min(i64::from(u32::max_value())) as u32
+    }
+
+    fn contains(&self, point: Point) -> bool {
+        self.squared_distance(point) < diameter_to_threshold(self.diameter)
+    }
+
+    fn distance(&self, point: Point) -> u32 {
+        if self.contains(point) {
+            return 0;
+        }
+
+        // `squared_distance` returns the squared distance to the center in half pixels
+        let distance = isqrt(u64::from(self.squared_distance(point)));
+
+        ((distance + 1).saturating_sub(self.diameter) / 2).max(1)
+    }
+
+    fn styled_contains<C>(&self, style: &PrimitiveStyle<C>, point: Point) -> bool
+    where
+        C: PixelColor,
+    {
+        if style.is_transparent() {
+            return false;
+        }
+
+        let distance = self.squared_distance(point);
+
+        if distance < diameter_to_threshold(inner_diameter(self.diameter, style)) {
+            style.fill_color.is_some()
+        } else if distance < diameter_to_threshold(self.diameter) {
+            style.stroke_color.is_some()
+        } else {
+            false
+        }
+    }
+
+    fn styled_distance<C>(&self, style: &PrimitiveStyle<C>, point: Point) -> u32
+    where
+        C: PixelColor,
+    {
+        if self.styled_contains(style, point) {
+            return 0;
+        }
+
+        if !self.contains(point) {
+            return self.distance(point);
+        }
+
+        // The point is inside an unfilled circle: return the distance to the stroke.
+        let distance = isqrt(u64::from(self.squared_distance(point)));
+
+        ((inner_diameter(self.diameter, style) + 1).saturating_sub(distance) / 2).max(1)
     }
 }
 
 /// Iterator that returns the squared distance to the center for all points in the bounding box.
 #[derive(Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Debug)]
 struct DistanceIterator {
-    center: Point,
+    geometry: Geometry,
     points: super::rectangle::Points,
 }
 
 impl DistanceIterator {
-    fn new(circle: &Circle) -> Self {
+    fn new(geometry: &Geometry) -> Self {
         Self {
-            center: circle.center_2x(),
-            points: circle.bounding_box().points(),
+            geometry: *geometry,
+            points: geometry.bounding_box().points(),
         }
     }
 
     fn empty() -> Self {
         Self {
-            center: Point::zero(),
+            geometry: Geometry::default(),
             points: Rectangle::new(Point::zero(), Size::zero()).points(),
         }
     }
@@ -307,19 +776,22 @@ impl Iterator for DistanceIterator {
     type Item = (Point, u32);
 
     fn next(&mut self) -> Option<Self::Item> {
-        self.points.next().map(|p| {
-            let delta = self.center - p * 2;
-            let distance = delta.x.pow(2) as u32 + delta.y.pow(2) as u32;
+        let geometry = &self.geometry;
 
-            (p, distance)
-        })
+        self.points
+            .next()
+            .map(|p| (p, geometry.squared_distance(p)))
     }
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
-    use crate::{mock_display::MockDisplay, pixelcolor::BinaryColor, style::PrimitiveStyleBuilder};
+    use crate::{
+        mock_display::MockDisplay,
+        pixelcolor::{BinaryColor, Gray8, GrayColor},
+        style::PrimitiveStyleBuilder,
+    };
 
     #[test]
     fn stroke_width_doesnt_affect_fill() -> Result<(), core::convert::Infallible> {
@@ -494,7 +966,7 @@ mod tests {
     fn distance_iter() {
         let circle = Circle::new(Point::zero(), 3);
 
-        let mut iter = DistanceIterator::new(&circle);
+        let mut iter = DistanceIterator::new(&circle.geometry());
         assert_eq!(iter.next(), Some((Point::new(0, 0), 8)));
         assert_eq!(iter.next(), Some((Point::new(1, 0), 4)));
         assert_eq!(iter.next(), Some((Point::new(2, 0), 8)));
@@ -642,4 +1114,196 @@ mod tests {
             "  #####  ",
         ],);
     }
+
+    #[test]
+    fn coverage_limits() {
+        assert_eq!(coverage(0, 0), 0);
+        assert_eq!(coverage(0, 10), 255);
+        assert_eq!(coverage(100, 10), 128);
+        assert_eq!(coverage(200, 10), 0);
+    }
+
+    #[test]
+    fn antialiased_fill() {
+        let circle =
+            Circle::new(Point::new(0, 0), 7).into_styled(PrimitiveStyle::with_fill(Gray8::WHITE));
+
+        let mut display = MockDisplay::new();
+        display.draw_iter(circle.antialiased(Gray8::BLACK)).unwrap();
+
+        // Pixels inside the aliased circle are fully or mostly covered.
+        for point in circle.primitive.points() {
+            let luma = display.get_pixel(point).unwrap().luma();
+            assert!(luma >= 0x80, "{:?}: {}", point, luma);
+        }
+
+        // The center is fully covered and the corners of the bounding box are not covered at all.
+        assert_eq!(display.get_pixel(Point::new(3, 3)), Some(Gray8::WHITE));
+        assert_eq!(display.get_pixel(Point::new(0, 0)), None);
+        assert_eq!(display.get_pixel(Point::new(6, 6)), None);
+
+        // Edge pixels are blended with the background.
+        let edge = display.get_pixel(Point::new(0, 3)).unwrap().luma();
+        assert!(edge > 0 && edge < 0xFF, "{}", edge);
+    }
+
+    #[test]
+    fn antialiased_is_symmetric() {
+        let circle = Circle::new(Point::new(0, 0), 12).into_styled(
+            PrimitiveStyleBuilder::new()
+                .stroke_color(Gray8::WHITE)
+                .stroke_width(2)
+                .fill_color(Gray8::new(0x44))
+                .build(),
+        );
+
+        let mut display = MockDisplay::new();
+        display.draw_iter(circle.antialiased(Gray8::BLACK)).unwrap();
+
+        for point in circle.primitive.bounding_box().points() {
+            let mirrored = Point::new(11 - point.x, 11 - point.y);
+            let transposed = Point::new(point.y, point.x);
+
+            assert_eq!(display.get_pixel(point), display.get_pixel(mirrored));
+            assert_eq!(display.get_pixel(point), display.get_pixel(transposed));
+        }
+
+        // The center is filled with the fill color and the stroke is fully opaque halfway
+        // between the inner and outer edge.
+        assert_eq!(display.get_pixel(Point::new(5, 5)), Some(Gray8::new(0x44)));
+        assert_eq!(display.get_pixel(Point::new(5, 1)), Some(Gray8::WHITE));
+    }
+
+    #[test]
+    fn antialiased_stroke_only() {
+        let circle = Circle::new(Point::new(0, 0), 20)
+            .into_styled(PrimitiveStyle::with_stroke(Gray8::WHITE, 1));
+
+        let mut display = MockDisplay::new();
+        display.draw_iter(circle.antialiased(Gray8::BLACK)).unwrap();
+
+        // The inside of the circle isn't drawn.
+        assert_eq!(display.get_pixel(Point::new(10, 10)), None);
+        assert_eq!(display.get_pixel(Point::new(5, 10)), None);
+
+        assert!(display.get_pixel(Point::new(0, 10)).is_some());
+    }
+
+    #[test]
+    fn antialiased_transparent() {
+        let circle = Circle::new(Point::new(0, 0), 20)
+            .into_styled(PrimitiveStyle::with_stroke(Gray8::WHITE, 0));
+
+        assert_eq!(circle.antialiased(Gray8::BLACK).next(), None);
+    }
+
+    #[test]
+    fn subpixel_integer_center() -> Result<(), core::convert::Infallible> {
+        let style = PrimitiveStyleBuilder::new()
+            .stroke_color(BinaryColor::On)
+            .stroke_width(2)
+            .fill_color(BinaryColor::Off)
+            .build();
+
+        for diameter in 0..20 {
+            let circle = Circle::new(Point::new(10, 20), diameter);
+            let subpixel_circle = SubpixelCircle::from(circle);
+
+            assert_eq!(subpixel_circle.bounding_box(), circle.bounding_box());
+
+            let mut expected = MockDisplay::new();
+            circle.into_styled(style).draw(&mut expected)?;
+
+            let mut display = MockDisplay::new();
+            subpixel_circle.into_styled(style).draw(&mut display)?;
+
+            assert_eq!(display, expected, "diameter {}", diameter);
+        }
+
+        let center = Point::new(10, 20);
+        for diameter in (1..20).step_by(2) {
+            assert_eq!(
+                SubpixelCircle::from(Circle::with_center(center, diameter)),
+                SubpixelCircle::new(center.into(), diameter)
+            );
+        }
+
+        Ok(())
+    }
+
+    #[test]
+    fn large_coordinates() {
+        let circle = Circle::new(Point::new(10_000_000, 0), 5);
+
+        assert!(circle.contains(Point::new(10_000_002, 2)));
+        assert!(!circle.contains(Point::new(10_000_005, 2)));
+        assert_eq!(circle.distance(Point::new(10_000_002, -3)), 3);
+        assert_eq!(circle.points().count(), 21);
+    }
+
+    #[test]
+    fn subpixel_center() -> Result<(), core::convert::Infallible> {
+        let circle = SubpixelCircle::new(SubpixelPoint::from_f32(3.5, 3.0), 5);
+
+        assert_eq!(
+            circle.bounding_box(),
+            Rectangle::new(Point::new(1, 1), Size::new(6, 5))
+        );
+
+        let mut display = MockDisplay::new();
+        circle
+            .into_styled(PrimitiveStyle::with_fill(BinaryColor::On))
+            .draw(&mut display)?;
+
+        #[rustfmt::skip]
+        assert_eq!(
+            display,
+            MockDisplay::from_pattern(&[
+                "      ",
+                "   ## ",
+                "  ####",
+                "  ####",
+                "  ####",
+                "   ## ",
+            ])
+        );
+
+        assert!(circle.contains(Point::new(5, 3)));
+        assert!(!circle.contains(Point::new(1, 3)));
+
+        Ok(())
+    }
+
+    #[test]
+    fn styled_contains_and_distance() {
+        let circle = Circle::new(Point::new(2, 3), 11);
+
+        let stroke = circle.into_styled(PrimitiveStyle::with_stroke(BinaryColor::On, 2));
+        let fill = circle.into_styled(
+            PrimitiveStyleBuilder::new()
+                .stroke_color(BinaryColor::On)
+                .stroke_width(2)
+                .fill_color(BinaryColor::Off)
+                .build(),
+        );
+
+        for y in 0..16 {
+            for x in 0..16 {
+                let point = Point::new(x, y);
+                for styled in [stroke, fill].iter() {
+                    assert_eq!(
+                        styled.contains(point),
+                        styled.into_iter().any(|Pixel(p, _)| p == point)
+                    );
+                }
+            }
+        }
+
+        assert_eq!(circle.distance(Point::new(7, 8)), 0);
+        assert_eq!(circle.distance(Point::new(7, 20)), 7);
+
+        assert_eq!(fill.distance(Point::new(7, 8)), 0);
+        assert_eq!(stroke.distance(Point::new(7, 8)), 4);
+        assert_eq!(stroke.distance(Point::new(7, 20)), 7);
+    }
 }
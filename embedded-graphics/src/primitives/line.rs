@@ -5,9 +5,12 @@ use crate::{
     drawable::Drawable,
     drawable::Pixel,
     geometry::Dimensions,
-    geometry::Point,
+    geometry::{isqrt, Point, Size, SubpixelPoint},
     pixelcolor::PixelColor,
-    primitives::{Primitive, Rectangle, ThickLineIterator},
+    primitives::{
+        thick_line_iterator, ContainsPoint, DistanceToPoint, Primitive, Rectangle,
+        ThickLineIterator,
+    },
     style::PrimitiveStyle,
     style::Styled,
     transform::Transform,
@@ -41,6 +44,10 @@ use crate::{
 /// # Ok::<(), core::convert::Infallible>(())
 /// ```
 #[derive(Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Debug, Default)]
+#[cfg_attr(
+    feature = "serde_support",
+    derive(serde::Serialize, serde::Deserialize)
+)]
 pub struct Line {
     /// Start point
     pub start: Point,
@@ -53,7 +60,22 @@ impl Primitive for Line {
     type PointsIter = Points;
 
     fn points(&self) -> Self::PointsIter {
-        Points::new(self)
+        Points::new(&(*self).into())
+    }
+}
+
+impl ContainsPoint for Line {
+    /// Returns `true` if the point is one of the points returned by [`points`].
+    ///
+    /// [`points`]: #method.points
+    fn contains(&self, point: Point) -> bool {
+        SubpixelLine::from(*self).contains(point)
+    }
+}
+
+impl DistanceToPoint for Line {
+    fn distance(&self, point: Point) -> u32 {
+        SubpixelLine::from(*self).distance(point)
     }
 }
 
@@ -117,6 +139,215 @@ where
     type Item = Pixel<C>;
     type IntoIter = StyledLineIterator<C>;
 
+    fn into_iter(self) -> Self::IntoIter {
+        StyledLineIterator {
+            style: self.style,
+
+            line_iter: ThickLineIterator::new(
+                &self.primitive.into(),
+                self.style.stroke_width_i32(),
+            ),
+        }
+    }
+}
+
+impl<C> Styled<Line, PrimitiveStyle<C>>
+where
+    C: PixelColor,
+{
+    /// Returns an iterator over the pixels of the line that are inside the clipping area.
+    ///
+    /// The returned pixels are identical to the pixels inside `clip_area` that are returned by
+    /// the unclipped iterator, but pixels outside the clipping area are skipped without iterating
+    /// over them. This makes drawing long lines, which are mostly outside the visible area, much
+    /// faster.
+    ///
+    /// The default implementation of [`DrawTarget::draw_line`] uses this method to clip lines to
+    /// the size of the draw target.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use embedded_graphics::{
+    ///     pixelcolor::BinaryColor,
+    ///     prelude::*,
+    ///     primitives::{ContainsPoint, Line, Rectangle},
+    ///     style::PrimitiveStyle,
+    /// };
+    ///
+    /// let line = Line::new(Point::new(-1000, 5), Point::new(1000, 7))
+    ///     .into_styled(PrimitiveStyle::with_stroke(BinaryColor::On, 1));
+    ///
+    /// let clip_area = Rectangle::new(Point::zero(), Size::new(10, 10));
+    ///
+    /// assert!(line
+    ///     .clipped(&clip_area)
+    ///     .eq(line.into_iter().filter(|Pixel(p, _)| clip_area.contains(*p))));
+    /// ```
+    ///
+    /// [`DrawTarget::draw_line`]: ../../trait.DrawTarget.html#method.draw_line
+    pub fn clipped(&self, clip_area: &Rectangle) -> StyledLineIterator<C> {
+        StyledLineIterator {
+            style: self.style,
+
+            line_iter: ThickLineIterator::clipped(
+                &self.primitive.into(),
+                self.style.stroke_width_i32(),
+                clip_area,
+            ),
+        }
+    }
+}
+
+impl<C> ContainsPoint for Styled<Line, PrimitiveStyle<C>>
+where
+    C: PixelColor,
+{
+    /// Returns `true` if the point is one of the drawn pixels of the line.
+    fn contains(&self, point: Point) -> bool {
+        let pixel = Rectangle::new(point, Size::new(1, 1));
+
+        self.clipped(&pixel).next().is_some()
+    }
+}
+
+impl<C> DistanceToPoint for Styled<Line, PrimitiveStyle<C>>
+where
+    C: PixelColor,
+{
+    fn distance(&self, point: Point) -> u32 {
+        Styled::new(SubpixelLine::from(self.primitive), self.style).distance(point)
+    }
+}
+
+/// Line primitive with subpixel precision.
+///
+/// The start and end point of a `SubpixelLine` are [`SubpixelPoint`]s, which can be positioned
+/// between pixels. A subpixel line is drawn by using the pixels that are closest to the exact line
+/// between `start` and `end`, which allows smooth motion in animations.
+///
+/// A [`Line`] can be converted into a `SubpixelLine` by using `From`/`Into`. Both lines are drawn
+/// identically.
+///
+/// # Examples
+///
+/// ## Move a line by a quarter pixel per frame
+///
+/// ```rust
+/// use embedded_graphics::{
+///     geometry::SubpixelPoint, pixelcolor::BinaryColor, prelude::*, primitives::SubpixelLine,
+///     style::PrimitiveStyle,
+/// };
+/// # use embedded_graphics::mock_display::MockDisplay;
+/// # let mut display: MockDisplay<BinaryColor> = MockDisplay::default();
+///
+/// let start = SubpixelPoint::from_point(Point::new(2, 2));
+/// let end = SubpixelPoint::from_point(Point::new(40, 12));
+///
+/// // A quarter pixel is 64 in the 24.8 fixed point format
+/// let velocity = SubpixelPoint::new(0, 64);
+///
+/// for frame in 0..4 {
+///     SubpixelLine::new(start + velocity * frame, end + velocity * frame)
+///         .into_styled(PrimitiveStyle::with_stroke(BinaryColor::On, 1))
+///         .draw(&mut display)?;
+/// #   display = MockDisplay::default();
+/// }
+/// # Ok::<(), core::convert::Infallible>(())
+/// ```
+///
+/// [`SubpixelPoint`]: ../../geometry/struct.SubpixelPoint.html
+/// [`Line`]: struct.Line.html
+#[derive(Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Debug, Default)]
+#[cfg_attr(
+    feature = "serde_support",
+    derive(serde::Serialize, serde::Deserialize)
+)]
+pub struct SubpixelLine {
+    /// Start point
+    pub start: SubpixelPoint,
+
+    /// End point
+    pub end: SubpixelPoint,
+}
+
+impl SubpixelLine {
+    /// Creates a new subpixel line.
+    pub const fn new(start: SubpixelPoint, end: SubpixelPoint) -> Self {
+        Self { start, end }
+    }
+}
+
+impl From<Line> for SubpixelLine {
+    fn from(line: Line) -> Self {
+        Self::new(line.start.into(), line.end.into())
+    }
+}
+
+impl Primitive for SubpixelLine {
+    type PointsIter = Points;
+
+    fn points(&self) -> Self::PointsIter {
+        Points::new(self)
+    }
+}
+
+impl ContainsPoint for SubpixelLine {
+    /// Returns `true` if the point is one of the points returned by [`points`].
+    ///
+    /// [`points`]: #method.points
+    fn contains(&self, point: Point) -> bool {
+        let pixel = Rectangle::new(point, Size::new(1, 1));
+
+        ThickLineIterator::clipped(self, 1, &pixel).next().is_some()
+    }
+}
+
+impl DistanceToPoint for SubpixelLine {
+    fn distance(&self, point: Point) -> u32 {
+        if self.contains(point) {
+            return 0;
+        }
+
+        subpixels_to_pixels(segment_distance(self.start, self.end, point))
+    }
+}
+
+impl Dimensions for SubpixelLine {
+    /// Returns the bounding box of the drawn pixels.
+    ///
+    /// The first and last pixel of a subpixel line can be offset by one pixel from the rounded
+    /// end points.
+    fn bounding_box(&self) -> Rectangle {
+        let (start, end) = thick_line_iterator::end_points(self);
+
+        Rectangle::with_corners(start, end)
+    }
+}
+
+impl Transform for SubpixelLine {
+    /// Translate the line from its current position to a new position by (x, y) pixels, returning
+    /// a new `SubpixelLine`. For a mutating transform, see `translate_mut`.
+    fn translate(&self, by: Point) -> Self {
+        Self::new(self.start + by, self.end + by)
+    }
+
+    /// Translate the line from its current position to a new position by (x, y) pixels.
+    fn translate_mut(&mut self, by: Point) -> &mut Self {
+        self.start += by;
+        self.end += by;
+
+        self
+    }
+}
+
+impl<'a, C> IntoIterator for &'a Styled<SubpixelLine, PrimitiveStyle<C>>
+where
+    C: PixelColor,
+{
+    type Item = Pixel<C>;
+    type IntoIter = StyledLineIterator<C>;
+
     fn into_iter(self) -> Self::IntoIter {
         StyledLineIterator {
             style: self.style,
@@ -126,6 +357,110 @@ where
     }
 }
 
+impl<C> Styled<SubpixelLine, PrimitiveStyle<C>>
+where
+    C: PixelColor,
+{
+    /// Returns an iterator over the pixels of the line that are inside the clipping area.
+    ///
+    /// The returned pixels are identical to the pixels of the unclipped line that are inside
+    /// `clip_area`.
+    pub fn clipped(&self, clip_area: &Rectangle) -> StyledLineIterator<C> {
+        StyledLineIterator {
+            style: self.style,
+
+            line_iter: ThickLineIterator::clipped(
+                &self.primitive,
+                self.style.stroke_width_i32(),
+                clip_area,
+            ),
+        }
+    }
+}
+
+impl<C> ContainsPoint for Styled<SubpixelLine, PrimitiveStyle<C>>
+where
+    C: PixelColor,
+{
+    /// Returns `true` if the point is one of the drawn pixels of the line.
+    fn contains(&self, point: Point) -> bool {
+        let pixel = Rectangle::new(point, Size::new(1, 1));
+
+        self.clipped(&pixel).next().is_some()
+    }
+}
+
+impl<C> DistanceToPoint for Styled<SubpixelLine, PrimitiveStyle<C>>
+where
+    C: PixelColor,
+{
+    fn distance(&self, point: Point) -> u32 {
+        if self.contains(point) {
+            return 0;
+        }
+
+        let distance = segment_distance(self.primitive.start, self.primitive.end, point);
+
+        // The centers of the outermost pixels of a thick line are `(stroke_width - 1) / 2`
+        // pixels away from the center line.
+        let half_width = u64::from(self.style.stroke_width.saturating_sub(1)) * 128;
+
+        subpixels_to_pixels(distance.saturating_sub(half_width))
+    }
+}
+
+impl<'a, C: 'a> Drawable<C> for &Styled<SubpixelLine, PrimitiveStyle<C>>
+where
+    C: PixelColor,
+{
+    fn draw<D: DrawTarget<C>>(self, display: &mut D) -> Result<(), D::Error> {
+        let clip_area = Rectangle::new(Point::zero(), display.size());
+
+        display.draw_iter(self.clipped(&clip_area))
+    }
+}
+
+/// Returns the distance between a point and a line segment in 1/256 pixels.
+pub(crate) fn segment_distance(start: SubpixelPoint, end: SubpixelPoint, point: Point) -> u64 {
+    let point = SubpixelPoint::from_point(point);
+
+    let delta = |a: SubpixelPoint, b: SubpixelPoint| {
+        (
+            i128::from(b.x) - i128::from(a.x),
+            i128::from(b.y) - i128::from(a.y),
+        )
+    };
+    let length_squared = |(x, y): (i128, i128)| x * x + y * y;
+
+    let (dx, dy) = delta(start, end);
+    let (px, py) = delta(start, point);
+
+    let line_length_squared = length_squared((dx, dy));
+    let dot = px * dx + py * dy;
+
+    let distance_squared = if line_length_squared == 0 || dot <= 0 {
+        length_squared((px, py))
+    } else if dot >= line_length_squared {
+        length_squared(delta(end, point))
+    } else {
+        // Distance to the closest point on the line, calculated from the cross product
+        let cross = px * dy - py * dx;
+        cross.saturating_mul(cross) / line_length_squared
+    };
+
+    u64::from(isqrt(
+        distance_squared.min(i128::from(u64::max_value())) as u64
+    ))
+}
+
+/// Converts a distance in 1/256 pixels into whole pixels.
+///
+/// The result is rounded down, but is at least `1` because it is only used for points which
+/// aren't contained in a shape.
+pub(crate) fn subpixels_to_pixels(distance: u64) -> u32 {
+    (distance / 256).max(1).min(u64::from(u32::max_value())) as u32
+}
+
 /// Iterator over all points on the line.
 #[derive(Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Debug)]
 pub struct Points {
@@ -133,7 +468,7 @@ pub struct Points {
 }
 
 impl Points {
-    fn new(line: &Line) -> Self {
+    fn new(line: &SubpixelLine) -> Self {
         Self {
             line_iter: ThickLineIterator::new(line, 1),
         }
@@ -191,7 +526,11 @@ where
 mod tests {
     use super::*;
     use crate::{
-        drawable::Pixel, geometry::Size, mock_display::MockDisplay, pixelcolor::BinaryColor,
+        drawable::Pixel,
+        geometry::Size,
+        mock_display::MockDisplay,
+        pixelcolor::BinaryColor,
+        primitives::{ContainsPoint, TestRng},
     };
 
     fn test_expected_line(start: Point, end: Point, expected: &[(i32, i32)]) {
@@ -487,4 +826,193 @@ mod tests {
 
         assert!(line.points().eq(styled_points));
     }
+
+    #[test]
+    fn subpixel_integer_coordinates() -> Result<(), core::convert::Infallible> {
+        let line = Line::new(Point::new(2, 3), Point::new(17, 9));
+        let subpixel_line = SubpixelLine::from(line);
+
+        assert_eq!(
+            subpixel_line,
+            SubpixelLine::new(
+                SubpixelPoint::from_point(Point::new(2, 3)),
+                SubpixelPoint::from_point(Point::new(17, 9)),
+            )
+        );
+        assert_eq!(subpixel_line.bounding_box(), line.bounding_box());
+
+        let style = PrimitiveStyle::with_stroke(BinaryColor::On, 3);
+
+        let mut expected = MockDisplay::new();
+        line.into_styled(style).draw(&mut expected)?;
+
+        let mut display = MockDisplay::new();
+        subpixel_line.into_styled(style).draw(&mut display)?;
+
+        assert_eq!(display, expected);
+
+        Ok(())
+    }
+
+    #[test]
+    fn subpixel_translate() {
+        let start = SubpixelPoint::new(300, -100);
+        let end = SubpixelPoint::new(1000, 200);
+        let line = SubpixelLine::new(start, end);
+
+        let moved = line.translate(Point::new(1, 2));
+        assert_eq!(moved.start, start + Point::new(1, 2));
+        assert_eq!(moved.end, end + Point::new(1, 2));
+
+        let mut moved = line;
+        moved.translate_mut(Point::new(-3, 4));
+        assert_eq!(moved.start, start + Point::new(-3, 4));
+        assert_eq!(moved.end, end + Point::new(-3, 4));
+    }
+
+    #[test]
+    fn subpixel_line() -> Result<(), core::convert::Infallible> {
+        let line = SubpixelLine::new(
+            SubpixelPoint::from_f32(0.0, 0.75),
+            SubpixelPoint::from_f32(7.0, 2.75),
+        );
+
+        let mut display = MockDisplay::new();
+        line.into_styled(PrimitiveStyle::with_stroke(BinaryColor::On, 1))
+            .draw(&mut display)?;
+
+        #[rustfmt::skip]
+        assert_eq!(
+            display,
+            MockDisplay::from_pattern(&[
+                "        ",
+                "###     ",
+                "   #### ",
+                "       #",
+            ])
+        );
+
+        assert_eq!(
+            line.bounding_box(),
+            Rectangle::new(Point::new(0, 1), Size::new(8, 3))
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn thick_subpixel_line_whole_pixel_offset() -> Result<(), core::convert::Infallible> {
+        let start = SubpixelPoint::from_f32(1.0, 1.25);
+        let end = SubpixelPoint::from_f32(20.0, 9.25);
+        let offset = Point::new(2, 3);
+        let style = PrimitiveStyle::with_stroke(BinaryColor::On, 4);
+
+        let mut expected = MockDisplay::new();
+        SubpixelLine::new(start, end)
+            .translate(offset)
+            .into_styled(style)
+            .draw(&mut expected)?;
+
+        let mut display = MockDisplay::new();
+        SubpixelLine::new(start + offset, end + offset)
+            .into_styled(style)
+            .draw(&mut display)?;
+
+        assert_eq!(display, expected);
+
+        Ok(())
+    }
+
+    #[test]
+    fn clipped_identical_to_unclipped() {
+        let clip_areas = [
+            Rectangle::new(Point::new(3, 4), Size::new(10, 7)),
+            Rectangle::new(Point::new(-2, -5), Size::new(1, 30)),
+            Rectangle::new(Point::new(8, 8), Size::new(1, 1)),
+            Rectangle::new(Point::new(8, 8), Size::zero()),
+        ];
+
+        let mut rng = TestRng::new(12345);
+
+        for _ in 0..500 {
+            let start = SubpixelPoint::new(rng.next_signed(30 * 256), rng.next_signed(30 * 256));
+            let end = SubpixelPoint::new(rng.next_signed(30 * 256), rng.next_signed(30 * 256));
+            let stroke_width = (rng.next_signed(3) + 4) as u32;
+
+            let style = PrimitiveStyle::with_stroke(BinaryColor::On, stroke_width);
+
+            let line = Line::new(start.round(), end.round()).into_styled(style);
+            let subpixel_line = SubpixelLine::new(start, end).into_styled(style);
+
+            for clip_area in clip_areas.iter() {
+                let inside = |Pixel(p, _): &Pixel<BinaryColor>| clip_area.contains(*p);
+
+                assert!(
+                    line.clipped(clip_area).eq(line.into_iter().filter(inside)),
+                    "{:?} clipped to {:?}",
+                    line,
+                    clip_area
+                );
+                assert!(
+                    subpixel_line
+                        .clipped(clip_area)
+                        .eq(subpixel_line.into_iter().filter(inside)),
+                    "{:?} clipped to {:?}",
+                    subpixel_line,
+                    clip_area
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn draw_line_clips_to_display() {
+        let line = Line::new(Point::new(-10_000, -5_000), Point::new(10_000, 5_000))
+            .into_styled(PrimitiveStyle::with_stroke(BinaryColor::On, 3));
+
+        let mut clipped = MockDisplay::new();
+        line.draw(&mut clipped).unwrap();
+
+        let mut unclipped = MockDisplay::new();
+        unclipped.draw_iter(&line).unwrap();
+
+        assert_eq!(clipped, unclipped);
+    }
+
+    #[test]
+    fn contains() {
+        let line = Line::new(Point::new(2, 3), Point::new(17, 8));
+
+        for y in 0..12 {
+            for x in 0..20 {
+                let point = Point::new(x, y);
+                assert_eq!(line.contains(point), line.points().any(|p| p == point));
+
+                for stroke_width in 0..5 {
+                    let styled = line
+                        .into_styled(PrimitiveStyle::with_stroke(BinaryColor::On, stroke_width));
+                    assert_eq!(
+                        styled.contains(point),
+                        styled.into_iter().any(|Pixel(p, _)| p == point)
+                    );
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn distance() {
+        let line = Line::new(Point::new(10, 10), Point::new(40, 10));
+
+        assert_eq!(line.distance(Point::new(20, 10)), 0);
+        assert_eq!(line.distance(Point::new(20, 15)), 5);
+        assert_eq!(line.distance(Point::new(20, 11)), 1);
+        assert_eq!(line.distance(Point::new(4, 2)), 10);
+        assert_eq!(line.distance(Point::new(43, 14)), 5);
+
+        let styled = line.into_styled(PrimitiveStyle::with_stroke(BinaryColor::On, 5));
+        assert_eq!(styled.distance(Point::new(20, 12)), 0);
+        assert_eq!(styled.distance(Point::new(20, 15)), 3);
+        assert_eq!(styled.distance(Point::new(20, 13)), 1);
+    }
 }
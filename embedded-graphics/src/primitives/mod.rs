@@ -6,11 +6,16 @@ pub mod rectangle;
 mod thick_line_iterator;
 pub mod triangle;
 
-pub use self::{circle::Circle, line::Line, rectangle::Rectangle, triangle::Triangle};
+pub use self::{
+    circle::{Circle, SubpixelCircle},
+    line::{Line, SubpixelLine},
+    rectangle::Rectangle,
+    triangle::{SubpixelTriangle, Triangle},
+};
 use crate::{
     geometry::{Dimensions, Point},
     pixelcolor::PixelColor,
-    style::{PrimitiveStyle, Styled},
+    style::{Fill, PrimitiveStyle, Styled},
 };
 pub(crate) use thick_line_iterator::ThickLineIterator;
 
@@ -28,16 +33,63 @@ pub trait Primitive: Dimensions {
         Styled::new(self, style)
     }
 
+    /// Converts this primitive into a `Styled` which is filled using a [`Fill`].
+    ///
+    /// [`Fill`]: ../style/trait.Fill.html
+    fn into_filled<F>(self, fill: F) -> Styled<Self, F>
+    where
+        F: Fill,
+        Self: Sized,
+    {
+        Styled::new(self, fill)
+    }
+
     /// Returns an iterator over all points inside the primitive.
     fn points(&self) -> Self::PointsIter;
 }
 
 /// Trait to check if a point is inside a closed shape.
+///
+/// `ContainsPoint` is implemented for primitives, which checks if the point is inside the filled
+/// shape, and for styled primitives, which checks if the point hits one of the drawn pixels,
+/// including thick strokes.
 pub trait ContainsPoint {
     /// Returns `true` is the given point is inside the shape.
     fn contains(&self, point: Point) -> bool;
 }
 
+/// Trait to calculate the distance between a point and a shape.
+///
+/// This can be used to detect near misses, e.g. to make small objects on a touchscreen easier to
+/// hit.
+///
+/// # Examples
+///
+/// ```
+/// use embedded_graphics::{
+///     pixelcolor::BinaryColor,
+///     prelude::*,
+///     primitives::{ContainsPoint, DistanceToPoint, Line},
+///     style::PrimitiveStyle,
+/// };
+///
+/// let line = Line::new(Point::new(10, 10), Point::new(40, 10))
+///     .into_styled(PrimitiveStyle::with_stroke(BinaryColor::On, 3));
+///
+/// let touch = Point::new(20, 14);
+///
+/// assert!(!line.contains(touch));
+/// assert_eq!(line.distance(touch), 3);
+/// ```
+pub trait DistanceToPoint {
+    /// Returns the distance between the point and the shape in whole pixels.
+    ///
+    /// The distance is `0` if the shape contains the point and at least `1` otherwise. The
+    /// distance is calculated from the exact geometry of the shape and might therefore differ
+    /// slightly from the distance to the nearest drawn pixel.
+    fn distance(&self, point: Point) -> u32;
+}
+
 /// Create a [`Circle`](./primitives/circle/struct.Circle.html) with optional styling using a
 /// convenient macro.
 ///
@@ -363,6 +415,31 @@ macro_rules! egtriangle {
     }};
 }
 
+/// Linear congruential generator to create reproducible sets of test primitives.
+#[cfg(test)]
+pub(crate) struct TestRng {
+    seed: u32,
+}
+
+#[cfg(test)]
+impl TestRng {
+    pub fn new(seed: u32) -> Self {
+        Self { seed }
+    }
+
+    /// Returns a pseudo random number in the range `0..range`.
+    pub fn next(&mut self, range: u32) -> i32 {
+        self.seed = self.seed.wrapping_mul(1_103_515_245).wrapping_add(12345);
+
+        ((self.seed >> 16) % range) as i32
+    }
+
+    /// Returns a pseudo random number in the range `-range..=range`.
+    pub fn next_signed(&mut self, range: i32) -> i32 {
+        self.next(2 * range as u32 + 1) - range
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
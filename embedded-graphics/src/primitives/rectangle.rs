@@ -1,10 +1,10 @@
 //! The rectangle primitive. Also good for drawing squares.
 
 use crate::{
-    drawable::{Drawable, Pixel},
-    geometry::{Dimensions, Point, Size},
+    drawable::{Drawable, Pixel, Spans},
+    geometry::{isqrt, AnchorPoint, AxisAnchor, Dimensions, Point, Size},
     pixelcolor::PixelColor,
-    primitives::{ContainsPoint, Primitive},
+    primitives::{ContainsPoint, DistanceToPoint, Primitive},
     style::{PrimitiveStyle, Styled},
     transform::Transform,
     DrawTarget,
@@ -44,7 +44,17 @@ use core::cmp::min;
 ///     .draw(&mut display)?;
 /// # Ok::<(), core::convert::Infallible>(())
 /// ```
+///
+/// Two-way conversions to [`euclid`]'s `Rect<i32, U>` and `Box2D<i32, U>` can be enabled with the
+/// `euclid_support` feature. Negative sizes are clamped to zero when a `Rect` is converted into a
+/// `Rectangle`.
+///
+/// [`euclid`]: https://docs.rs/euclid
 #[derive(Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Debug, Default)]
+#[cfg_attr(
+    feature = "serde_support",
+    derive(serde::Serialize, serde::Deserialize)
+)]
 pub struct Rectangle {
     /// Top left point of the rectangle.
     pub top_left: Point,
@@ -63,17 +73,36 @@ impl Primitive for Rectangle {
 
 impl ContainsPoint for Rectangle {
     fn contains(&self, point: Point) -> bool {
-        if point.x >= self.top_left.x && point.y >= self.top_left.y {
-            // FIXME: use Rectangle::bottom_right
-            let delta = Size::from_bounding_box(self.top_left, point);
-
-            delta.width <= self.size.width && delta.height <= self.size.height
+        if let Some(bottom_right) = self.bottom_right() {
+            point.x >= self.top_left.x
+                && point.y >= self.top_left.y
+                && point.x <= bottom_right.x
+                && point.y <= bottom_right.y
         } else {
             false
         }
     }
 }
 
+impl DistanceToPoint for Rectangle {
+    fn distance(&self, point: Point) -> u32 {
+        if self.contains(point) {
+            return 0;
+        }
+
+        let axis_distance = |value: i32, start: i32, length: u32| {
+            let (value, min) = (i64::from(value), i64::from(start));
+            let max = min + i64::from(length) - 1;
+
+            (min - value).max(value - max).max(0) as u64
+        };
+        let dx = axis_distance(point.x, self.top_left.x, self.size.width);
+        let dy = axis_distance(point.y, self.top_left.y, self.size.height);
+
+        isqrt(dx.pow(2).saturating_add(dy.pow(2))).max(1)
+    }
+}
+
 impl Dimensions for Rectangle {
     fn bounding_box(&self) -> Rectangle {
         *self
@@ -107,6 +136,167 @@ impl Rectangle {
 
         self.top_left + Size::new(dx, dy)
     }
+
+    /// Creates a new rectangle from the center point and the size.
+    ///
+    /// For rectangles with even width and/or height the top left corner is placed so that
+    /// [`center`] returns `center`.
+    ///
+    /// [`center`]: #method.center
+    pub fn with_center(center: Point, size: Size) -> Self {
+        let offset = Point::new(
+            AxisAnchor::Center.offset(size.width),
+            AxisAnchor::Center.offset(size.height),
+        );
+
+        Self::new(center - offset, size)
+    }
+
+    /// Returns the bottom right corner of this rectangle.
+    ///
+    /// Because the smallest rectangle that contains a point has a size of `1x1`, the bottom
+    /// right corner is the last point inside the rectangle. `None` is returned for zero sized
+    /// rectangles, which don't contain any points.
+    pub fn bottom_right(&self) -> Option<Point> {
+        if self.is_zero_sized() {
+            None
+        } else {
+            Some(self.top_left + self.size - Point::new(1, 1))
+        }
+    }
+
+    /// Returns `true` if the width or the height of this rectangle is zero.
+    pub fn is_zero_sized(&self) -> bool {
+        self.size.width == 0 || self.size.height == 0
+    }
+
+    /// Returns the intersection of two rectangles.
+    ///
+    /// A zero sized rectangle is returned if the rectangles don't overlap.
+    ///
+    /// ```
+    /// use embedded_graphics::{prelude::*, primitives::Rectangle};
+    ///
+    /// let rect1 = Rectangle::new(Point::new(0, 0), Size::new(10, 10));
+    /// let rect2 = Rectangle::new(Point::new(5, 2), Size::new(10, 3));
+    ///
+    /// assert_eq!(
+    ///     rect1.intersection(&rect2),
+    ///     Rectangle::new(Point::new(5, 2), Size::new(5, 3))
+    /// );
+    /// ```
+    pub fn intersection(&self, other: &Rectangle) -> Rectangle {
+        match (self.bottom_right(), other.bottom_right()) {
+            (Some(bottom_right_a), Some(bottom_right_b)) => {
+                let top_left = self.top_left.component_max(other.top_left);
+                let bottom_right = bottom_right_a.component_min(bottom_right_b);
+
+                if top_left.x <= bottom_right.x && top_left.y <= bottom_right.y {
+                    Rectangle::with_corners(top_left, bottom_right)
+                } else {
+                    Rectangle::default()
+                }
+            }
+            _ => Rectangle::default(),
+        }
+    }
+
+    /// Returns the smallest rectangle that contains both rectangles.
+    ///
+    /// Zero sized rectangles are ignored, which makes it possible to start with an empty
+    /// rectangle and extend it with other rectangles, for example to track dirty regions.
+    ///
+    /// ```
+    /// use embedded_graphics::{prelude::*, primitives::Rectangle};
+    ///
+    /// let rect1 = Rectangle::new(Point::new(0, 0), Size::new(10, 10));
+    /// let rect2 = Rectangle::new(Point::new(5, 2), Size::new(10, 3));
+    ///
+    /// assert_eq!(
+    ///     rect1.envelope(&rect2),
+    ///     Rectangle::new(Point::new(0, 0), Size::new(15, 10))
+    /// );
+    /// ```
+    pub fn envelope(&self, other: &Rectangle) -> Rectangle {
+        match (self.bottom_right(), other.bottom_right()) {
+            (Some(bottom_right_a), Some(bottom_right_b)) => Rectangle::with_corners(
+                self.top_left.component_min(other.top_left),
+                bottom_right_a.component_max(bottom_right_b),
+            ),
+            (Some(_), None) => *self,
+            (None, _) => *other,
+        }
+    }
+
+    /// Returns a new rectangle with all edges moved outwards by `offset` pixels.
+    ///
+    /// Negative offsets move the edges inwards. If the rectangle is shrunk by more than its size
+    /// a zero sized rectangle at the center of the original rectangle is returned. The size of the
+    /// returned rectangle is limited to `u32::max_value()` and the coordinates saturate at the
+    /// `i32` range.
+    pub fn offset(&self, offset: i32) -> Rectangle {
+        let (x, width) = offset_axis(self.top_left.x, self.size.width, offset);
+        let (y, height) = offset_axis(self.top_left.y, self.size.height, offset);
+
+        Rectangle::new(Point::new(x, y), Size::new(width, height))
+    }
+
+    /// Returns a new rectangle with all edges moved inwards by `inset` pixels.
+    ///
+    /// This is equivalent to calling [`offset`] with a negated value.
+    ///
+    /// [`offset`]: #method.offset
+    pub fn inset(&self, inset: i32) -> Rectangle {
+        self.offset(inset.checked_neg().unwrap_or(i32::max_value()))
+    }
+
+    /// Returns the position of an anchor point.
+    ///
+    /// The right and bottom anchor points refer to the last column and row inside the rectangle.
+    /// Center points are rounded in the same way as [`center`].
+    ///
+    /// [`center`]: #method.center
+    pub fn anchor_point(&self, anchor: AnchorPoint) -> Point {
+        let (x, y) = anchor.axes();
+
+        self.top_left + Point::new(x.offset(self.size.width), y.offset(self.size.height))
+    }
+
+    /// Returns a resized copy of this rectangle.
+    ///
+    /// The rectangle is resized so that the position of the given anchor point is unchanged.
+    ///
+    /// ```
+    /// use embedded_graphics::{geometry::AnchorPoint, prelude::*, primitives::Rectangle};
+    ///
+    /// let rect = Rectangle::new(Point::new(20, 20), Size::new(10, 10));
+    ///
+    /// let resized = rect.resized(Size::new(20, 4), AnchorPoint::BottomRight);
+    ///
+    /// assert_eq!(resized, Rectangle::new(Point::new(10, 26), Size::new(20, 4)));
+    /// ```
+    pub fn resized(&self, size: Size, anchor: AnchorPoint) -> Rectangle {
+        let (x, y) = anchor.axes();
+
+        let top_left =
+            self.anchor_point(anchor) - Point::new(x.offset(size.width), y.offset(size.height));
+
+        Rectangle::new(top_left, size)
+    }
+}
+
+/// Moves both edges of a rectangle along one axis outwards.
+fn offset_axis(start: i32, length: u32, offset: i32) -> (i32, u32) {
+    let new_length = i64::from(length) + 2 * i64::from(offset);
+
+    if new_length > 0 {
+        (
+            start.saturating_sub(offset),
+            new_length.min(i64::from(u32::max_value())) as u32,
+        )
+    } else {
+        (start.saturating_add((length / 2) as i32), 0)
+    }
 }
 
 impl Transform for Rectangle {
@@ -147,6 +337,19 @@ impl Transform for Rectangle {
     }
 }
 
+impl<C> Styled<Rectangle, PrimitiveStyle<C>>
+where
+    C: PixelColor,
+{
+    /// Returns an iterator over the horizontal spans of the styled rectangle.
+    ///
+    /// Consecutive pixels in a row which have the same color are combined into a single span.
+    /// Drawing the spans results in the same output as drawing the pixels of the styled rectangle.
+    pub fn spans(&self) -> Spans<StyledRectangleIterator<C>, C> {
+        Spans::new(self)
+    }
+}
+
 impl<C> IntoIterator for &Styled<Rectangle, PrimitiveStyle<C>>
 where
     C: PixelColor,
@@ -159,6 +362,59 @@ where
     }
 }
 
+impl<C> ContainsPoint for Styled<Rectangle, PrimitiveStyle<C>>
+where
+    C: PixelColor,
+{
+    /// Returns `true` if the point is one of the drawn pixels of the rectangle.
+    fn contains(&self, point: Point) -> bool {
+        if self.style.is_transparent() || !self.primitive.contains(point) {
+            return false;
+        }
+
+        if fill_area(self).contains(point) {
+            self.style.fill_color.is_some()
+        } else {
+            self.style.stroke_color.is_some()
+        }
+    }
+}
+
+impl<C> DistanceToPoint for Styled<Rectangle, PrimitiveStyle<C>>
+where
+    C: PixelColor,
+{
+    fn distance(&self, point: Point) -> u32 {
+        if self.contains(point) {
+            return 0;
+        }
+
+        let fill_area = fill_area(self);
+        if !fill_area.contains(point) {
+            return self.primitive.distance(point);
+        }
+
+        // The point is inside an unfilled rectangle: return the distance to the stroke.
+        let bottom_right = fill_area.top_left + fill_area.size;
+        let distance =
+            (point - fill_area.top_left).component_min(bottom_right - point - Point::new(1, 1));
+
+        distance.x.min(distance.y) as u32 + 1
+    }
+}
+
+/// Returns the area of a styled rectangle which is drawn using the fill color.
+fn fill_area<C>(styled: &Styled<Rectangle, PrimitiveStyle<C>>) -> Rectangle
+where
+    C: PixelColor,
+{
+    let stroke_width = styled.style.effective_stroke_width();
+    let stroke_offset = Size::new(stroke_width, stroke_width);
+    let fill_area_size = styled.primitive.size.saturating_sub(stroke_offset * 2);
+
+    Rectangle::new(styled.primitive.top_left + stroke_offset, fill_area_size)
+}
+
 /// Iterator over all points inside the rectangle.
 #[derive(Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Debug)]
 pub struct Points {
@@ -233,15 +489,10 @@ where
             Points::empty()
         };
 
-        let stroke_width = styled.style.effective_stroke_width();
-        let stroke_offset = Size::new(stroke_width, stroke_width);
-        let fill_area_size = styled.primitive.size.saturating_sub(stroke_offset * 2);
-        let fill_area = Rectangle::new(styled.primitive.top_left + stroke_offset, fill_area_size);
-
         Self {
             iter,
             stroke_color: styled.style.stroke_color,
-            fill_area,
+            fill_area: fill_area(styled),
             fill_color: styled.style.fill_color,
         }
     }
@@ -279,6 +530,57 @@ where
     }
 }
 
+#[cfg(feature = "euclid_support")]
+impl<U> From<euclid::Rect<i32, U>> for Rectangle {
+    fn from(other: euclid::Rect<i32, U>) -> Self {
+        Self::new(
+            Point::new(other.origin.x, other.origin.y),
+            Size::new(
+                other.size.width.max(0) as u32,
+                other.size.height.max(0) as u32,
+            ),
+        )
+    }
+}
+
+#[cfg(feature = "euclid_support")]
+impl<U> From<Rectangle> for euclid::Rect<i32, U> {
+    fn from(other: Rectangle) -> Self {
+        Self::new(
+            euclid::Point2D::new(other.top_left.x, other.top_left.y),
+            euclid::Size2D::new(
+                other.size.width.min(i32::max_value() as u32) as i32,
+                other.size.height.min(i32::max_value() as u32) as i32,
+            ),
+        )
+    }
+}
+
+#[cfg(feature = "euclid_support")]
+impl<U> From<euclid::Box2D<i32, U>> for Rectangle {
+    fn from(other: euclid::Box2D<i32, U>) -> Self {
+        // The maximum point of a `Box2D` isn't included in the box
+        let size = other.max - other.min;
+
+        Self::new(
+            Point::new(other.min.x, other.min.y),
+            Size::new(size.x.max(0) as u32, size.y.max(0) as u32),
+        )
+    }
+}
+
+#[cfg(feature = "euclid_support")]
+impl<U> From<Rectangle> for euclid::Box2D<i32, U> {
+    fn from(other: Rectangle) -> Self {
+        let max = other.top_left + other.size;
+
+        Self::new(
+            euclid::Point2D::new(other.top_left.x, other.top_left.y),
+            euclid::Point2D::new(max.x, max.y),
+        )
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -394,4 +696,300 @@ mod tests {
         let even = Rectangle::new(Point::new(20, 30), Size::new(4, 8));
         assert_eq!(even.center(), Point::new(21, 33));
     }
+
+    #[test]
+    fn with_center() {
+        let odd = Rectangle::with_center(Point::new(12, 23), Size::new(5, 7));
+        assert_eq!(odd, Rectangle::new(Point::new(10, 20), Size::new(5, 7)));
+
+        let even = Rectangle::with_center(Point::new(21, 33), Size::new(4, 8));
+        assert_eq!(even, Rectangle::new(Point::new(20, 30), Size::new(4, 8)));
+    }
+
+    #[test]
+    fn bottom_right() {
+        let rect = Rectangle::new(Point::new(10, 20), Size::new(1, 3));
+        assert_eq!(rect.bottom_right(), Some(Point::new(10, 22)));
+
+        let zero = Rectangle::new(Point::new(10, 20), Size::new(0, 3));
+        assert_eq!(zero.bottom_right(), None);
+        assert!(zero.is_zero_sized());
+        assert!(!rect.is_zero_sized());
+    }
+
+    #[test]
+    fn contains_zero_sized() {
+        let zero = Rectangle::new(Point::new(10, 20), Size::new(3, 0));
+
+        assert!(!zero.contains(Point::new(10, 20)));
+    }
+
+    #[test]
+    fn intersection() {
+        let rect1 = Rectangle::new(Point::new(-2, -3), Size::new(8, 6));
+        let rect2 = Rectangle::new(Point::new(2, 0), Size::new(10, 10));
+        let expected = Rectangle::new(Point::new(2, 0), Size::new(4, 3));
+
+        assert_eq!(rect1.intersection(&rect2), expected);
+        assert_eq!(rect2.intersection(&rect1), expected);
+        assert_eq!(rect1.intersection(&rect1), rect1);
+
+        for p in rect1.envelope(&rect2).points() {
+            assert_eq!(
+                expected.contains(p),
+                rect1.contains(p) && rect2.contains(p),
+                "{:?}",
+                p
+            );
+        }
+    }
+
+    #[test]
+    fn intersection_without_overlap() {
+        let rect1 = Rectangle::new(Point::new(0, 0), Size::new(5, 5));
+        let touching = Rectangle::new(Point::new(5, 0), Size::new(5, 5));
+        let zero = Rectangle::new(Point::new(1, 1), Size::zero());
+
+        assert!(rect1.intersection(&touching).is_zero_sized());
+        assert!(rect1.intersection(&zero).is_zero_sized());
+    }
+
+    #[test]
+    fn envelope() {
+        let rect1 = Rectangle::new(Point::new(-2, -3), Size::new(3, 2));
+        let rect2 = Rectangle::new(Point::new(5, 1), Size::new(1, 4));
+        let expected = Rectangle::with_corners(Point::new(-2, -3), Point::new(5, 4));
+
+        assert_eq!(rect1.envelope(&rect2), expected);
+        assert_eq!(rect2.envelope(&rect1), expected);
+
+        let zero = Rectangle::new(Point::new(100, 100), Size::zero());
+        assert_eq!(rect1.envelope(&zero), rect1);
+        assert_eq!(zero.envelope(&rect1), rect1);
+    }
+
+    #[test]
+    fn offset_and_inset() {
+        let rect = Rectangle::new(Point::new(10, 20), Size::new(6, 5));
+
+        assert_eq!(
+            rect.offset(2),
+            Rectangle::new(Point::new(8, 18), Size::new(10, 9))
+        );
+        assert_eq!(
+            rect.inset(2),
+            Rectangle::new(Point::new(12, 22), Size::new(2, 1))
+        );
+        assert_eq!(rect.offset(-2), rect.inset(2));
+        assert_eq!(
+            rect.inset(3),
+            Rectangle::new(Point::new(13, 22), Size::new(0, 0))
+        );
+        assert_eq!(rect.offset(0), rect);
+    }
+
+    #[test]
+    fn offset_and_inset_extreme_values() {
+        let rect = Rectangle::new(Point::new(10, 20), Size::new(6, 5));
+
+        assert_eq!(
+            rect.inset(i32::min_value()),
+            Rectangle::new(
+                Point::new(10 - i32::max_value(), 20 - i32::max_value()),
+                Size::new(u32::max_value(), u32::max_value())
+            )
+        );
+        assert_eq!(rect.inset(i32::min_value()), rect.offset(i32::max_value()));
+        assert_eq!(
+            rect.inset(i32::max_value()),
+            Rectangle::new(Point::new(13, 22), Size::zero())
+        );
+
+        let large = Rectangle::new(
+            Point::new(i32::max_value(), 0),
+            Size::new(u32::max_value(), 1),
+        );
+        assert_eq!(
+            large.offset(i32::max_value()),
+            Rectangle::new(
+                Point::new(0, -i32::max_value()),
+                Size::new(u32::max_value(), u32::max_value())
+            )
+        );
+        assert_eq!(
+            large.inset(i32::max_value()),
+            Rectangle::new(Point::new(i32::max_value(), 0), Size::new(1, 0))
+        );
+    }
+
+    #[test]
+    fn anchor_point() {
+        let rect = Rectangle::new(Point::new(10, 20), Size::new(5, 4));
+
+        assert_eq!(rect.anchor_point(AnchorPoint::TopLeft), Point::new(10, 20));
+        assert_eq!(
+            rect.anchor_point(AnchorPoint::TopCenter),
+            Point::new(12, 20)
+        );
+        assert_eq!(rect.anchor_point(AnchorPoint::TopRight), Point::new(14, 20));
+        assert_eq!(
+            rect.anchor_point(AnchorPoint::CenterLeft),
+            Point::new(10, 21)
+        );
+        assert_eq!(rect.anchor_point(AnchorPoint::Center), rect.center());
+        assert_eq!(
+            rect.anchor_point(AnchorPoint::CenterRight),
+            Point::new(14, 21)
+        );
+        assert_eq!(
+            rect.anchor_point(AnchorPoint::BottomLeft),
+            Point::new(10, 23)
+        );
+        assert_eq!(
+            rect.anchor_point(AnchorPoint::BottomCenter),
+            Point::new(12, 23)
+        );
+        assert_eq!(
+            rect.anchor_point(AnchorPoint::BottomRight),
+            Point::new(14, 23)
+        );
+    }
+
+    #[test]
+    fn resized() {
+        let rect = Rectangle::new(Point::new(10, 20), Size::new(5, 5));
+
+        assert_eq!(
+            rect.resized(Size::new(3, 7), AnchorPoint::TopLeft),
+            Rectangle::new(Point::new(10, 20), Size::new(3, 7))
+        );
+        assert_eq!(
+            rect.resized(Size::new(3, 7), AnchorPoint::Center),
+            Rectangle::new(Point::new(11, 19), Size::new(3, 7))
+        );
+        assert_eq!(
+            rect.resized(Size::new(3, 7), AnchorPoint::BottomRight),
+            Rectangle::new(Point::new(12, 18), Size::new(3, 7))
+        );
+        assert_eq!(
+            rect.resized(Size::new(3, 7), AnchorPoint::CenterRight),
+            Rectangle::new(Point::new(12, 19), Size::new(3, 7))
+        );
+    }
+
+    #[test]
+    #[cfg(feature = "euclid_support")]
+    fn euclid_support() {
+        let rect = Rectangle::new(Point::new(-1, 2), Size::new(10, 20));
+
+        let euclid_rect: euclid::default::Rect<i32> = rect.into();
+        assert_eq!(euclid_rect, euclid::rect(-1, 2, 10, 20));
+        assert_eq!(Rectangle::from(euclid_rect), rect);
+
+        let euclid_box: euclid::default::Box2D<i32> = rect.into();
+        assert_eq!(
+            euclid_box,
+            euclid::Box2D::new(euclid::point2(-1, 2), euclid::point2(9, 22))
+        );
+        assert_eq!(Rectangle::from(euclid_box), rect);
+
+        let negative: euclid::default::Rect<i32> = euclid::rect(1, 2, -3, 4);
+        assert_eq!(
+            Rectangle::from(negative),
+            Rectangle::new(Point::new(1, 2), Size::new(0, 4))
+        );
+    }
+
+    #[test]
+    fn spans() {
+        use crate::{drawable::Span, style::PrimitiveStyleBuilder};
+
+        let style = PrimitiveStyleBuilder::new()
+            .stroke_color(Rgb565::RED)
+            .stroke_width(1)
+            .fill_color(Rgb565::GREEN)
+            .build();
+
+        let rectangle = Rectangle::new(Point::new(2, 3), Size::new(5, 3)).into_styled(style);
+
+        let mut spans = rectangle.spans();
+        assert_eq!(
+            spans.next(),
+            Some(Span::new(Point::new(2, 3), 5, Rgb565::RED))
+        );
+        assert_eq!(
+            spans.next(),
+            Some(Span::new(Point::new(2, 4), 1, Rgb565::RED))
+        );
+        assert_eq!(
+            spans.next(),
+            Some(Span::new(Point::new(3, 4), 3, Rgb565::GREEN))
+        );
+        assert_eq!(
+            spans.next(),
+            Some(Span::new(Point::new(6, 4), 1, Rgb565::RED))
+        );
+        assert_eq!(
+            spans.next(),
+            Some(Span::new(Point::new(2, 5), 5, Rgb565::RED))
+        );
+        assert_eq!(spans.next(), None);
+    }
+
+    #[test]
+    fn styled_contains_and_distance() {
+        use crate::style::PrimitiveStyleBuilder;
+
+        let rectangle = Rectangle::new(Point::new(2, 3), Size::new(10, 8));
+
+        let stroke = rectangle.into_styled(PrimitiveStyle::with_stroke(Rgb565::RED, 2));
+        let fill = rectangle.into_styled(
+            PrimitiveStyleBuilder::new()
+                .stroke_color(Rgb565::RED)
+                .stroke_width(2)
+                .fill_color(Rgb565::GREEN)
+                .build(),
+        );
+
+        for y in 0..15 {
+            for x in 0..15 {
+                let point = Point::new(x, y);
+                for styled in [stroke, fill].iter() {
+                    assert_eq!(
+                        styled.contains(point),
+                        styled.into_iter().any(|Pixel(p, _)| p == point)
+                    );
+                }
+            }
+        }
+
+        assert_eq!(rectangle.distance(Point::new(5, 5)), 0);
+        assert_eq!(rectangle.distance(Point::new(5, 0)), 3);
+        assert_eq!(rectangle.distance(Point::new(15, 14)), 5);
+
+        assert_eq!(fill.distance(Point::new(6, 6)), 0);
+        assert_eq!(stroke.distance(Point::new(4, 6)), 1);
+        assert_eq!(stroke.distance(Point::new(6, 6)), 2);
+        assert_eq!(stroke.distance(Point::new(5, 0)), 3);
+    }
+
+    #[test]
+    fn distance_to_distant_points() {
+        let rectangle = Rectangle::new(Point::new(0, 0), Size::new(10, 10));
+
+        assert_eq!(rectangle.distance(Point::new(i32::min_value(), 0)), 1 << 31);
+        assert_eq!(
+            rectangle.distance(Point::new(i32::max_value(), 5)),
+            i32::max_value() as u32 - 9
+        );
+
+        let rectangle = Rectangle::new(
+            Point::new(i32::max_value() - 1, i32::max_value() - 1),
+            Size::new(1, 1),
+        );
+        assert_eq!(
+            rectangle.distance(Point::new(i32::min_value(), i32::min_value())),
+            u32::max_value()
+        );
+    }
 }
@@ -1,5 +1,8 @@
 use crate::geometry::Point;
-use crate::primitives::Line;
+use crate::primitives::{Rectangle, SubpixelLine};
+
+/// Number of subpixels per pixel in the coordinates of a `SubpixelPoint`.
+const SUBPIXELS: i32 = 256;
 
 /// Which side of the center line to draw on
 ///
@@ -28,24 +31,30 @@ struct ParallelLineState {
 
     /// Length accumulator
     ///
-    /// Checked against `parallel_length` of the line to know when to stop iterating
+    /// Checked against `end_length` to know when to stop iterating
     current_length: u32,
 
+    /// Length of the last pixel on the line
+    ///
+    /// This is equal to `parallel_length` of the line, unless the line was clipped.
+    end_length: u32,
+
     /// Error accumulator
     error: i32,
 }
 
 impl ParallelLineState {
-    fn new(start_point: Point, initial_length: u32, initial_error: i32) -> Self {
+    fn new(start_point: Point, initial_length: u32, initial_error: i32, end_length: u32) -> Self {
         Self {
             current_point: start_point,
             current_length: initial_length,
+            end_length,
             error: initial_error,
         }
     }
 
     fn next(&mut self, parameters: &ThickLineParameters) -> Option<Point> {
-        if self.current_length > parameters.parallel_length {
+        if self.current_length > self.end_length {
             return None;
         }
 
@@ -62,6 +71,117 @@ impl ParallelLineState {
         self.error += parameters.e_square;
         Some(p)
     }
+
+    /// Returns the state after `steps` calls to `next`.
+    ///
+    /// The position and error are calculated directly, without stepping along the line.
+    fn advanced(&self, steps: u32, parameters: &ThickLineParameters) -> Self {
+        let dx = -i64::from(parameters.e_diag) / 2;
+        let e_square = i64::from(parameters.e_square);
+
+        let mut steps = i64::from(steps);
+        let mut major_steps = 0;
+        let mut minor_steps = 0;
+        let mut error = i64::from(self.error);
+
+        // The error of parallel lines can start outside of the range `-dx < error <= dx`, which
+        // is maintained by the Bresenham algorithm. If the error is too small, only major steps
+        // are made until the error is large enough to enter the range.
+        if error + e_square <= -dx {
+            let plain_steps = if e_square > 0 {
+                div_ceil(-dx - error - e_square + 1, e_square).min(steps)
+            } else {
+                steps
+            };
+
+            major_steps += plain_steps;
+            error += e_square * plain_steps;
+            steps -= plain_steps;
+        }
+
+        // If the error is too large, a minor step is made for every major step until the error
+        // enters the range.
+        if error + e_square > 3 * dx {
+            let diagonal_steps = if dx > e_square / 2 {
+                div_ceil(error + e_square - 3 * dx, 2 * dx - e_square).min(steps)
+            } else {
+                steps
+            };
+
+            major_steps += diagonal_steps;
+            minor_steps += diagonal_steps;
+            error += (e_square - 2 * dx) * diagonal_steps;
+            steps -= diagonal_steps;
+        }
+
+        // Inside the range each step keeps the error in the range `-dx < error <= dx` by making
+        // a minor step whenever it would leave this range.
+        if steps > 0 {
+            let range_minor_steps = div_ceil(error + e_square * steps - dx, 2 * dx);
+
+            major_steps += steps;
+            minor_steps += range_minor_steps;
+            error += e_square * steps - 2 * dx * range_minor_steps;
+        }
+
+        Self {
+            current_point: self.current_point
+                + parameters.step_major * major_steps as i32
+                + parameters.step_minor * minor_steps as i32,
+            current_length: self.current_length + major_steps as u32,
+            end_length: self.end_length,
+            error: error as i32,
+        }
+    }
+
+    /// Skips all pixels which are outside the clipping area.
+    ///
+    /// The pixels that remain are identical to the pixels that are inside the clipping area when
+    /// the unclipped line is drawn.
+    fn clip(&mut self, parameters: &ThickLineParameters, top_left: Point, bottom_right: Point) {
+        if self.current_length > self.end_length {
+            return;
+        }
+
+        let last = i64::from(self.end_length - self.current_length);
+
+        // The coordinate along the major axis increases by one for every step.
+        let (major_min, major_max) = axis_range(parameters.step_major, top_left, bottom_right);
+        let major = i64::from(axis(self.current_point, parameters.step_major));
+        let first = (major_min - major).max(0);
+        let last = (major_max - major).min(last);
+
+        if first > last {
+            self.finish();
+            return;
+        }
+
+        // The coordinate along the minor axis is monotonically increasing, which allows the
+        // visible range to be found by a binary search.
+        let (minor_min, minor_max) = axis_range(parameters.step_minor, top_left, bottom_right);
+        let minor = |steps: i64| {
+            let point = self.advanced(steps as u32, parameters).current_point;
+            i64::from(axis(point, parameters.step_minor))
+        };
+
+        let first = partition_point(first, last + 1, |steps| minor(steps) < minor_min);
+        let last = partition_point(first, last + 1, |steps| minor(steps) <= minor_max) - 1;
+
+        if first > last {
+            self.finish();
+            return;
+        }
+
+        let end_length = self.current_length + last as u32;
+        *self = self.advanced(first as u32, parameters);
+        self.end_length = end_length;
+    }
+
+    /// Removes all remaining pixels from the line.
+    fn finish(&mut self) {
+        self.current_length = 1;
+        self.end_length = 0;
+    }
 }
 
 /// Current side state
@@ -107,14 +227,16 @@ impl SideState {
 
             if self.p_error > parameters.threshold {
                 let parallel = match swapped_side {
-                    Side::Right => ParallelLineState::new(
+                    Side::Right => parameters.parallel_line(
                         parallel_start + parameters.step_minor,
                         1,
                         -self.p_error,
                     ),
-                    Side::Left => {
-                        ParallelLineState::new(parallel_start, 0, self.p_error + parameters.e_diag)
-                    }
+                    Side::Left => parameters.parallel_line(
+                        parallel_start,
+                        0,
+                        self.p_error + parameters.e_diag,
+                    ),
                 };
 
                 self.p_error += parameters.e_diag + parameters.e_square;
@@ -138,7 +260,7 @@ impl SideState {
             Side::Right => -self.p_error,
         };
 
-        let parallel = ParallelLineState::new(self.parallel_start, 0, p_error);
+        let parallel = parameters.parallel_line(self.parallel_start, 0, p_error);
 
         (parallel, thickness_change)
     }
@@ -163,7 +285,7 @@ struct ThickLineParameters {
     /// article](http://kt8216.unixcab.org/murphy/index.html). The difference in this implementation
     /// is that both sides of the comparison are squared, removing the need for an expensive
     /// `sqrt()` call.
-    thickness: i32,
+    thickness: i64,
 
     /// The "major" step
     ///
@@ -183,12 +305,22 @@ struct ThickLineParameters {
 
     /// Length of parallel lines
     parallel_length: u32,
+
+    /// First pixel of the center line
+    start: Point,
+
+    /// Initial error of the center line, which depends on the subpixel position of the start point
+    initial_error: i32,
 }
 
 impl ThickLineParameters {
-    fn new(line: &Line, stroke_width: i32) -> Self {
-        let dx: i32 = line.end.x - line.start.x;
-        let dy: i32 = line.end.y - line.start.y;
+    fn new(line: &SubpixelLine, stroke_width: i32) -> Self {
+        // All deltas and error terms are calculated in subpixel units.
+        let start = line.start;
+        let end = line.end;
+
+        let dx: i32 = end.x - start.x;
+        let dy: i32 = end.y - start.y;
 
         let direction = match (dx >= 0, dy >= 0) {
             (true, true) => Point::new(1, 1),
@@ -207,7 +339,8 @@ impl ThickLineParameters {
 
         // Thickness threshold, taking into account that fewer pixels are required to draw a
         // diagonal line of the same perceived width.
-        let thickness = 4 * stroke_width.pow(2) * (dx.pow(2) + dy.pow(2));
+        let thickness =
+            4 * i64::from(stroke_width).pow(2) * (i64::from(dx).pow(2) + i64::from(dy).pow(2));
 
         let mut dx = dx.abs();
         let mut dy = dy.abs();
@@ -219,7 +352,7 @@ impl ThickLineParameters {
         };
 
         // Swap components if line is Y-major. dx is always the "major" direction delta.
-        let (step_major, step_minor, perp_step_major, perp_step_minor) = if dy > dx {
+        let (step_major, step_minor, perp_step_major, perp_step_minor, start, end) = if dy > dx {
             core::mem::swap(&mut dx, &mut dy);
 
             (
@@ -227,6 +360,8 @@ impl ThickLineParameters {
                 Point::new(direction.x, 0),
                 Point::new(0, perp_direction.y),
                 Point::new(perp_direction.x, 0),
+                (start.y * direction.y, start.x * direction.x),
+                (end.y * direction.y, end.x * direction.x),
             )
         } else {
             (
@@ -234,6 +369,8 @@ impl ThickLineParameters {
                 Point::new(0, direction.y),
                 Point::new(perp_direction.x, 0),
                 Point::new(0, perp_direction.y),
+                (start.x * direction.x, start.y * direction.y),
+                (end.x * direction.x, end.y * direction.y),
             )
         };
 
@@ -241,6 +378,13 @@ impl ThickLineParameters {
         let e_diag = -2 * dx;
         let e_square = 2 * dy;
 
+        let major_sign = step_major.x + step_major.y;
+        let minor_sign = step_minor.x + step_minor.y;
+
+        let (start_major, start_minor, initial_error) =
+            first_pixel(start, dx, dy, major_sign, minor_sign);
+        let parallel_length = (round_subpixel(end.0, major_sign) - start_major) as u32;
+
         Self {
             step_major,
             step_minor,
@@ -251,9 +395,167 @@ impl ThickLineParameters {
             e_square,
             thickness,
             swap_sides,
-            parallel_length: dx as u32,
+            parallel_length,
+            start: step_major * start_major + step_minor * start_minor,
+            initial_error,
+        }
+    }
+
+    /// Creates a parallel line, which is shifted by the subpixel position of the center line.
+    fn parallel_line(&self, start: Point, length: u32, error: i32) -> ParallelLineState {
+        let error = error + self.initial_error;
+
+        // Move the start point if the shift is large enough to reach the next pixel
+        if error > -self.e_diag / 2 {
+            ParallelLineState::new(
+                start + self.step_minor,
+                length,
+                error + self.e_diag,
+                self.parallel_length,
+            )
+        } else {
+            ParallelLineState::new(start, length, error, self.parallel_length)
+        }
+    }
+
+    /// Returns the last pixel of the center line.
+    fn last_pixel(&self) -> Point {
+        let steps = i64::from(self.parallel_length);
+        let e_square = i64::from(self.e_square);
+        let e_diag = i64::from(self.e_diag);
+
+        // Number of minor steps, which keeps the error in the range `-dx < error <= dx`
+        let minor_steps = if e_diag != 0 {
+            div_ceil(
+                i64::from(self.initial_error) + steps * e_square + e_diag / 2,
+                -e_diag,
+            )
+        } else {
+            0
+        };
+
+        self.start + self.step_major * steps as i32 + self.step_minor * minor_steps as i32
+    }
+}
+
+/// Rounds a mirrored coordinate in subpixel units to the nearest pixel.
+///
+/// `sign` is the sign that was used to mirror the coordinate. Coordinates exactly between two
+/// pixels are always rounded towards positive infinity in the unmirrored coordinate system.
+fn round_subpixel(value: i32, sign: i32) -> i32 {
+    (value * sign + SUBPIXELS / 2).div_euclid(SUBPIXELS) * sign
+}
+
+/// Divides two integers, rounding the result towards positive infinity.
+///
+/// `divisor` must be positive.
+fn div_ceil(dividend: i64, divisor: i64) -> i64 {
+    -(-dividend).div_euclid(divisor)
+}
+
+/// Returns the first pixel along the major and minor axis and the initial Bresenham error.
+///
+/// `start` contains the major and minor coordinates of the start point in subpixel units, which
+/// are mirrored to make `d_major` and `d_minor` positive. The first pixel is the pixel that is
+/// closest to the exact line along the minor axis at the rounded major coordinate of `start`.
+fn first_pixel(
+    start: (i32, i32),
+    d_major: i32,
+    d_minor: i32,
+    major_sign: i32,
+    minor_sign: i32,
+) -> (i32, i32, i32) {
+    let major = round_subpixel(start.0, major_sign);
+
+    if d_major == 0 {
+        return (major, round_subpixel(start.1, minor_sign), 0);
+    }
+
+    let d_major = i64::from(d_major);
+    let d_minor = i64::from(d_minor);
+    let subpixels = i64::from(SUBPIXELS);
+
+    // Exact minor coordinate at the major pixel coordinate, scaled by `2 * d_major` to match the
+    // units of the Bresenham error.
+    let offset = i64::from(major) * subpixels - i64::from(start.0);
+    let exact = 2 * (i64::from(start.1) * d_major + offset * d_minor);
+    let exact = (exact + subpixels / 2).div_euclid(subpixels);
+
+    // Choose the minor pixel that keeps the error in the range `-d_major < error <= d_major`.
+    let minor = div_ceil(exact - d_major, 2 * d_major);
+    let error = exact - 2 * d_major * minor;
+
+    (major, minor as i32, error as i32)
+}
+
+/// Returns the coordinate of a point along the axis of a major or minor step.
+///
+/// The coordinate is mirrored if the step points in the negative direction.
+fn axis(point: Point, step: Point) -> i32 {
+    point.x * step.x + point.y * step.y
+}
+
+/// Returns the inclusive range of coordinates of a rectangle along the axis of a step.
+fn axis_range(step: Point, top_left: Point, bottom_right: Point) -> (i64, i64) {
+    let a = i64::from(axis(top_left, step));
+    let b = i64::from(axis(bottom_right, step));
+
+    (a.min(b), a.max(b))
+}
+
+/// Returns the first value in `start..end` for which `predicate` returns `false`.
+///
+/// `predicate` must return `true` for all values before the returned value and `false` for all
+/// values after it. `end` is returned if `predicate` is `true` for all values.
+fn partition_point<F>(mut start: i64, mut end: i64, predicate: F) -> i64
+where
+    F: Fn(i64) -> bool,
+{
+    while start < end {
+        let middle = start + (end - start) / 2;
+
+        if predicate(middle) {
+            start = middle + 1;
+        } else {
+            end = middle;
         }
     }
+
+    start
+}
+
+/// Cohen-Sutherland outcode bits.
+const OUTCODE_LEFT: u8 = 1;
+const OUTCODE_RIGHT: u8 = 2;
+const OUTCODE_TOP: u8 = 4;
+const OUTCODE_BOTTOM: u8 = 8;
+
+/// Returns the Cohen-Sutherland outcode of a point.
+///
+/// Each bit in the outcode indicates on which side of the area the point is located.
+fn outcode(point: Point, top_left: Point, bottom_right: Point) -> u8 {
+    let mut code = 0;
+
+    if point.x < top_left.x {
+        code |= OUTCODE_LEFT;
+    } else if point.x > bottom_right.x {
+        code |= OUTCODE_RIGHT;
+    }
+
+    if point.y < top_left.y {
+        code |= OUTCODE_TOP;
+    } else if point.y > bottom_right.y {
+        code |= OUTCODE_BOTTOM;
+    }
+
+    code
+}
+
+/// Returns the first and last pixel of the center line of a line.
+pub(crate) fn end_points(line: &SubpixelLine) -> (Point, Point) {
+    let parameters = ThickLineParameters::new(line, 1);
+
+    (parameters.start, parameters.last_pixel())
 }
 
 /// Pixel iterator for each pixel in the line
@@ -265,7 +567,7 @@ pub(crate) struct ThickLineIterator {
     /// Thickness of pixels drawn so far
     ///
     /// Compared against `thickness` for width limit
-    thickness_accum: i32,
+    thickness_accum: i64,
 
     /// Which side the _next_ parallel line will be on
     ///
@@ -281,28 +583,69 @@ pub(crate) struct ThickLineIterator {
 
     /// Right side state
     right: SideState,
+
+    /// Clipping area as top left and bottom right corner
+    ///
+    /// Pixels outside this area are skipped without iterating over them.
+    clip: Option<(Point, Point)>,
 }
 
 impl ThickLineIterator {
-    /// Create a new line iterator from a `Line` and a stroke width
+    /// Create a new line iterator from a `SubpixelLine` and a stroke width
     ///
     /// Lines with a thickness greater than 1px are filled using multiple parallel lines to the
     /// left/right of the central original line.
-    pub(crate) fn new(line: &Line, stroke_width: i32) -> Self {
+    pub(crate) fn new(line: &SubpixelLine, stroke_width: i32) -> Self {
         let parameters = ThickLineParameters::new(line, stroke_width);
 
-        let thickness_accum = (parameters.e_square - parameters.e_diag) / 2;
+        let thickness_accum = i64::from(parameters.e_square - parameters.e_diag) / 2;
 
         Self {
             parameters,
             thickness_accum,
             // Next side to draw after center line
             next_side: Side::Left,
-            parallel: ParallelLineState::new(line.start, 0, 0),
-            left: SideState::new(line.start),
-            right: SideState::new(line.start),
+            parallel: parameters.parallel_line(parameters.start, 0, 0),
+            left: SideState::new(parameters.start),
+            right: SideState::new(parameters.start),
+            clip: None,
         }
     }
+
+    /// Create a new line iterator which only returns the pixels inside the clipping area.
+    ///
+    /// The returned pixels are identical to the pixels of an unclipped line that are inside
+    /// `clip_area`. Lines which are entirely outside of the clipping area are rejected by
+    /// comparing the Cohen-Sutherland outcodes of their end points. For all other lines each
+    /// parallel line is clipped individually, by skipping directly to the first visible pixel
+    /// and stopping after the last one.
+    pub(crate) fn clipped(line: &SubpixelLine, stroke_width: i32, clip_area: &Rectangle) -> Self {
+        let mut iter = Self::new(line, stroke_width);
+
+        let (top_left, bottom_right) = match clip_area.bottom_right() {
+            Some(bottom_right) => (clip_area.top_left, bottom_right),
+            None => {
+                iter.parameters.parallel_length = 0;
+                return iter;
+            }
+        };
+
+        // All pixels of a thick line are within `stroke_width` pixels of the center line.
+        let margin = Point::new(stroke_width, stroke_width);
+        let (start, end) = (iter.parameters.start, iter.parameters.last_pixel());
+        if outcode(start, top_left - margin, bottom_right + margin)
+            & outcode(end, top_left - margin, bottom_right + margin)
+            != 0
+        {
+            iter.parameters.parallel_length = 0;
+            return iter;
+        }
+
+        iter.clip = Some((top_left, bottom_right));
+        iter.parallel.clip(&iter.parameters, top_left, bottom_right);
+
+        iter
+    }
 }
 
 impl Iterator for ThickLineIterator {
@@ -330,9 +673,13 @@ impl Iterator for ThickLineIterator {
                 Side::Right => self.right.next(&self.parameters, Side::Right, swapped_side),
             };
 
-            self.thickness_accum += thickness_change;
+            self.thickness_accum += i64::from(thickness_change);
             self.parallel = parallel;
 
+            if let Some((top_left, bottom_right)) = self.clip {
+                self.parallel.clip(&self.parameters, top_left, bottom_right);
+            }
+
             // Switch to opposite side of line to keep it balanced
             self.next_side = self.next_side.swap();
 
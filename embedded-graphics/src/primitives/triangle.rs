@@ -1,10 +1,14 @@
 //! The triangle primitive.
 
 use crate::{
-    drawable::{Drawable, Pixel},
-    geometry::{Dimensions, Point},
-    pixelcolor::PixelColor,
-    primitives::{line::Line, ContainsPoint, Primitive, Rectangle, ThickLineIterator},
+    drawable::{Drawable, Pixel, Span, SpanPixels},
+    geometry::{Dimensions, Point, SubpixelPoint},
+    pixelcolor::{BinaryColor, PixelColor},
+    primitives::{
+        line::{self, SubpixelLine},
+        thick_line_iterator, ContainsPoint, DistanceToPoint, Primitive, Rectangle,
+        ThickLineIterator,
+    },
     style::{PrimitiveStyle, Styled},
     transform::Transform,
     DrawTarget,
@@ -61,6 +65,10 @@ use core::{
 /// # assert_eq!(tri_ref, Triangle::new(p1, p2, p3));
 /// ```
 #[derive(Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Debug, Default)]
+#[cfg_attr(
+    feature = "serde_support",
+    derive(serde::Serialize, serde::Deserialize)
+)]
 pub struct Triangle {
     /// First point of the triangle
     pub p1: Point,
@@ -76,20 +84,19 @@ impl Primitive for Triangle {
     type PointsIter = Points;
 
     fn points(&self) -> Self::PointsIter {
-        Points::new(self)
+        Points::new(&(*self).into())
     }
 }
 
 impl ContainsPoint for Triangle {
     fn contains(&self, point: Point) -> bool {
-        // Skip expensive calculations below if point is outside the bounding box
-        if !self.bounding_box().contains(point) {
-            return false;
-        }
+        SubpixelTriangle::from(*self).contains(point)
+    }
+}
 
-        // This is inefficient and should be replaced by a better algorithm to
-        // determine if point is inside the triangle
-        self.points().any(|p| p == point)
+impl DistanceToPoint for Triangle {
+    fn distance(&self, point: Point) -> u32 {
+        SubpixelTriangle::from(*self).distance(point)
     }
 }
 
@@ -124,11 +131,7 @@ impl Triangle {
     {
         let points = points.borrow();
 
-        Triangle {
-            p1: points[0].into(),
-            p2: points[1].into(),
-            p3: points[2].into(),
-        }
+        Triangle::new(points[0].into(), points[1].into(), points[2].into())
     }
 }
 
@@ -176,15 +179,24 @@ impl Transform for Triangle {
     }
 }
 
-fn sort_two_yx(p1: Point, p2: Point) -> (Point, Point) {
-    if p1.y < p2.y || (p1.y == p2.y && p1.x < p2.x) {
+fn sort_two_yx<P>(p1: P, p2: P) -> (P, P)
+where
+    P: Into<SubpixelPoint> + Copy,
+{
+    let (s1, s2) = (p1.into(), p2.into());
+
+    if s1.y < s2.y || (s1.y == s2.y && s1.x < s2.x) {
         (p1, p2)
     } else {
         (p2, p1)
     }
 }
 
-fn sort_yx(p1: Point, p2: Point, p3: Point) -> (Point, Point, Point) {
+fn sort_yx(
+    p1: SubpixelPoint,
+    p2: SubpixelPoint,
+    p3: SubpixelPoint,
+) -> (SubpixelPoint, SubpixelPoint, SubpixelPoint) {
     let (y1, y2) = sort_two_yx(p1, p2);
     let (y1, y3) = sort_two_yx(p3, y1);
     let (y2, y3) = sort_two_yx(y3, y2);
@@ -192,6 +204,40 @@ fn sort_yx(p1: Point, p2: Point, p3: Point) -> (Point, Point, Point) {
     (y1, y2, y3)
 }
 
+impl<C> Styled<Triangle, PrimitiveStyle<C>>
+where
+    C: PixelColor,
+{
+    /// Returns an iterator over the horizontal spans of the styled triangle.
+    ///
+    /// The spans are generated directly from the edges of the triangle. Each row consists of the
+    /// edge pixels, which are drawn in the stroke color, and the pixels between the edges, which
+    /// are drawn in the fill color. Consecutive pixels in a row which have the same color are
+    /// returned as a single span and the spans in a row don't overlap.
+    pub fn spans(&self) -> StyledTriangleSpans<C> {
+        StyledTriangleSpans::new(&self.primitive.into(), self.style)
+    }
+}
+
+impl<C> ContainsPoint for Styled<Triangle, PrimitiveStyle<C>>
+where
+    C: PixelColor,
+{
+    /// Returns `true` if the point is one of the drawn pixels of the triangle.
+    fn contains(&self, point: Point) -> bool {
+        Styled::new(SubpixelTriangle::from(self.primitive), self.style).contains(point)
+    }
+}
+
+impl<C> DistanceToPoint for Styled<Triangle, PrimitiveStyle<C>>
+where
+    C: PixelColor,
+{
+    fn distance(&self, point: Point) -> u32 {
+        Styled::new(SubpixelTriangle::from(self.primitive), self.style).distance(point)
+    }
+}
+
 impl<C> IntoIterator for &Styled<Triangle, PrimitiveStyle<C>>
 where
     C: PixelColor,
@@ -200,28 +246,237 @@ where
     type IntoIter = StyledTriangleIterator<C>;
 
     fn into_iter(self) -> Self::IntoIter {
-        let (v1, v2, v3) = sort_yx(self.primitive.p1, self.primitive.p2, self.primitive.p3);
+        StyledTriangleIterator::new(&self.primitive.into(), self.style)
+    }
+}
 
-        let mut line_a = ThickLineIterator::new(&Line::new(v1, v2), 1);
-        let mut line_b = ThickLineIterator::new(&Line::new(v1, v3), 1);
-        let mut line_c = ThickLineIterator::new(&Line::new(v2, v3), 1);
+impl<'a, C: 'a> Drawable<C> for &Styled<Triangle, PrimitiveStyle<C>>
+where
+    C: PixelColor,
+{
+    fn draw<D: DrawTarget<C>>(self, display: &mut D) -> Result<(), D::Error> {
+        display.draw_triangle(self)
+    }
+}
 
-        let next_ac = line_a.next().or_else(|| line_c.next());
-        let next_b = line_b.next();
+/// Triangle primitive with subpixel precision.
+///
+/// The vertices of a `SubpixelTriangle` are [`SubpixelPoint`]s, which can be positioned between
+/// pixels. The edges are rasterized like a [`SubpixelLine`] and the pixels between the edges are
+/// filled.
+///
+/// A [`Triangle`] can be converted into a `SubpixelTriangle` by using `From`/`Into`. Both
+/// triangles are drawn identically.
+///
+/// # Examples
+///
+/// ```rust
+/// use embedded_graphics::{
+///     geometry::SubpixelPoint, pixelcolor::BinaryColor, prelude::*,
+///     primitives::SubpixelTriangle, style::PrimitiveStyle,
+/// };
+/// # use embedded_graphics::mock_display::MockDisplay;
+/// # let mut display = MockDisplay::default();
+///
+/// SubpixelTriangle::new(
+///     SubpixelPoint::from_f32(1.5, 0.25),
+///     SubpixelPoint::from_f32(20.75, 10.0),
+///     SubpixelPoint::from_f32(3.25, 15.5),
+/// )
+/// .into_styled(PrimitiveStyle::with_fill(BinaryColor::On))
+/// .draw(&mut display)?;
+/// # Ok::<(), core::convert::Infallible>(())
+/// ```
+///
+/// [`SubpixelPoint`]: ../../geometry/struct.SubpixelPoint.html
+/// [`SubpixelLine`]: struct.SubpixelLine.html
+/// [`Triangle`]: struct.Triangle.html
+#[derive(Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Debug, Default)]
+#[cfg_attr(
+    feature = "serde_support",
+    derive(serde::Serialize, serde::Deserialize)
+)]
+pub struct SubpixelTriangle {
+    /// First point of the triangle
+    pub p1: SubpixelPoint,
 
-        StyledTriangleIterator {
-            line_a,
-            line_b,
-            line_c,
-            cur_ac: None,
-            cur_b: None,
-            next_ac,
-            next_b,
-            x: 0,
-            min_y: v1.y,
-            max_y: v3.y,
-            style: self.style,
+    /// Second point of the triangle
+    pub p2: SubpixelPoint,
+
+    /// Third point of the triangle
+    pub p3: SubpixelPoint,
+}
+
+impl SubpixelTriangle {
+    /// Creates a new subpixel triangle.
+    pub const fn new(p1: SubpixelPoint, p2: SubpixelPoint, p3: SubpixelPoint) -> Self {
+        Self { p1, p2, p3 }
+    }
+
+    /// Returns the edges of the triangle, sorted by the y coordinate of their start points.
+    ///
+    /// The first edge connects the top vertex and the middle vertex, the second edge the top and
+    /// bottom vertex and the third edge the middle and bottom vertex.
+    fn sorted_edges(&self) -> [SubpixelLine; 3] {
+        let (v1, v2, v3) = sort_yx(self.p1, self.p2, self.p3);
+
+        [
+            SubpixelLine::new(v1, v2),
+            SubpixelLine::new(v1, v3),
+            SubpixelLine::new(v2, v3),
+        ]
+    }
+}
+
+impl From<Triangle> for SubpixelTriangle {
+    fn from(triangle: Triangle) -> Self {
+        Self::new(triangle.p1.into(), triangle.p2.into(), triangle.p3.into())
+    }
+}
+
+impl Primitive for SubpixelTriangle {
+    type PointsIter = SubpixelPoints;
+
+    fn points(&self) -> Self::PointsIter {
+        SubpixelPoints::new(self)
+    }
+}
+
+impl ContainsPoint for SubpixelTriangle {
+    fn contains(&self, point: Point) -> bool {
+        // Skip expensive calculations below if point is outside the bounding box
+        if !self.bounding_box().contains(point) {
+            return false;
         }
+
+        // This is inefficient and should be replaced by a better algorithm to
+        // determine if point is inside the triangle
+        self.points().any(|p| p == point)
+    }
+}
+
+impl DistanceToPoint for SubpixelTriangle {
+    fn distance(&self, point: Point) -> u32 {
+        if self.contains(point) {
+            return 0;
+        }
+
+        edge_distance(self, point)
+    }
+}
+
+/// Returns the distance between a point and the closest edge of a triangle.
+fn edge_distance(triangle: &SubpixelTriangle, point: Point) -> u32 {
+    let SubpixelTriangle { p1, p2, p3 } = *triangle;
+
+    let distance = line::segment_distance(p1, p2, point)
+        .min(line::segment_distance(p2, p3, point))
+        .min(line::segment_distance(p3, p1, point));
+
+    line::subpixels_to_pixels(distance)
+}
+
+impl Dimensions for SubpixelTriangle {
+    /// Returns the bounding box of the drawn pixels.
+    ///
+    /// The first and last pixel of an edge with subpixel precision can be offset by one pixel
+    /// from the rounded vertex position.
+    fn bounding_box(&self) -> Rectangle {
+        let (a1, a2) = thick_line_iterator::end_points(&SubpixelLine::new(self.p1, self.p2));
+        let (b1, b2) = thick_line_iterator::end_points(&SubpixelLine::new(self.p2, self.p3));
+        let (c1, c2) = thick_line_iterator::end_points(&SubpixelLine::new(self.p3, self.p1));
+
+        let min = a1
+            .component_min(a2)
+            .component_min(b1.component_min(b2))
+            .component_min(c1.component_min(c2));
+        let max = a1
+            .component_max(a2)
+            .component_max(b1.component_max(b2))
+            .component_max(c1.component_max(c2));
+
+        Rectangle::with_corners(min, max)
+    }
+}
+
+impl Transform for SubpixelTriangle {
+    /// Translate the triangle from its current position to a new position by (x, y) pixels,
+    /// returning a new `SubpixelTriangle`. For a mutating transform, see `translate_mut`.
+    fn translate(&self, by: Point) -> Self {
+        Self::new(self.p1 + by, self.p2 + by, self.p3 + by)
+    }
+
+    /// Translate the triangle from its current position to a new position by (x, y) pixels.
+    fn translate_mut(&mut self, by: Point) -> &mut Self {
+        self.p1 += by;
+        self.p2 += by;
+        self.p3 += by;
+
+        self
+    }
+}
+
+impl<C> Styled<SubpixelTriangle, PrimitiveStyle<C>>
+where
+    C: PixelColor,
+{
+    /// Returns an iterator over the horizontal spans of the styled triangle.
+    ///
+    /// The spans are generated in the same way as the spans of a styled `Triangle`.
+    pub fn spans(&self) -> StyledTriangleSpans<C> {
+        StyledTriangleSpans::new(&self.primitive, self.style)
+    }
+}
+
+impl<C> ContainsPoint for Styled<SubpixelTriangle, PrimitiveStyle<C>>
+where
+    C: PixelColor,
+{
+    /// Returns `true` if the point is one of the drawn pixels of the triangle.
+    fn contains(&self, point: Point) -> bool {
+        // Skip iterating over the pixels if point is outside the bounding box
+        if !self.primitive.bounding_box().contains(point) {
+            return false;
+        }
+
+        self.into_iter().any(|Pixel(p, _)| p == point)
+    }
+}
+
+impl<C> DistanceToPoint for Styled<SubpixelTriangle, PrimitiveStyle<C>>
+where
+    C: PixelColor,
+{
+    fn distance(&self, point: Point) -> u32 {
+        if self.contains(point) {
+            return 0;
+        }
+
+        edge_distance(&self.primitive, point)
+    }
+}
+
+impl<C> IntoIterator for &Styled<SubpixelTriangle, PrimitiveStyle<C>>
+where
+    C: PixelColor,
+{
+    type Item = Pixel<C>;
+    type IntoIter = StyledSubpixelTriangleIterator<C>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        StyledSubpixelTriangleIterator {
+            spans: self.spans(),
+            pixels: None,
+        }
+    }
+}
+
+impl<'a, C: 'a> Drawable<C> for &Styled<SubpixelTriangle, PrimitiveStyle<C>>
+where
+    C: PixelColor,
+{
+    fn draw<D: DrawTarget<C>>(self, display: &mut D) -> Result<(), D::Error> {
+        display.draw_spans(self.spans())
     }
 }
 
@@ -247,12 +502,12 @@ pub struct Points {
 }
 
 impl Points {
-    fn new(triangle: &Triangle) -> Self {
-        let (v1, v2, v3) = sort_yx(triangle.p1, triangle.p2, triangle.p3);
+    fn new(triangle: &SubpixelTriangle) -> Self {
+        let [a, b, c] = triangle.sorted_edges();
 
-        let mut line_a = ThickLineIterator::new(&Line::new(v1, v2), 1);
-        let mut line_b = ThickLineIterator::new(&Line::new(v1, v3), 1);
-        let mut line_c = ThickLineIterator::new(&Line::new(v2, v3), 1);
+        let mut line_a = ThickLineIterator::new(&a, 1);
+        let mut line_b = ThickLineIterator::new(&b, 1);
+        let mut line_c = ThickLineIterator::new(&c, 1);
 
         let next_ac = line_a.next().or_else(|| line_c.next());
         let next_b = line_b.next();
@@ -266,8 +521,8 @@ impl Points {
             next_ac,
             next_b,
             x: 0,
-            min_y: v1.y,
-            max_y: v3.y,
+            min_y: b.start.round().y,
+            max_y: b.end.round().y,
         }
     }
 
@@ -372,6 +627,7 @@ where
     x: i32,
     max_y: i32,
     min_y: i32,
+    edge_runs: EdgeRuns,
     style: PrimitiveStyle<C>,
 }
 
@@ -379,6 +635,32 @@ impl<C> StyledTriangleIterator<C>
 where
     C: PixelColor,
 {
+    fn new(triangle: &SubpixelTriangle, style: PrimitiveStyle<C>) -> Self {
+        let [a, b, c] = triangle.sorted_edges();
+
+        let mut line_a = ThickLineIterator::new(&a, 1);
+        let mut line_b = ThickLineIterator::new(&b, 1);
+        let mut line_c = ThickLineIterator::new(&c, 1);
+
+        let next_ac = line_a.next().or_else(|| line_c.next());
+        let next_b = line_b.next();
+
+        Self {
+            line_a,
+            line_b,
+            line_c,
+            cur_ac: None,
+            cur_b: None,
+            next_ac,
+            next_b,
+            x: 0,
+            min_y: b.start.round().y,
+            max_y: b.end.round().y,
+            edge_runs: EdgeRuns::new(triangle),
+            style,
+        }
+    }
+
     fn update_ac(&mut self) -> IterState {
         if let Some(ac) = self.next_ac {
             self.cur_ac = Some(ac);
@@ -431,6 +713,14 @@ where
             }
         }
     }
+
+    /// Returns `true` if the point is an edge pixel which must not be drawn in the fill color.
+    ///
+    /// The edges are drawn in the fill color if the stroke width is zero, which makes it
+    /// unnecessary to exclude them from the fill.
+    fn is_stroke(&mut self, point: Point) -> bool {
+        self.style.stroke_width > 0 && self.edge_runs.contains(point)
+    }
 }
 
 impl<C> Iterator for StyledTriangleIterator<C>
@@ -466,7 +756,9 @@ where
                         if l.x + self.x < r.x {
                             let point = Point::new(l.x + self.x, l.y);
                             self.x += 1;
-                            return Some(Pixel(point, color));
+                            if !self.is_stroke(point) {
+                                return Some(Pixel(point, color));
+                            }
                         } else if l.x + self.x >= r.x {
                             // We reached the right edge, move on to next row
                             self.cur_ac = None;
@@ -484,12 +776,288 @@ where
     }
 }
 
-impl<'a, C: 'a> Drawable<C> for &Styled<Triangle, PrimitiveStyle<C>>
+/// Iterator over all points inside a subpixel triangle.
+#[derive(Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Debug)]
+pub struct SubpixelPoints {
+    pixels: StyledSubpixelTriangleIterator<BinaryColor>,
+}
+
+impl SubpixelPoints {
+    fn new(triangle: &SubpixelTriangle) -> Self {
+        let style = PrimitiveStyle::with_fill(BinaryColor::On);
+
+        Self {
+            pixels: StyledSubpixelTriangleIterator {
+                spans: StyledTriangleSpans::new(triangle, style),
+                pixels: None,
+            },
+        }
+    }
+}
+
+impl Iterator for SubpixelPoints {
+    type Item = Point;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.pixels.next().map(|Pixel(point, _)| point)
+    }
+}
+
+/// Pixel iterator for a styled subpixel triangle.
+///
+/// The pixels are returned row by row, in the same order as the spans of the styled triangle.
+#[derive(Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Debug)]
+pub struct StyledSubpixelTriangleIterator<C>
 where
     C: PixelColor,
 {
-    fn draw<D: DrawTarget<C>>(self, display: &mut D) -> Result<(), D::Error> {
-        display.draw_triangle(self)
+    spans: StyledTriangleSpans<C>,
+    pixels: Option<SpanPixels<C>>,
+}
+
+impl<C> Iterator for StyledSubpixelTriangleIterator<C>
+where
+    C: PixelColor,
+{
+    type Item = Pixel<C>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        loop {
+            if let Some(pixel) = self.pixels.as_mut().and_then(Iterator::next) {
+                return Some(pixel);
+            }
+
+            self.pixels = Some(self.spans.next()?.into_iter());
+        }
+    }
+}
+
+/// Horizontal run of pixels in a row of a triangle.
+#[derive(Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Debug, Default)]
+struct Run {
+    start: i32,
+    end: i32,
+}
+
+/// Edge pixels of a triangle, collected row by row.
+#[derive(Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Debug)]
+struct EdgeRuns {
+    /// Iterators over the pixels of the three edges, sorted from top to bottom.
+    edges: [ThickLineIterator; 3],
+
+    /// Next pixel of each edge.
+    next: [Option<Point>; 3],
+
+    /// Current row.
+    y: i32,
+
+    /// Edge pixels in the current row, sorted by x coordinate and without overlaps.
+    runs: [Run; 3],
+    run_count: usize,
+}
+
+impl EdgeRuns {
+    fn new(triangle: &SubpixelTriangle) -> Self {
+        let [a, b, c] = triangle.sorted_edges();
+
+        let mut edges = [
+            ThickLineIterator::new(&a, 1),
+            ThickLineIterator::new(&b, 1),
+            ThickLineIterator::new(&c, 1),
+        ];
+        let next = [edges[0].next(), edges[1].next(), edges[2].next()];
+
+        Self {
+            edges,
+            next,
+            y: i32::min_value(),
+            runs: [Run::default(); 3],
+            run_count: 0,
+        }
+    }
+
+    /// Collects the edge pixels of the next row.
+    ///
+    /// Returns `false` if all rows have been processed.
+    fn next_row(&mut self) -> bool {
+        let y = match self
+            .next
+            .iter()
+            .filter_map(|point| point.map(|p| p.y))
+            .min()
+        {
+            Some(y) => y,
+            None => return false,
+        };
+
+        let mut runs = [Run::default(); 3];
+        let mut run_count = 0;
+
+        for (edge, next) in self.edges.iter_mut().zip(self.next.iter_mut()) {
+            let mut run: Option<Run> = None;
+
+            while let Some(point) = next.filter(|point| point.y == y) {
+                run = Some(run.map_or(
+                    Run {
+                        start: point.x,
+                        end: point.x,
+                    },
+                    |run| Run {
+                        start: run.start.min(point.x),
+                        end: run.end.max(point.x),
+                    },
+                ));
+
+                *next = edge.next();
+            }
+
+            if let Some(run) = run {
+                runs[run_count] = run;
+                run_count += 1;
+            }
+        }
+
+        let runs = &mut runs[0..run_count];
+        runs.sort_unstable();
+
+        // Merge overlapping and adjacent runs.
+        self.run_count = 0;
+        for &run in runs.iter() {
+            match self.runs[..self.run_count].last_mut() {
+                Some(last) if run.start <= last.end + 1 => last.end = last.end.max(run.end),
+                _ => {
+                    self.runs[self.run_count] = run;
+                    self.run_count += 1;
+                }
+            }
+        }
+
+        self.y = y;
+
+        true
+    }
+
+    /// Returns `true` if the point is an edge pixel.
+    ///
+    /// The points must be checked in increasing row order, because rows above the current row
+    /// are discarded.
+    fn contains(&mut self, point: Point) -> bool {
+        while self.y < point.y {
+            if !self.next_row() {
+                return false;
+            }
+        }
+
+        point.y == self.y
+            && self.runs[..self.run_count]
+                .iter()
+                .any(|run| run.start <= point.x && point.x <= run.end)
+    }
+}
+
+/// Span iterator for a styled triangle.
+///
+/// This iterator is created by the [`spans`] method.
+///
+/// [`spans`]: ../../style/struct.Styled.html#method.spans-2
+#[derive(Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Debug)]
+pub struct StyledTriangleSpans<C>
+where
+    C: PixelColor,
+{
+    /// Edge pixels of the current row.
+    rows: EdgeRuns,
+
+    /// Index of the next span in the current row.
+    ///
+    /// Even indices are edge runs and odd indices are the gaps between two runs.
+    index: usize,
+
+    edge_color: Option<C>,
+    fill_color: Option<C>,
+}
+
+impl<C> StyledTriangleSpans<C>
+where
+    C: PixelColor,
+{
+    fn new(triangle: &SubpixelTriangle, style: PrimitiveStyle<C>) -> Self {
+        let edge_color = if style.stroke_width > 0 {
+            style.stroke_color
+        } else {
+            style.fill_color
+        };
+
+        Self {
+            rows: EdgeRuns::new(triangle),
+            index: 0,
+            edge_color,
+            fill_color: style.fill_color,
+        }
+    }
+
+    /// Collects the edge pixels of the next row.
+    ///
+    /// Returns `false` if all rows have been processed.
+    fn next_row(&mut self) -> bool {
+        self.index = 0;
+
+        self.rows.next_row()
+    }
+
+    /// Returns the start, end and color of a segment in the current row.
+    fn segment(&self, index: usize) -> (i32, i32, Option<C>) {
+        if index % 2 == 0 {
+            let run = self.rows.runs[index / 2];
+
+            (run.start, run.end, self.edge_color)
+        } else {
+            let left = self.rows.runs[index / 2];
+            let right = self.rows.runs[index / 2 + 1];
+
+            (left.end + 1, right.start - 1, self.fill_color)
+        }
+    }
+}
+
+impl<C> Iterator for StyledTriangleSpans<C>
+where
+    C: PixelColor,
+{
+    type Item = Span<C>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        loop {
+            // A row contains `run_count` edge runs and `run_count - 1` gaps between them.
+            if self.index + 1 >= 2 * self.rows.run_count && !self.next_row() {
+                return None;
+            }
+
+            let (start, mut end, color) = self.segment(self.index);
+            self.index += 1;
+
+            let color = match color {
+                Some(color) => color,
+                None => continue,
+            };
+
+            // Combine following segments which have the same color.
+            while self.index + 1 < 2 * self.rows.run_count {
+                let (_, next_end, next_color) = self.segment(self.index);
+                if next_color != Some(color) {
+                    break;
+                }
+
+                end = next_end;
+                self.index += 1;
+            }
+
+            return Some(Span::new(
+                Point::new(start, self.rows.y),
+                (end - start + 1) as u32,
+                color,
+            ));
+        }
     }
 }
 
@@ -500,6 +1068,7 @@ mod tests {
         geometry::Size,
         mock_display::MockDisplay,
         pixelcolor::{BinaryColor, Rgb888, RgbColor},
+        primitives::TestRng,
         style::PrimitiveStyleBuilder,
     };
 
@@ -703,4 +1272,236 @@ mod tests {
             ])
         );
     }
+
+    #[test]
+    fn subpixel_integer_coordinates() -> Result<(), core::convert::Infallible> {
+        let p1 = Point::new(5, 10);
+        let p2 = Point::new(15, 25);
+        let p3 = Point::new(5, 25);
+
+        let triangle = Triangle::new(p1, p2, p3);
+        let subpixel_triangle = SubpixelTriangle::from(triangle);
+
+        assert_eq!(
+            subpixel_triangle,
+            SubpixelTriangle::new(p1.into(), p2.into(), p3.into())
+        );
+        assert_eq!(subpixel_triangle.bounding_box(), triangle.bounding_box());
+
+        let style = PrimitiveStyleBuilder::new()
+            .stroke_color(BinaryColor::On)
+            .stroke_width(1)
+            .fill_color(BinaryColor::Off)
+            .build();
+
+        let mut expected = MockDisplay::new();
+        triangle.into_styled(style).draw(&mut expected)?;
+
+        let mut display = MockDisplay::new();
+        subpixel_triangle.into_styled(style).draw(&mut display)?;
+
+        assert_eq!(display, expected);
+
+        Ok(())
+    }
+
+    #[test]
+    fn subpixel_triangle() -> Result<(), core::convert::Infallible> {
+        let triangle = SubpixelTriangle::new(
+            SubpixelPoint::from_f32(1.2, 1.1),
+            SubpixelPoint::from_f32(7.3, 1.6),
+            SubpixelPoint::from_f32(1.4, 7.2),
+        );
+
+        let mut display = MockDisplay::new();
+        triangle
+            .into_styled(PrimitiveStyle::with_fill(BinaryColor::On))
+            .draw(&mut display)?;
+
+        #[rustfmt::skip]
+        assert_eq!(
+            display,
+            MockDisplay::from_pattern(&[
+                "        ",
+                " ###### ",
+                " #######",
+                " ###### ",
+                " #####  ",
+                " ####   ",
+                " ###    ",
+                " ##     ",
+                " #      ",
+            ])
+        );
+
+        let bounding_box = triangle.bounding_box();
+        assert!(triangle.points().all(|p| bounding_box.contains(p)));
+
+        Ok(())
+    }
+
+    #[test]
+    fn subpixel_triangle_without_gaps() {
+        // The third edge of this triangle starts one row above the end of the first edge.
+        let triangle = SubpixelTriangle::new(
+            SubpixelPoint::new(4019, 13910),
+            SubpixelPoint::new(4518, 9371),
+            SubpixelPoint::new(874, 10958),
+        );
+
+        let mut rows = 0;
+        let mut previous: Option<Point> = None;
+
+        for point in triangle.points() {
+            match previous {
+                Some(previous) if previous.y == point.y => assert_eq!(point.x, previous.x + 1),
+                _ => rows += 1,
+            }
+
+            previous = Some(point);
+        }
+
+        assert_eq!(rows, triangle.bounding_box().size.height);
+    }
+
+    #[test]
+    fn styled_contains_and_distance() {
+        let triangle = Triangle::new(Point::new(2, 2), Point::new(12, 4), Point::new(5, 11));
+
+        let stroke = triangle.into_styled(PrimitiveStyle::with_stroke(BinaryColor::On, 1));
+        let fill = triangle.into_styled(PrimitiveStyle::with_fill(BinaryColor::On));
+
+        for y in 0..15 {
+            for x in 0..15 {
+                let point = Point::new(x, y);
+                assert_eq!(
+                    stroke.contains(point),
+                    stroke.into_iter().any(|Pixel(p, _)| p == point)
+                );
+                assert_eq!(fill.contains(point), triangle.contains(point));
+            }
+        }
+
+        assert_eq!(triangle.distance(Point::new(6, 6)), 0);
+        assert_eq!(triangle.distance(Point::new(2, 12)), 3);
+
+        assert_eq!(fill.distance(Point::new(6, 6)), 0);
+        assert_eq!(stroke.distance(Point::new(6, 6)), 2);
+        assert_eq!(stroke.distance(Point::new(2, 12)), 3);
+    }
+
+    #[test]
+    fn spans_dont_overlap() {
+        let triangle = Triangle::new(Point::new(0, 0), Point::new(99, 30), Point::new(20, 80));
+
+        for style in [
+            PrimitiveStyle::with_fill(BinaryColor::On),
+            PrimitiveStyleBuilder::new()
+                .stroke_color(BinaryColor::On)
+                .stroke_width(1)
+                .fill_color(BinaryColor::Off)
+                .build(),
+        ]
+        .iter()
+        {
+            let styled = triangle.into_styled(*style);
+            let mut rows = 0;
+            let mut previous: Option<Span<BinaryColor>> = None;
+
+            for span in styled.spans() {
+                match previous {
+                    Some(previous) if previous.start.y == span.start.y => {
+                        assert!(previous.start.x + previous.width as i32 <= span.start.x);
+                        assert_ne!(previous.color, span.color);
+                    }
+                    Some(previous) => assert_eq!(span.start.y, previous.start.y + 1),
+                    None => assert_eq!(span.start.y, 0),
+                }
+
+                if previous.map_or(true, |previous| previous.start.y != span.start.y) {
+                    rows += 1;
+                }
+                previous = Some(span);
+            }
+
+            assert_eq!(rows, 81);
+        }
+
+        // Filled triangles without a stroke consist of one span per row.
+        assert_eq!(
+            triangle
+                .into_styled(PrimitiveStyle::with_fill(BinaryColor::On))
+                .spans()
+                .count(),
+            81
+        );
+    }
+
+    #[test]
+    fn spans_cover_pixels() -> Result<(), core::convert::Infallible> {
+        let mut rng = TestRng::new(54321);
+
+        let styles = [
+            PrimitiveStyle::with_fill(Rgb888::RED),
+            PrimitiveStyle::with_stroke(Rgb888::GREEN, 1),
+            PrimitiveStyleBuilder::new()
+                .stroke_color(Rgb888::GREEN)
+                .stroke_width(1)
+                .fill_color(Rgb888::RED)
+                .build(),
+            PrimitiveStyleBuilder::new()
+                .stroke_width(1)
+                .fill_color(Rgb888::RED)
+                .build(),
+        ];
+
+        for _ in 0..200 {
+            let triangle = Triangle::new(
+                Point::new(rng.next(64), rng.next(64)),
+                Point::new(rng.next(64), rng.next(64)),
+                Point::new(rng.next(64), rng.next(64)),
+            );
+
+            let subpixel_triangle = SubpixelTriangle::new(
+                SubpixelPoint::new(rng.next(64 * 256), rng.next(64 * 256)),
+                SubpixelPoint::new(rng.next(64 * 256), rng.next(64 * 256)),
+                SubpixelPoint::new(rng.next(64 * 256), rng.next(64 * 256)),
+            );
+
+            for style in styles.iter() {
+                let styled = triangle.into_styled(*style);
+
+                let mut pixels = MockDisplay::new();
+                styled.into_iter().draw(&mut pixels)?;
+
+                let mut spans = MockDisplay::new();
+                spans.draw_spans(styled.spans())?;
+
+                let mut drawn = MockDisplay::new();
+                styled.draw(&mut drawn)?;
+
+                assert_eq!(pixels, spans, "{:?} {:?}", triangle, style);
+                assert_eq!(pixels, drawn, "{:?} {:?}", triangle, style);
+
+                let styled = subpixel_triangle.into_styled(*style);
+
+                let mut pixels = MockDisplay::new();
+                styled.into_iter().draw(&mut pixels)?;
+
+                let mut drawn = MockDisplay::new();
+                styled.draw(&mut drawn)?;
+
+                assert_eq!(pixels, drawn, "{:?} {:?}", subpixel_triangle, style);
+                assert!(
+                    subpixel_triangle
+                        .points()
+                        .all(|p| subpixel_triangle.bounding_box().contains(p)),
+                    "{:?}",
+                    subpixel_triangle
+                );
+            }
+        }
+
+        Ok(())
+    }
 }
@@ -0,0 +1,195 @@
+use crate::{
+    drawable::{Drawable, Pixel},
+    geometry::Point,
+    pixelcolor::PixelColor,
+    primitives::Primitive,
+    style::Styled,
+    DrawTarget,
+};
+
+/// Fill with a color that depends on the position.
+///
+/// Types that implement `Fill` can be used to fill any [primitive] with more than a single
+/// color, for example a [`LinearGradient`]. Use [`Primitive::into_filled`] to attach a fill to a
+/// primitive.
+///
+/// A fill only affects the pixels inside the primitive. To draw a stroke around a filled
+/// primitive, the primitive can be drawn a second time using a stroke [`PrimitiveStyle`].
+///
+/// # Examples
+///
+/// ## Fill a rectangle with a custom fill
+///
+/// ```rust
+/// use embedded_graphics::{
+///     pixelcolor::{Rgb565, RgbColor},
+///     prelude::*,
+///     primitives::Rectangle,
+///     style::Fill,
+/// };
+/// # use embedded_graphics::mock_display::MockDisplay;
+/// # let mut display: MockDisplay<Rgb565> = MockDisplay::default();
+///
+/// /// Vertical stripes with a width of 2 pixels.
+/// #[derive(Debug, Copy, Clone)]
+/// struct Stripes;
+///
+/// impl Fill for Stripes {
+///     type Color = Rgb565;
+///
+///     fn color_at(&self, point: Point) -> Option<Rgb565> {
+///         if point.x % 4 < 2 {
+///             Some(Rgb565::RED)
+///         } else {
+///             Some(Rgb565::WHITE)
+///         }
+///     }
+/// }
+///
+/// Rectangle::new(Point::new(10, 10), Size::new(20, 10))
+///     .into_filled(Stripes)
+///     .draw(&mut display)?;
+/// # Ok::<(), core::convert::Infallible>(())
+/// ```
+///
+/// [primitive]: ../primitives/index.html
+/// [`LinearGradient`]: struct.LinearGradient.html
+/// [`Primitive::into_filled`]: ../primitives/trait.Primitive.html#method.into_filled
+/// [`PrimitiveStyle`]: struct.PrimitiveStyle.html
+pub trait Fill {
+    /// The color type.
+    type Color: PixelColor;
+
+    /// Returns the fill color at the given point.
+    ///
+    /// Pixels for which `None` is returned are transparent and will not be drawn.
+    fn color_at(&self, point: Point) -> Option<Self::Color>;
+}
+
+impl<F> Fill for &F
+where
+    F: Fill,
+{
+    type Color = F::Color;
+
+    fn color_at(&self, point: Point) -> Option<Self::Color> {
+        (*self).color_at(point)
+    }
+}
+
+/// Pixel iterator for a primitive with a [`Fill`].
+///
+/// [`Fill`]: trait.Fill.html
+#[derive(Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Debug)]
+pub struct StyledFillIterator<'a, I, F> {
+    points: I,
+    fill: &'a F,
+}
+
+impl<I, F> Iterator for StyledFillIterator<'_, I, F>
+where
+    I: Iterator<Item = Point>,
+    F: Fill,
+{
+    type Item = Pixel<F::Color>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let fill = self.fill;
+
+        self.points
+            .find_map(|point| fill.color_at(point).map(|color| Pixel(point, color)))
+    }
+}
+
+impl<'a, T, F> IntoIterator for &'a Styled<T, F>
+where
+    T: Primitive,
+    F: Fill,
+{
+    type Item = Pixel<F::Color>;
+    type IntoIter = StyledFillIterator<'a, T::PointsIter, F>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        StyledFillIterator {
+            points: self.primitive.points(),
+            fill: &self.style,
+        }
+    }
+}
+
+impl<T, F> Drawable<F::Color> for &Styled<T, F>
+where
+    T: Primitive,
+    F: Fill,
+{
+    fn draw<D: DrawTarget<F::Color>>(self, display: &mut D) -> Result<(), D::Error> {
+        display.draw_iter(self)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        geometry::Size,
+        mock_display::MockDisplay,
+        pixelcolor::BinaryColor,
+        primitives::{Circle, Rectangle, Triangle},
+    };
+
+    /// Checkerboard fill with transparent black fields.
+    #[derive(Copy, Clone, Debug)]
+    struct Checkerboard;
+
+    impl Fill for Checkerboard {
+        type Color = BinaryColor;
+
+        fn color_at(&self, point: Point) -> Option<BinaryColor> {
+            if (point.x + point.y) % 2 == 0 {
+                Some(BinaryColor::On)
+            } else {
+                None
+            }
+        }
+    }
+
+    #[test]
+    fn fill_rectangle() -> Result<(), core::convert::Infallible> {
+        let mut display = MockDisplay::new();
+
+        Rectangle::new(Point::new(1, 1), Size::new(4, 3))
+            .into_filled(Checkerboard)
+            .draw(&mut display)?;
+
+        #[rustfmt::skip]
+        assert_eq!(
+            display,
+            MockDisplay::from_pattern(&[
+                "      ",
+                " # #  ",
+                "  # # ",
+                " # #  ",
+            ])
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn fill_matches_points() {
+        let circle = Circle::new(Point::new(2, 3), 9);
+        let triangle = Triangle::new(Point::new(2, 3), Point::new(12, 5), Point::new(7, 15));
+
+        assert!(circle
+            .into_filled(Checkerboard)
+            .into_iter()
+            .map(|Pixel(p, _)| p)
+            .eq(circle.points().filter(|p| (p.x + p.y) % 2 == 0)));
+
+        assert!(triangle
+            .into_filled(&Checkerboard)
+            .into_iter()
+            .map(|Pixel(p, _)| p)
+            .eq(triangle.points().filter(|p| (p.x + p.y) % 2 == 0)));
+    }
+}
@@ -0,0 +1,549 @@
+use crate::{
+    geometry::{isqrt, Point, SubpixelPoint},
+    pixelcolor::Blend,
+    primitives::{line, SubpixelTriangle, Triangle},
+    style::Fill,
+};
+
+/// Color stop in a gradient.
+///
+/// The position of a color stop is given as a fraction of the gradient length, with `0` being the
+/// start and `255` being the end of the gradient.
+#[derive(Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Debug, Default)]
+pub struct ColorStop<C> {
+    /// Position of the color stop.
+    pub position: u8,
+
+    /// Color at the color stop.
+    pub color: C,
+}
+
+impl<C> ColorStop<C> {
+    /// Creates a new color stop.
+    pub const fn new(position: u8, color: C) -> Self {
+        Self { position, color }
+    }
+}
+
+/// Returns the color at a position in a list of color stops.
+///
+/// Colors between two stops are interpolated. Positions before the first or after the last stop
+/// use the color of the nearest stop.
+fn color_at_position<C>(stops: &[ColorStop<C>], position: u8) -> Option<C>
+where
+    C: Blend,
+{
+    let first = stops.first()?;
+    if position <= first.position {
+        return Some(first.color);
+    }
+
+    for window in stops.windows(2) {
+        let (from, to) = (&window[0], &window[1]);
+
+        if position <= to.position {
+            let length = u32::from(to.position - from.position);
+            let offset = u32::from(position - from.position);
+
+            let ratio = (offset * 255).checked_div(length).map_or(255, |r| r as u8);
+
+            return Some(from.color.blend(to.color, ratio));
+        }
+    }
+
+    stops.last().map(|stop| stop.color)
+}
+
+/// Linear gradient fill.
+///
+/// A linear gradient changes its color along the line from `start` to `end`. The colors are
+/// defined by a list of [`ColorStop`]s, which must be sorted by their position. Points before the
+/// start or after the end of the gradient use the color of the first or last stop.
+///
+/// Gradients can be used to fill any primitive by using [`Primitive::into_filled`].
+///
+/// # Examples
+///
+/// ## Fill a progress bar with a horizontal gradient
+///
+/// ```rust
+/// use embedded_graphics::{
+///     pixelcolor::Rgb565,
+///     prelude::*,
+///     primitives::Rectangle,
+///     style::{ColorStop, LinearGradient, PrimitiveStyle},
+/// };
+/// # use embedded_graphics::mock_display::MockDisplay;
+/// # let mut display: MockDisplay<Rgb565> = MockDisplay::default();
+///
+/// let stops = [
+///     ColorStop::new(0, Rgb565::GREEN),
+///     ColorStop::new(192, Rgb565::YELLOW),
+///     ColorStop::new(255, Rgb565::RED),
+/// ];
+///
+/// let bar = Rectangle::new(Point::new(5, 5), Size::new(50, 8));
+///
+/// bar.into_filled(LinearGradient::new(Point::new(5, 0), Point::new(54, 0), &stops))
+///     .draw(&mut display)?;
+///
+/// // Add a border to the progress bar
+/// bar.into_styled(PrimitiveStyle::with_stroke(Rgb565::WHITE, 1))
+///     .draw(&mut display)?;
+/// # Ok::<(), core::convert::Infallible>(())
+/// ```
+///
+/// [`ColorStop`]: struct.ColorStop.html
+/// [`Primitive::into_filled`]: ../primitives/trait.Primitive.html#method.into_filled
+#[derive(Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Debug)]
+pub struct LinearGradient<'a, C> {
+    /// Start point.
+    pub start: Point,
+
+    /// End point.
+    pub end: Point,
+
+    /// Color stops.
+    pub stops: &'a [ColorStop<C>],
+}
+
+impl<'a, C> LinearGradient<'a, C> {
+    /// Creates a new linear gradient.
+    pub const fn new(start: Point, end: Point, stops: &'a [ColorStop<C>]) -> Self {
+        Self { start, end, stops }
+    }
+
+    /// Returns the position of a point along the gradient.
+    fn position(&self, point: Point) -> u8 {
+        let direction = self.end - self.start;
+        let delta = point - self.start;
+
+        let length_squared = i64::from(direction.x).pow(2) + i64::from(direction.y).pow(2);
+        if length_squared == 0 {
+            return 0;
+        }
+
+        let projection = i64::from(delta.x) * i64::from(direction.x)
+            + i64::from(delta.y) * i64::from(direction.y);
+
+        (projection * 255 / length_squared).max(0).min(255) as u8
+    }
+}
+
+impl<C> Fill for LinearGradient<'_, C>
+where
+    C: Blend,
+{
+    type Color = C;
+
+    fn color_at(&self, point: Point) -> Option<C> {
+        color_at_position(self.stops, self.position(point))
+    }
+}
+
+/// Radial gradient fill.
+///
+/// A radial gradient changes its color with the distance from `center`. The first color stop
+/// defines the color at the center and the stop at position `255` the color at `radius` pixels
+/// from the center. Points outside the radius use the color of the last stop.
+///
+/// See [`LinearGradient`] for more details about color stops.
+///
+/// # Examples
+///
+/// ## Fill a circle with a radial gradient
+///
+/// ```rust
+/// use embedded_graphics::{
+///     pixelcolor::Gray8,
+///     prelude::*,
+///     primitives::Circle,
+///     style::{ColorStop, RadialGradient},
+/// };
+/// # use embedded_graphics::mock_display::MockDisplay;
+/// # let mut display: MockDisplay<Gray8> = MockDisplay::default();
+///
+/// let stops = [ColorStop::new(0, Gray8::WHITE), ColorStop::new(255, Gray8::new(0x40))];
+///
+/// let center = Point::new(20, 20);
+///
+/// Circle::with_center(center, 31)
+///     .into_filled(RadialGradient::new(center, 15, &stops))
+///     .draw(&mut display)?;
+/// # Ok::<(), core::convert::Infallible>(())
+/// ```
+///
+/// [`LinearGradient`]: struct.LinearGradient.html
+#[derive(Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Debug)]
+pub struct RadialGradient<'a, C> {
+    /// Center point.
+    pub center: Point,
+
+    /// Radius.
+    pub radius: u32,
+
+    /// Color stops.
+    pub stops: &'a [ColorStop<C>],
+}
+
+impl<'a, C> RadialGradient<'a, C> {
+    /// Creates a new radial gradient.
+    pub const fn new(center: Point, radius: u32, stops: &'a [ColorStop<C>]) -> Self {
+        Self {
+            center,
+            radius,
+            stops,
+        }
+    }
+
+    /// Returns the position of a point along the gradient.
+    fn position(&self, point: Point) -> u8 {
+        if self.radius == 0 {
+            return 255;
+        }
+
+        let delta = point - self.center;
+        let distance_squared = (i64::from(delta.x).pow(2) + i64::from(delta.y).pow(2)) as u64;
+        let distance = u64::from(isqrt(distance_squared));
+
+        (distance * 255 / u64::from(self.radius)).min(255) as u8
+    }
+}
+
+impl<C> Fill for RadialGradient<'_, C>
+where
+    C: Blend,
+{
+    type Color = C;
+
+    fn color_at(&self, point: Point) -> Option<C> {
+        color_at_position(self.stops, self.position(point))
+    }
+}
+
+/// Triangle gradient fill.
+///
+/// A triangle gradient assigns a color to each vertex of a triangle and interpolates the colors
+/// across the interior using barycentric coordinates, which is also known as Gouraud shading.
+/// Points outside the triangle use the color of the nearest point on the edges of the triangle.
+///
+/// The gradient only depends on the positions of the vertices, which means that it can be used to
+/// fill the triangle it was created from, but also other primitives which are inside the
+/// triangle. Use [`with_subpixel`] to create a gradient for a [`SubpixelTriangle`]. Degenerate
+/// triangles without an area are filled with the color of the first vertex.
+///
+/// # Examples
+///
+/// ## Fill a triangle with red, green and blue vertices
+///
+/// ```rust
+/// use embedded_graphics::{
+///     pixelcolor::Rgb565,
+///     prelude::*,
+///     primitives::Triangle,
+///     style::TriangleGradient,
+/// };
+/// # use embedded_graphics::mock_display::MockDisplay;
+/// # let mut display: MockDisplay<Rgb565> = MockDisplay::default();
+///
+/// let triangle = Triangle::new(Point::new(32, 2), Point::new(60, 50), Point::new(4, 50));
+///
+/// triangle
+///     .into_filled(TriangleGradient::new(
+///         &triangle,
+///         [Rgb565::RED, Rgb565::GREEN, Rgb565::BLUE],
+///     ))
+///     .draw(&mut display)?;
+/// # Ok::<(), core::convert::Infallible>(())
+/// ```
+///
+/// [`with_subpixel`]: #method.with_subpixel
+/// [`SubpixelTriangle`]: ../primitives/triangle/struct.SubpixelTriangle.html
+#[derive(Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Debug)]
+pub struct TriangleGradient<C> {
+    /// Vertices of the triangle.
+    pub vertices: [SubpixelPoint; 3],
+
+    /// Colors at the vertices.
+    pub colors: [C; 3],
+}
+
+impl<C> TriangleGradient<C> {
+    /// Creates a new triangle gradient.
+    ///
+    /// The colors are assigned to the vertices `p1`, `p2` and `p3` of the triangle in this order.
+    pub fn new(triangle: &Triangle, colors: [C; 3]) -> Self {
+        Self::with_subpixel(&SubpixelTriangle::from(*triangle), colors)
+    }
+
+    /// Creates a new triangle gradient for a triangle with subpixel precision.
+    ///
+    /// The colors are assigned to the vertices `p1`, `p2` and `p3` of the triangle in this order.
+    pub fn with_subpixel(triangle: &SubpixelTriangle, colors: [C; 3]) -> Self {
+        Self {
+            vertices: [triangle.p1, triangle.p2, triangle.p3],
+            colors,
+        }
+    }
+
+    /// Returns the barycentric weights of a point.
+    ///
+    /// The weights are proportional to the barycentric coordinates of the point. Points outside
+    /// the triangle are projected onto the nearest edge, which results in weights that are only
+    /// non-zero for the two vertices of this edge.
+    fn weights(&self, point: Point) -> [u128; 3] {
+        let [v1, v2, v3] = self.vertices;
+        let p = SubpixelPoint::from_point(point);
+
+        let delta = |a: SubpixelPoint, b: SubpixelPoint| {
+            (
+                i128::from(b.x) - i128::from(a.x),
+                i128::from(b.y) - i128::from(a.y),
+            )
+        };
+
+        let edge = |a, b, c| {
+            let (abx, aby) = delta(a, b);
+            let (acx, acy) = delta(a, c);
+
+            abx * acy - aby * acx
+        };
+
+        // Make the weights positive inside the triangle, regardless of the winding order.
+        let sign = edge(v1, v2, v3).signum();
+
+        let weights = [
+            edge(v2, v3, p) * sign,
+            edge(v3, v1, p) * sign,
+            edge(v1, v2, p) * sign,
+        ];
+
+        if weights.iter().all(|weight| *weight >= 0) {
+            return [weights[0] as u128, weights[1] as u128, weights[2] as u128];
+        }
+
+        // Interpolate between the two vertices of the nearest edge for points outside the
+        // triangle.
+        let (a, b) = [(1, 2), (2, 0), (0, 1)]
+            .iter()
+            .copied()
+            .min_by_key(|&(a, b)| line::segment_distance(self.vertices[a], self.vertices[b], point))
+            .unwrap();
+
+        let (abx, aby) = delta(self.vertices[a], self.vertices[b]);
+        let (apx, apy) = delta(self.vertices[a], p);
+
+        let length_squared = abx * abx + aby * aby;
+        let projection = (apx * abx + apy * aby).max(0).min(length_squared);
+
+        let mut weights = [0; 3];
+        weights[a] = (length_squared - projection) as u128;
+        weights[b] = projection as u128;
+
+        weights
+    }
+}
+
+impl<C> Fill for TriangleGradient<C>
+where
+    C: Blend,
+{
+    type Color = C;
+
+    fn color_at(&self, point: Point) -> Option<C> {
+        let weights = self.weights(point);
+
+        // Scale the weights down to 32 bits without changing their ratio.
+        let max = weights.iter().copied().max().unwrap_or(0);
+        let shift = (128 - max.leading_zeros()).saturating_sub(32);
+        let [w1, w2, w3] = weights;
+
+        Some(C::interpolate(
+            self.colors,
+            [
+                (w1 >> shift) as u32,
+                (w2 >> shift) as u32,
+                (w3 >> shift) as u32,
+            ],
+        ))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        drawable::Drawable,
+        geometry::Size,
+        mock_display::MockDisplay,
+        pixelcolor::{Gray8, GrayColor, Rgb888, RgbColor},
+        primitives::{Primitive, Rectangle},
+    };
+
+    const BLACK_WHITE: [ColorStop<Gray8>; 2] = [
+        ColorStop::new(0, Gray8::BLACK),
+        ColorStop::new(255, Gray8::WHITE),
+    ];
+
+    #[test]
+    fn color_stops() {
+        let stops = [
+            ColorStop::new(50, Rgb888::RED),
+            ColorStop::new(100, Rgb888::GREEN),
+            ColorStop::new(100, Rgb888::BLUE),
+            ColorStop::new(200, Rgb888::WHITE),
+        ];
+
+        assert_eq!(color_at_position(&stops, 0), Some(Rgb888::RED));
+        assert_eq!(color_at_position(&stops, 50), Some(Rgb888::RED));
+        assert_eq!(
+            color_at_position(&stops, 75),
+            Some(Rgb888::new(128, 127, 0))
+        );
+        assert_eq!(color_at_position(&stops, 100), Some(Rgb888::GREEN));
+        assert_eq!(color_at_position(&stops, 101), Some(Rgb888::new(2, 2, 255)));
+        assert_eq!(color_at_position(&stops, 200), Some(Rgb888::WHITE));
+        assert_eq!(color_at_position(&stops, 255), Some(Rgb888::WHITE));
+    }
+
+    #[test]
+    fn no_color_stops() {
+        let gradient: LinearGradient<Gray8> =
+            LinearGradient::new(Point::zero(), Point::new(10, 0), &[]);
+
+        assert_eq!(gradient.color_at(Point::new(5, 0)), None);
+    }
+
+    #[test]
+    fn linear_gradient() -> Result<(), core::convert::Infallible> {
+        let mut display = MockDisplay::new();
+
+        Rectangle::new(Point::zero(), Size::new(18, 2))
+            .into_filled(LinearGradient::new(
+                Point::new(1, 0),
+                Point::new(16, 0),
+                &BLACK_WHITE,
+            ))
+            .draw(&mut display)?;
+
+        assert_eq!(
+            display,
+            MockDisplay::from_pattern(&["00123456789ABCDEFF", "00123456789ABCDEFF"])
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn diagonal_linear_gradient() {
+        let gradient = LinearGradient::new(Point::new(0, 0), Point::new(10, 10), &BLACK_WHITE);
+
+        assert_eq!(gradient.color_at(Point::new(-5, -5)), Some(Gray8::BLACK));
+        assert_eq!(gradient.color_at(Point::new(10, 0)), Some(Gray8::new(127)));
+        assert_eq!(gradient.color_at(Point::new(0, 10)), Some(Gray8::new(127)));
+        assert_eq!(gradient.color_at(Point::new(10, 10)), Some(Gray8::WHITE));
+    }
+
+    #[test]
+    fn degenerate_linear_gradient() {
+        let gradient = LinearGradient::new(Point::new(3, 3), Point::new(3, 3), &BLACK_WHITE);
+
+        assert_eq!(gradient.color_at(Point::new(10, 10)), Some(Gray8::BLACK));
+    }
+
+    #[test]
+    fn radial_gradient() {
+        let gradient = RadialGradient::new(Point::new(10, 10), 10, &BLACK_WHITE);
+
+        assert_eq!(gradient.color_at(Point::new(10, 10)), Some(Gray8::BLACK));
+        assert_eq!(gradient.color_at(Point::new(15, 10)), Some(Gray8::new(127)));
+        assert_eq!(gradient.color_at(Point::new(10, 5)), Some(Gray8::new(127)));
+        assert_eq!(gradient.color_at(Point::new(16, 18)), Some(Gray8::WHITE));
+        assert_eq!(gradient.color_at(Point::new(50, 50)), Some(Gray8::WHITE));
+
+        let gradient = RadialGradient::new(Point::new(10, 10), 0, &BLACK_WHITE);
+        assert_eq!(gradient.color_at(Point::new(10, 10)), Some(Gray8::WHITE));
+    }
+
+    #[test]
+    fn triangle_gradient() {
+        let triangle = Triangle::new(Point::new(0, 0), Point::new(20, 0), Point::new(0, 20));
+        let gradient = TriangleGradient::new(&triangle, [Rgb888::RED, Rgb888::GREEN, Rgb888::BLUE]);
+
+        assert_eq!(gradient.color_at(Point::new(0, 0)), Some(Rgb888::RED));
+        assert_eq!(gradient.color_at(Point::new(20, 0)), Some(Rgb888::GREEN));
+        assert_eq!(gradient.color_at(Point::new(0, 20)), Some(Rgb888::BLUE));
+        assert_eq!(
+            gradient.color_at(Point::new(10, 0)),
+            Some(Rgb888::new(128, 128, 0))
+        );
+        assert_eq!(
+            gradient.color_at(Point::new(5, 5)),
+            Some(Rgb888::new(128, 64, 64))
+        );
+
+        // Points outside the triangle use the color of the nearest edge.
+        assert_eq!(
+            gradient.color_at(Point::new(15, 15)),
+            Some(Rgb888::new(0, 128, 128))
+        );
+        assert_eq!(gradient.color_at(Point::new(-5, -5)), Some(Rgb888::RED));
+        assert_eq!(gradient.color_at(Point::new(25, 5)), Some(Rgb888::GREEN));
+        assert_eq!(
+            gradient.color_at(Point::new(10, -3)),
+            Some(Rgb888::new(128, 128, 0))
+        );
+
+        // The winding order of the vertices doesn't change the result.
+        let reversed = TriangleGradient::new(
+            &Triangle::new(triangle.p3, triangle.p2, triangle.p1),
+            [Rgb888::BLUE, Rgb888::GREEN, Rgb888::RED],
+        );
+        for point in triangle.points() {
+            assert_eq!(reversed.color_at(point), gradient.color_at(point));
+        }
+    }
+
+    #[test]
+    fn triangle_gradient_large_coordinates() {
+        let (min, max) = (i32::min_value(), i32::max_value());
+
+        let gradient = TriangleGradient::with_subpixel(
+            &SubpixelTriangle::new(
+                SubpixelPoint::new(min, min),
+                SubpixelPoint::new(max, min),
+                SubpixelPoint::new(min, max),
+            ),
+            [Gray8::BLACK, Gray8::WHITE, Gray8::WHITE],
+        );
+
+        assert_eq!(gradient.color_at(Point::new(min, min)), Some(Gray8::BLACK));
+        assert_eq!(gradient.color_at(Point::zero()), Some(Gray8::WHITE));
+        assert_eq!(gradient.color_at(Point::new(max, max)), Some(Gray8::WHITE));
+    }
+
+    #[test]
+    fn draw_triangle_gradient() -> Result<(), core::convert::Infallible> {
+        let triangle = Triangle::new(Point::new(0, 0), Point::new(3, 0), Point::new(0, 3));
+
+        let mut display = MockDisplay::new();
+        triangle
+            .into_filled(TriangleGradient::new(
+                &triangle,
+                [Gray8::BLACK, Gray8::BLACK, Gray8::WHITE],
+            ))
+            .draw(&mut display)?;
+
+        assert_eq!(
+            display,
+            MockDisplay::from_pattern(&["0000", "555 ", "AA  ", "F   ",])
+        );
+
+        let degenerate = Triangle::new(Point::new(0, 0), Point::new(5, 5), Point::new(10, 10));
+        let gradient =
+            TriangleGradient::new(&degenerate, [Gray8::BLACK, Gray8::WHITE, Gray8::WHITE]);
+        assert_eq!(gradient.color_at(Point::new(5, 5)), Some(Gray8::BLACK));
+
+        Ok(())
+    }
+}
@@ -1,12 +1,18 @@
 //! Styling struct to customize the look of objects.
 
+mod fill;
+mod gradient;
+mod pattern;
 mod primitive_style;
 mod styled;
 mod text_style;
 
+pub use fill::{Fill, StyledFillIterator};
+pub use gradient::{ColorStop, LinearGradient, RadialGradient, TriangleGradient};
+pub use pattern::{ImageFill, Pattern};
 pub use primitive_style::{PrimitiveStyle, PrimitiveStyleBuilder};
 pub use styled::Styled;
-pub use text_style::{TextStyle, TextStyleBuilder};
+pub use text_style::{Baseline, HorizontalAlignment, TextRotation, TextStyle, TextStyleBuilder};
 
 /// Create a [`PrimitiveStyle`]
 ///
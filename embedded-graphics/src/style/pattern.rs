@@ -0,0 +1,310 @@
+use crate::{
+    geometry::Point,
+    image::{GetPixel, ImageDimensions},
+    pixelcolor::PixelColor,
+    style::Fill,
+};
+use core::marker::PhantomData;
+
+/// Image fill.
+///
+/// An image fill uses the pixels of an image as the fill color. The top left corner of the image
+/// is placed at `origin`. The image is either drawn once, leaving the area outside the image
+/// transparent, or repeated to cover the whole primitive by using [`ImageFill::tiled`].
+///
+/// Any image type that implements [`GetPixel`] and [`ImageDimensions`] can be used, for example
+/// [`ImageRaw`] or the TGA and BMP images from the [tinytga] and [tinybmp] crates.
+///
+/// # Examples
+///
+/// ## Fill a circle with a texture
+///
+/// ```rust
+/// use embedded_graphics::{
+///     image::ImageRaw,
+///     pixelcolor::BinaryColor,
+///     prelude::*,
+///     primitives::Circle,
+///     style::ImageFill,
+/// };
+/// # use embedded_graphics::mock_display::MockDisplay;
+/// # let mut display: MockDisplay<BinaryColor> = MockDisplay::default();
+///
+/// // 4x4 pixel texture
+/// let texture: ImageRaw<BinaryColor> =
+///     ImageRaw::new(&[0b0110_0000, 0b1001_0000, 0b1001_0000, 0b0110_0000], 4, 4);
+///
+/// Circle::new(Point::new(20, 20), 21)
+///     .into_filled(ImageFill::tiled(&texture, Point::zero()))
+///     .draw(&mut display)?;
+/// # Ok::<(), core::convert::Infallible>(())
+/// ```
+///
+/// [`ImageFill::tiled`]: #method.tiled
+/// [`GetPixel`]: ../image/trait.GetPixel.html
+/// [`ImageDimensions`]: ../image/trait.ImageDimensions.html
+/// [`ImageRaw`]: ../image/struct.ImageRaw.html
+/// [tinytga]: https://crates.io/crates/tinytga
+/// [tinybmp]: https://crates.io/crates/tinybmp
+#[derive(Debug, Clone, Copy)]
+pub struct ImageFill<'a, I, C> {
+    image: &'a I,
+    origin: Point,
+    tiled: bool,
+    c: PhantomData<C>,
+}
+
+impl<'a, I, C> ImageFill<'a, I, C>
+where
+    I: GetPixel<C> + ImageDimensions,
+    C: PixelColor,
+{
+    /// Creates a new image fill.
+    ///
+    /// The image is drawn once with its top left corner at `origin`.
+    pub fn new(image: &'a I, origin: Point) -> Self {
+        Self {
+            image,
+            origin,
+            tiled: false,
+            c: PhantomData,
+        }
+    }
+
+    /// Creates a new tiled image fill.
+    ///
+    /// The image is repeated in both directions, with one of the tiles having its top left corner
+    /// at `origin`.
+    pub fn tiled(image: &'a I, origin: Point) -> Self {
+        Self {
+            image,
+            origin,
+            tiled: true,
+            c: PhantomData,
+        }
+    }
+}
+
+impl<I, C> Fill for ImageFill<'_, I, C>
+where
+    I: GetPixel<C> + ImageDimensions,
+    C: PixelColor,
+{
+    type Color = C;
+
+    fn color_at(&self, point: Point) -> Option<C> {
+        let mut point = point - self.origin;
+
+        if self.tiled {
+            let width = self.image.width() as i32;
+            let height = self.image.height() as i32;
+
+            if width == 0 || height == 0 {
+                return None;
+            }
+
+            point = Point::new(point.x.rem_euclid(width), point.y.rem_euclid(height));
+        }
+
+        self.image.pixel(point)
+    }
+}
+
+/// Pattern fill.
+///
+/// A pattern is an 8x8 pixel 1 bit per pixel bitmap which is repeated to cover the primitive.
+/// Each byte in `rows` defines one row of the pattern, with the most significant bit being the
+/// leftmost pixel. Set bits are drawn in the `foreground` color and cleared bits in the
+/// `background` color, or are left transparent if no background color is set.
+///
+/// Patterns are aligned to the coordinate system of the draw target, which ensures that patterns
+/// in adjacent primitives line up with each other.
+///
+/// # Examples
+///
+/// ## Hatch an inactive region on a monochrome display
+///
+/// ```rust
+/// use embedded_graphics::{
+///     pixelcolor::BinaryColor,
+///     prelude::*,
+///     primitives::Rectangle,
+///     style::{Pattern, PrimitiveStyle},
+/// };
+/// # use embedded_graphics::mock_display::MockDisplay;
+/// # let mut display: MockDisplay<BinaryColor> = MockDisplay::default();
+///
+/// let hatch = Pattern::new(
+///     [
+///         0b1000_1000,
+///         0b0100_0100,
+///         0b0010_0010,
+///         0b0001_0001,
+///         0b1000_1000,
+///         0b0100_0100,
+///         0b0010_0010,
+///         0b0001_0001,
+///     ],
+///     BinaryColor::On,
+/// )
+/// .with_background(BinaryColor::Off);
+///
+/// let region = Rectangle::new(Point::new(10, 10), Size::new(30, 20));
+///
+/// region.into_filled(hatch).draw(&mut display)?;
+/// region
+///     .into_styled(PrimitiveStyle::with_stroke(BinaryColor::On, 1))
+///     .draw(&mut display)?;
+/// # Ok::<(), core::convert::Infallible>(())
+/// ```
+#[derive(Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Debug)]
+pub struct Pattern<C> {
+    /// Pattern rows.
+    pub rows: [u8; 8],
+
+    /// Foreground color.
+    pub foreground: C,
+
+    /// Background color.
+    pub background: Option<C>,
+}
+
+impl<C> Pattern<C>
+where
+    C: PixelColor,
+{
+    /// Creates a new pattern with a transparent background.
+    pub const fn new(rows: [u8; 8], foreground: C) -> Self {
+        Self {
+            rows,
+            foreground,
+            background: None,
+        }
+    }
+
+    /// Sets the background color.
+    pub const fn with_background(self, background: C) -> Self {
+        Self {
+            background: Some(background),
+            ..self
+        }
+    }
+}
+
+impl<C> Fill for Pattern<C>
+where
+    C: PixelColor,
+{
+    type Color = C;
+
+    fn color_at(&self, point: Point) -> Option<C> {
+        let row = self.rows[point.y.rem_euclid(8) as usize];
+
+        if row & (0x80 >> point.x.rem_euclid(8)) != 0 {
+            Some(self.foreground)
+        } else {
+            self.background
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        drawable::Drawable,
+        geometry::Size,
+        image::ImageRaw,
+        mock_display::MockDisplay,
+        pixelcolor::{BinaryColor, Gray8},
+        primitives::{Primitive, Rectangle},
+    };
+
+    const CHECKERBOARD: [u8; 8] = [0xAA, 0x55, 0xAA, 0x55, 0xAA, 0x55, 0xAA, 0x55];
+
+    #[test]
+    fn image_fill() -> Result<(), core::convert::Infallible> {
+        let image: ImageRaw<Gray8> = ImageRaw::new(&[0x11, 0x22, 0x33, 0x44], 2, 2);
+
+        let mut display = MockDisplay::new();
+        Rectangle::new(Point::new(0, 0), Size::new(5, 5))
+            .into_filled(ImageFill::new(&image, Point::new(2, 1)))
+            .draw(&mut display)?;
+
+        #[rustfmt::skip]
+        assert_eq!(
+            display,
+            MockDisplay::from_pattern(&[
+                "     ",
+                "  12 ",
+                "  34 ",
+            ])
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn tiled_image_fill() -> Result<(), core::convert::Infallible> {
+        let image: ImageRaw<Gray8> = ImageRaw::new(&[0x11, 0x22, 0x33, 0x44], 2, 2);
+
+        let mut display = MockDisplay::new();
+        Rectangle::new(Point::new(0, 0), Size::new(5, 3))
+            .into_filled(ImageFill::tiled(&image, Point::new(1, 1)))
+            .draw(&mut display)?;
+
+        #[rustfmt::skip]
+        assert_eq!(
+            display,
+            MockDisplay::from_pattern(&[
+                "43434",
+                "21212",
+                "43434",
+            ])
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn empty_tiled_image_fill() {
+        let image: ImageRaw<Gray8> = ImageRaw::new(&[], 0, 0);
+
+        let fill = ImageFill::tiled(&image, Point::zero());
+
+        assert_eq!(fill.color_at(Point::new(3, 4)), None);
+    }
+
+    #[test]
+    fn pattern() -> Result<(), core::convert::Infallible> {
+        let mut display = MockDisplay::new();
+        Rectangle::new(Point::new(0, 0), Size::new(10, 3))
+            .into_filled(Pattern::new(CHECKERBOARD, BinaryColor::On))
+            .draw(&mut display)?;
+
+        #[rustfmt::skip]
+        assert_eq!(
+            display,
+            MockDisplay::from_pattern(&[
+                "# # # # # ",
+                " # # # # #",
+                "# # # # # ",
+            ])
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn pattern_with_background() {
+        let pattern = Pattern::new([0x80, 0, 0, 0, 0, 0, 0, 0x01], BinaryColor::On)
+            .with_background(BinaryColor::Off);
+
+        assert_eq!(pattern.color_at(Point::new(0, 0)), Some(BinaryColor::On));
+        assert_eq!(pattern.color_at(Point::new(1, 0)), Some(BinaryColor::Off));
+        assert_eq!(pattern.color_at(Point::new(-1, -1)), Some(BinaryColor::On));
+        assert_eq!(pattern.color_at(Point::new(16, 8)), Some(BinaryColor::On));
+        assert_eq!(pattern.color_at(Point::new(15, 15)), Some(BinaryColor::On));
+        assert_eq!(pattern.color_at(Point::new(14, 15)), Some(BinaryColor::Off));
+    }
+}
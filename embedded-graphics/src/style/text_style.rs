@@ -1,4 +1,8 @@
-use crate::{fonts::Font, pixelcolor::PixelColor};
+use crate::{
+    fonts::{Font, ScaledFont},
+    geometry::Point,
+    pixelcolor::PixelColor,
+};
 
 /// Style properties for text.
 ///
@@ -11,7 +15,11 @@ use crate::{fonts::Font, pixelcolor::PixelColor};
 /// [`non_exhaustive`]: https://blog.rust-lang.org/2019/12/19/Rust-1.40.0.html#[non_exhaustive]-structs,-enums,-and-variants
 /// [`text_style!`]: ../macro.text_style.html
 /// [`TextStyleBuilder`]: ./struct.TextStyleBuilder.html
-#[derive(Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Debug, Default)]
+#[derive(Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Debug)]
+#[cfg_attr(
+    feature = "serde_support",
+    derive(serde::Serialize, serde::Deserialize)
+)]
 #[non_exhaustive]
 pub struct TextStyle<C, F>
 where
@@ -26,6 +34,44 @@ where
 
     /// Font.
     pub font: F,
+
+    /// Horizontal alignment.
+    pub horizontal_alignment: HorizontalAlignment,
+
+    /// Vertical position of the text relative to the text position.
+    pub baseline: Baseline,
+
+    /// Integer scale factor.
+    ///
+    /// Each pixel of the font is drawn as a square block of `scale` by `scale` pixels. All font
+    /// metrics, like the line height and character spacing, are scaled by the same factor. A
+    /// scale of `0` is treated like a scale of `1`.
+    pub scale: u8,
+
+    /// Rotation.
+    ///
+    /// The text is rotated around the text position after it was aligned by using the horizontal
+    /// alignment and baseline.
+    pub rotation: TextRotation,
+
+    /// Underline.
+    ///
+    /// The underline is drawn across the full advance of each character, including the character
+    /// spacing of the font. The spacing after the last character of a line is only covered if a
+    /// background color is set.
+    pub underline: bool,
+
+    /// Strikethrough.
+    ///
+    /// The strikethrough line is drawn across the full advance of each character, including the
+    /// character spacing of the font. The spacing after the last character of a line is only
+    /// covered if a background color is set.
+    pub strikethrough: bool,
+
+    /// Color of the underline and strikethrough line.
+    ///
+    /// The text color is used if no decoration color is set.
+    pub decoration_color: Option<C>,
 }
 
 impl<C, F> TextStyle<C, F>
@@ -39,10 +85,201 @@ where
             font,
             text_color: Some(text_color),
             background_color: None,
+            horizontal_alignment: HorizontalAlignment::Left,
+            baseline: Baseline::Top,
+            scale: 1,
+            rotation: TextRotation::Rotate0,
+            underline: false,
+            strikethrough: false,
+            decoration_color: None,
+        }
+    }
+
+    /// Returns the font scaled by the scale factor of this style.
+    pub(crate) fn scaled_font(&self) -> ScaledFont<'_, F> {
+        ScaledFont::new(&self.font, self.scale)
+    }
+}
+
+impl<C, F> Default for TextStyle<C, F>
+where
+    C: PixelColor,
+    F: Font + Default,
+{
+    fn default() -> Self {
+        Self {
+            text_color: None,
+            background_color: None,
+            font: F::default(),
+            horizontal_alignment: HorizontalAlignment::Left,
+            baseline: Baseline::Top,
+            scale: 1,
+            rotation: TextRotation::Rotate0,
+            underline: false,
+            strikethrough: false,
+            decoration_color: None,
+        }
+    }
+}
+
+/// Horizontal text alignment.
+///
+/// The alignment defines which part of a line of text is placed at the x coordinate of the
+/// text position. Each line of a multi-line text is aligned separately.
+#[derive(Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Debug)]
+#[cfg_attr(
+    feature = "serde_support",
+    derive(serde::Serialize, serde::Deserialize)
+)]
+pub enum HorizontalAlignment {
+    /// Left aligned text.
+    ///
+    /// The first column of each line starts at the text position.
+    Left,
+
+    /// Centered text.
+    ///
+    /// Each line is centered on the text position. Lines with an even width are shifted one pixel
+    /// to the left, because they don't have a center pixel.
+    Center,
+
+    /// Right aligned text.
+    ///
+    /// The last column of each line ends at the text position.
+    Right,
+}
+
+impl HorizontalAlignment {
+    /// Returns the offset of the first column of a line relative to the text position.
+    pub(crate) fn offset(self, width: u32) -> i32 {
+        match self {
+            HorizontalAlignment::Left => 0,
+            HorizontalAlignment::Center => -((width / 2) as i32),
+            HorizontalAlignment::Right => 1 - width as i32,
+        }
+    }
+}
+
+impl Default for HorizontalAlignment {
+    fn default() -> Self {
+        HorizontalAlignment::Left
+    }
+}
+
+/// Vertical text position.
+///
+/// The baseline defines which row of the first line of text is placed at the y coordinate of the
+/// text position. Following lines are placed below the first line.
+#[derive(Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Debug)]
+#[cfg_attr(
+    feature = "serde_support",
+    derive(serde::Serialize, serde::Deserialize)
+)]
+pub enum Baseline {
+    /// Top.
+    ///
+    /// The top row of the first line is placed at the text position.
+    Top,
+
+    /// Middle.
+    ///
+    /// The middle row of the first line is placed at the text position. For fonts with an even
+    /// height the row above the center is used.
+    Middle,
+
+    /// Alphabetic baseline.
+    ///
+    /// The [baseline] of the font is placed at the text position.
+    ///
+    /// [baseline]: ../fonts/trait.Font.html#method.baseline
+    Alphabetic,
+
+    /// Bottom.
+    ///
+    /// The bottom row of the first line is placed at the text position.
+    Bottom,
+}
+
+impl Baseline {
+    /// Returns the offset of the top row of the first line relative to the text position.
+    pub(crate) fn offset<F: Font>(self, font: &F) -> i32 {
+        let height = font.line_height() as i32;
+
+        match self {
+            Baseline::Top => 0,
+            Baseline::Middle => -((height - 1) / 2),
+            Baseline::Alphabetic => -(font.baseline() as i32),
+            Baseline::Bottom => 1 - height,
         }
     }
 }
 
+impl Default for Baseline {
+    fn default() -> Self {
+        Baseline::Top
+    }
+}
+
+/// Text rotation.
+///
+/// Rotated text is drawn by rotating the unrotated text clockwise around the text position. The
+/// characters are placed along the rotated direction, e.g. from top to bottom for text which is
+/// rotated by 90 degrees.
+///
+/// Text boxes ignore the rotation.
+#[derive(Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Debug)]
+#[cfg_attr(
+    feature = "serde_support",
+    derive(serde::Serialize, serde::Deserialize)
+)]
+pub enum TextRotation {
+    /// No rotation.
+    Rotate0,
+
+    /// Rotated clockwise by 90 degrees.
+    ///
+    /// The text is read from top to bottom.
+    Rotate90,
+
+    /// Rotated by 180 degrees.
+    ///
+    /// The text is upside down and read from right to left.
+    Rotate180,
+
+    /// Rotated clockwise by 270 degrees.
+    ///
+    /// The text is read from bottom to top.
+    Rotate270,
+}
+
+impl TextRotation {
+    /// Rotates a point, relative to the text position, clockwise around the text position.
+    pub(crate) fn rotate(self, point: Point) -> Point {
+        match self {
+            TextRotation::Rotate0 => point,
+            TextRotation::Rotate90 => Point::new(-point.y, point.x),
+            TextRotation::Rotate180 => Point::new(-point.x, -point.y),
+            TextRotation::Rotate270 => Point::new(point.y, -point.x),
+        }
+    }
+
+    /// Returns the rotation which reverses this rotation.
+    pub(crate) fn inverse(self) -> Self {
+        match self {
+            TextRotation::Rotate0 => TextRotation::Rotate0,
+            TextRotation::Rotate90 => TextRotation::Rotate270,
+            TextRotation::Rotate180 => TextRotation::Rotate180,
+            TextRotation::Rotate270 => TextRotation::Rotate90,
+        }
+    }
+}
+
+impl Default for TextRotation {
+    fn default() -> Self {
+        TextRotation::Rotate0
+    }
+}
+
 /// Text style builder.
 ///
 /// Use this builder to create [`TextStyle`]s for [`Text`].
@@ -118,6 +355,39 @@ where
 /// let text = Text::new("Hello Rust!", Point::new(0, 0)).into_styled(style);
 /// ```
 ///
+/// ## Center a label in a button
+///
+/// The text position can be used as the center of the text by setting the horizontal alignment
+/// and baseline. Each line of a multi-line text is centered separately.
+///
+/// ```rust
+/// use embedded_graphics::{
+///     fonts::{Font6x8, Text},
+///     pixelcolor::Rgb565,
+///     prelude::*,
+///     primitives::Rectangle,
+///     style::{Baseline, HorizontalAlignment, PrimitiveStyle, TextStyleBuilder},
+/// };
+/// # use embedded_graphics::mock_display::MockDisplay;
+/// # let mut display: MockDisplay<Rgb565> = MockDisplay::default();
+///
+/// let button = Rectangle::new(Point::new(2, 2), Size::new(50, 15));
+///
+/// let style = TextStyleBuilder::new(Font6x8)
+///     .text_color(Rgb565::WHITE)
+///     .horizontal_alignment(HorizontalAlignment::Center)
+///     .baseline(Baseline::Middle)
+///     .build();
+///
+/// button
+///     .into_styled(PrimitiveStyle::with_fill(Rgb565::BLUE))
+///     .draw(&mut display)?;
+/// Text::new("OK", button.center())
+///     .into_styled(style)
+///     .draw(&mut display)?;
+/// # Ok::<(), core::convert::Infallible>(())
+/// ```
+///
 /// [`Font`]: ../fonts/trait.Font.html
 /// [`Font6x8`]: ../fonts/struct.Font6x8.html
 /// [`Font8x16`]: ../fonts/struct.Font8x16.html
@@ -147,6 +417,13 @@ where
                 font,
                 background_color: None,
                 text_color: None,
+                horizontal_alignment: HorizontalAlignment::Left,
+                baseline: Baseline::Top,
+                scale: 1,
+                rotation: TextRotation::Rotate0,
+                underline: false,
+                strikethrough: false,
+                decoration_color: None,
             },
         }
     }
@@ -165,6 +442,55 @@ where
         self
     }
 
+    /// Sets the horizontal alignment.
+    pub fn horizontal_alignment(mut self, horizontal_alignment: HorizontalAlignment) -> Self {
+        self.style.horizontal_alignment = horizontal_alignment;
+
+        self
+    }
+
+    /// Sets the baseline.
+    pub fn baseline(mut self, baseline: Baseline) -> Self {
+        self.style.baseline = baseline;
+
+        self
+    }
+
+    /// Sets the integer scale factor.
+    pub fn scale(mut self, scale: u8) -> Self {
+        self.style.scale = scale;
+
+        self
+    }
+
+    /// Sets the rotation.
+    pub fn rotation(mut self, rotation: TextRotation) -> Self {
+        self.style.rotation = rotation;
+
+        self
+    }
+
+    /// Enables or disables the underline.
+    pub fn underline(mut self, underline: bool) -> Self {
+        self.style.underline = underline;
+
+        self
+    }
+
+    /// Enables or disables the strikethrough line.
+    pub fn strikethrough(mut self, strikethrough: bool) -> Self {
+        self.style.strikethrough = strikethrough;
+
+        self
+    }
+
+    /// Sets the color of the underline and strikethrough line.
+    pub fn decoration_color(mut self, decoration_color: C) -> Self {
+        self.style.decoration_color = Some(decoration_color);
+
+        self
+    }
+
     /// Builds the text style.
     pub fn build(self) -> TextStyle<C, F> {
         self.style
@@ -183,11 +509,26 @@ mod tests {
             TextStyle {
                 font: Font12x16,
                 text_color: None,
-                background_color: None
+                background_color: None,
+                horizontal_alignment: HorizontalAlignment::Left,
+                baseline: Baseline::Top,
+                scale: 1,
+                rotation: TextRotation::Rotate0,
+                underline: false,
+                strikethrough: false,
+                decoration_color: None,
             }
         );
     }
 
+    #[test]
+    fn default() {
+        assert_eq!(
+            TextStyle::<BinaryColor, Font12x16>::default(),
+            TextStyleBuilder::new(Font12x16).build()
+        );
+    }
+
     #[test]
     fn builder_text_color() {
         assert_eq!(
@@ -214,4 +555,120 @@ mod tests {
             }
         );
     }
+
+    #[test]
+    fn builder_alignment() {
+        let style = TextStyleBuilder::<BinaryColor, _>::new(Font12x16)
+            .horizontal_alignment(HorizontalAlignment::Right)
+            .baseline(Baseline::Alphabetic)
+            .build();
+
+        assert_eq!(style.horizontal_alignment, HorizontalAlignment::Right);
+        assert_eq!(style.baseline, Baseline::Alphabetic);
+    }
+
+    #[test]
+    fn builder_scale() {
+        let style = TextStyleBuilder::<BinaryColor, _>::new(Font12x16)
+            .scale(3)
+            .build();
+
+        assert_eq!(style.scale, 3);
+    }
+
+    #[test]
+    fn builder_rotation() {
+        let style = TextStyleBuilder::<BinaryColor, _>::new(Font12x16)
+            .rotation(TextRotation::Rotate270)
+            .build();
+
+        assert_eq!(style.rotation, TextRotation::Rotate270);
+    }
+
+    #[test]
+    fn builder_decorations() {
+        let style = TextStyleBuilder::new(Font12x16)
+            .underline(true)
+            .strikethrough(true)
+            .decoration_color(BinaryColor::On)
+            .build();
+
+        assert!(style.underline);
+        assert!(style.strikethrough);
+        assert_eq!(style.decoration_color, Some(BinaryColor::On));
+        assert_eq!(style.text_color, None);
+    }
+
+    #[test]
+    fn rotate() {
+        let point = Point::new(3, 1);
+
+        assert_eq!(TextRotation::Rotate0.rotate(point), Point::new(3, 1));
+        assert_eq!(TextRotation::Rotate90.rotate(point), Point::new(-1, 3));
+        assert_eq!(TextRotation::Rotate180.rotate(point), Point::new(-3, -1));
+        assert_eq!(TextRotation::Rotate270.rotate(point), Point::new(1, -3));
+
+        for &rotation in &[
+            TextRotation::Rotate0,
+            TextRotation::Rotate90,
+            TextRotation::Rotate180,
+            TextRotation::Rotate270,
+        ] {
+            assert_eq!(rotation.inverse().rotate(rotation.rotate(point)), point);
+        }
+    }
+
+    #[test]
+    #[cfg(feature = "serde_support")]
+    fn serde_font_by_name() {
+        use serde_test::{assert_tokens, Token};
+
+        let style = TextStyleBuilder::new(Font12x16)
+            .text_color(BinaryColor::On)
+            .build();
+
+        assert_tokens(
+            &style,
+            &[
+                Token::Struct {
+                    name: "TextStyle",
+                    len: 10,
+                },
+                Token::Str("text_color"),
+                Token::Some,
+                Token::UnitVariant {
+                    name: "BinaryColor",
+                    variant: "On",
+                },
+                Token::Str("background_color"),
+                Token::None,
+                Token::Str("font"),
+                Token::Str("Font12x16"),
+                Token::Str("horizontal_alignment"),
+                Token::UnitVariant {
+                    name: "HorizontalAlignment",
+                    variant: "Left",
+                },
+                Token::Str("baseline"),
+                Token::UnitVariant {
+                    name: "Baseline",
+                    variant: "Top",
+                },
+                Token::Str("scale"),
+                Token::U8(1),
+                Token::Str("rotation"),
+                Token::UnitVariant {
+                    name: "TextRotation",
+                    variant: "Rotate0",
+                },
+                Token::Str("underline"),
+                Token::Bool(false),
+                Token::Str("strikethrough"),
+                Token::Bool(false),
+                Token::Str("decoration_color"),
+                Token::None,
+                Token::StructEnd,
+            ],
+        );
+    }
 }
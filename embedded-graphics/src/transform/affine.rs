@@ -0,0 +1,558 @@
+use crate::{
+    geometry::{Angle, Point, SubpixelPoint},
+    primitives::{Line, SubpixelLine, SubpixelTriangle, Triangle},
+    style::Styled,
+};
+
+/// Fixed point representation of `1.0` in the 16.16 format used by `AffineTransform`.
+const ONE: i64 = 1 << 16;
+
+/// Converts a 128 bit intermediate result to `i64`, saturating at the bounds of `i64`.
+fn saturate(value: i128) -> i64 {
+    if value > i128::from(i64::max_value()) {
+        i64::max_value()
+    } else if value < i128::from(i64::min_value()) {
+        i64::min_value()
+    } else {
+        value as i64
+    }
+}
+
+/// Converts a 128 bit intermediate result to `i32`, saturating at the bounds of `i32`.
+fn saturate_i32(value: i128) -> i32 {
+    if value > i128::from(i32::max_value()) {
+        i32::max_value()
+    } else if value < i128::from(i32::min_value()) {
+        i32::min_value()
+    } else {
+        value as i32
+    }
+}
+
+/// Multiplies two 16.16 fixed point numbers.
+fn mul(a: i64, b: i64) -> i64 {
+    saturate((i128::from(a) * i128::from(b) + i128::from(ONE / 2)) >> 16)
+}
+
+/// Affine transformation.
+///
+/// An affine transformation can rotate, scale, skew and translate points. Transformations are
+/// created by using one of the constructors and can be combined by using [`then`]. The
+/// transformation is stored as a matrix of 16.16 fixed point numbers and doesn't require floating
+/// point support.
+///
+/// The matrix uses 64 bit numbers, which can represent translations and scaling factors over the
+/// whole range of `i32`. Results which don't fit into the matrix or into the coordinates of a
+/// transformed point saturate at the bounds of the number type instead of overflowing.
+///
+/// Use the [`AffineTransformable`] trait to apply a transformation to a primitive.
+///
+/// # Examples
+///
+/// ## Rotate a compass needle around its center
+///
+/// ```rust
+/// use embedded_graphics::{
+///     geometry::Angle,
+///     pixelcolor::BinaryColor,
+///     prelude::*,
+///     primitives::Triangle,
+///     style::PrimitiveStyle,
+///     transform::{AffineTransform, AffineTransformable},
+/// };
+/// # use embedded_graphics::mock_display::MockDisplay;
+/// # let mut display: MockDisplay<BinaryColor> = MockDisplay::default();
+///
+/// let center = Point::new(32, 32);
+///
+/// // Needle pointing upwards
+/// let needle = Triangle::new(Point::new(29, 32), Point::new(35, 32), Point::new(32, 10));
+///
+/// let heading = Angle::from_degrees(45);
+/// let transform = AffineTransform::rotation(heading).with_origin(center);
+///
+/// needle
+///     .transform(&transform)
+///     .into_styled(PrimitiveStyle::with_fill(BinaryColor::On))
+///     .draw(&mut display)?;
+/// # Ok::<(), core::convert::Infallible>(())
+/// ```
+///
+/// [`then`]: #method.then
+/// [`AffineTransformable`]: trait.AffineTransformable.html
+#[derive(Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Debug)]
+pub struct AffineTransform {
+    /// Transformation matrix `[a, b, c, d, e, f]` in 16.16 fixed point format.
+    ///
+    /// A point is transformed by calculating `x' = a * x + b * y + c` and `y' = d * x + e * y + f`.
+    matrix: [i64; 6],
+}
+
+impl AffineTransform {
+    /// Creates an identity transformation, which doesn't change the transformed points.
+    pub const fn identity() -> Self {
+        Self {
+            matrix: [ONE, 0, 0, 0, ONE, 0],
+        }
+    }
+
+    /// Creates a translation.
+    pub const fn translation(by: Point) -> Self {
+        Self {
+            matrix: [ONE, 0, by.x as i64 * ONE, 0, ONE, by.y as i64 * ONE],
+        }
+    }
+
+    /// Creates a rotation around the origin.
+    ///
+    /// Positive angles rotate points clockwise, because the Y axis of the display coordinate
+    /// system points downwards.
+    pub fn rotation(angle: Angle) -> Self {
+        let sin = i64::from(angle.sin());
+        let cos = i64::from(angle.cos());
+
+        Self {
+            matrix: [cos, -sin, 0, sin, cos, 0],
+        }
+    }
+
+    /// Creates a scaling by integer factors relative to the origin.
+    ///
+    /// Negative factors mirror points on the corresponding axis.
+    pub const fn scaling(x: i32, y: i32) -> Self {
+        Self {
+            matrix: [x as i64 * ONE, 0, 0, 0, y as i64 * ONE, 0],
+        }
+    }
+
+    /// Creates a uniform scaling by the factor `numerator / denominator` relative to the origin.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `denominator` is zero.
+    pub fn scaling_ratio(numerator: i32, denominator: i32) -> Self {
+        let factor = i64::from(numerator) * ONE / i64::from(denominator);
+
+        Self {
+            matrix: [factor, 0, 0, 0, factor, 0],
+        }
+    }
+
+    /// Creates a skew transformation relative to the origin.
+    ///
+    /// `x` is the angle by which vertical lines are tilted and `y` the angle by which horizontal
+    /// lines are tilted. Angles close to ±90° result in very large coordinates.
+    pub fn skew(x: Angle, y: Angle) -> Self {
+        let tan = |angle: Angle| i64::from(angle.tan().unwrap_or(i32::max_value()));
+
+        Self {
+            matrix: [ONE, tan(x), 0, tan(y), ONE, 0],
+        }
+    }
+
+    /// Combines two transformations.
+    ///
+    /// The returned transformation is equivalent to first applying `self` and then `next`.
+    pub fn then(&self, next: &Self) -> Self {
+        let [a1, b1, c1, d1, e1, f1] = self.matrix;
+        let [a2, b2, c2, d2, e2, f2] = next.matrix;
+
+        Self {
+            matrix: [
+                mul(a2, a1).saturating_add(mul(b2, d1)),
+                mul(a2, b1).saturating_add(mul(b2, e1)),
+                mul(a2, c1).saturating_add(mul(b2, f1)).saturating_add(c2),
+                mul(d2, a1).saturating_add(mul(e2, d1)),
+                mul(d2, b1).saturating_add(mul(e2, e1)),
+                mul(d2, c1).saturating_add(mul(e2, f1)).saturating_add(f2),
+            ],
+        }
+    }
+
+    /// Moves the origin of the transformation to `origin`.
+    ///
+    /// This can be used to rotate or scale objects around a point other than `(0, 0)`, for
+    /// example the center of an object.
+    pub fn with_origin(&self, origin: Point) -> Self {
+        Self::translation(-origin)
+            .then(self)
+            .then(&Self::translation(origin))
+    }
+
+    /// Transforms a point.
+    ///
+    /// The coordinates of the transformed point are rounded to the nearest integer. Coordinates
+    /// outside the range of `i32` saturate at the bounds of `i32`.
+    pub fn transform_point(&self, point: Point) -> Point {
+        let [a, b, c, d, e, f] = self.matrix;
+
+        let x = i128::from(point.x);
+        let y = i128::from(point.y);
+        let half = i128::from(ONE / 2);
+
+        // 128 bit intermediates can't overflow for any matrix and point.
+        Point::new(
+            saturate_i32((i128::from(a) * x + i128::from(b) * y + i128::from(c) + half) >> 16),
+            saturate_i32((i128::from(d) * x + i128::from(e) * y + i128::from(f) + half) >> 16),
+        )
+    }
+}
+
+impl AffineTransform {
+    /// Transforms a point with subpixel precision.
+    ///
+    /// The coordinates of the transformed point are rounded to the nearest 1/256 pixel.
+    /// Coordinates outside the range of `i32` saturate at the bounds of `i32`.
+    pub fn transform_subpixel_point(&self, point: SubpixelPoint) -> SubpixelPoint {
+        let [a, b, c, d, e, f] = self.matrix;
+
+        let x = i128::from(point.x);
+        let y = i128::from(point.y);
+        let half = i128::from(ONE / 2);
+
+        // The translation is converted from 16.16 to the 24.8 format of the subpixel point by
+        // multiplying it by 256 before the whole sum is shifted.
+        SubpixelPoint::new(
+            saturate_i32(
+                (i128::from(a) * x + i128::from(b) * y + i128::from(c) * 256 + half) >> 16,
+            ),
+            saturate_i32(
+                (i128::from(d) * x + i128::from(e) * y + i128::from(f) * 256 + half) >> 16,
+            ),
+        )
+    }
+}
+
+impl Default for AffineTransform {
+    fn default() -> Self {
+        Self::identity()
+    }
+}
+
+/// Objects that can be transformed by an [`AffineTransform`].
+///
+/// This trait is implemented for primitives that are still the same type of primitive after an
+/// arbitrary affine transformation was applied, like [`Line`] and [`Triangle`].
+///
+/// [`AffineTransform`]: struct.AffineTransform.html
+/// [`Line`]: ../primitives/line/struct.Line.html
+/// [`Triangle`]: ../primitives/triangle/struct.Triangle.html
+pub trait AffineTransformable {
+    /// Applies an affine transformation to an object, returning a new object.
+    fn transform(&self, transform: &AffineTransform) -> Self;
+
+    /// Applies an affine transformation to an object, mutating the object in place.
+    fn transform_mut(&mut self, transform: &AffineTransform) -> &mut Self;
+}
+
+impl AffineTransformable for Point {
+    fn transform(&self, transform: &AffineTransform) -> Self {
+        transform.transform_point(*self)
+    }
+
+    fn transform_mut(&mut self, transform: &AffineTransform) -> &mut Self {
+        *self = transform.transform_point(*self);
+
+        self
+    }
+}
+
+impl AffineTransformable for SubpixelPoint {
+    fn transform(&self, transform: &AffineTransform) -> Self {
+        transform.transform_subpixel_point(*self)
+    }
+
+    fn transform_mut(&mut self, transform: &AffineTransform) -> &mut Self {
+        *self = transform.transform_subpixel_point(*self);
+
+        self
+    }
+}
+
+impl AffineTransformable for Line {
+    fn transform(&self, transform: &AffineTransform) -> Self {
+        Self::new(
+            transform.transform_point(self.start),
+            transform.transform_point(self.end),
+        )
+    }
+
+    fn transform_mut(&mut self, transform: &AffineTransform) -> &mut Self {
+        self.start.transform_mut(transform);
+        self.end.transform_mut(transform);
+
+        self
+    }
+}
+
+impl AffineTransformable for SubpixelLine {
+    fn transform(&self, transform: &AffineTransform) -> Self {
+        Self::new(
+            transform.transform_subpixel_point(self.start),
+            transform.transform_subpixel_point(self.end),
+        )
+    }
+
+    fn transform_mut(&mut self, transform: &AffineTransform) -> &mut Self {
+        self.start.transform_mut(transform);
+        self.end.transform_mut(transform);
+
+        self
+    }
+}
+
+impl AffineTransformable for Triangle {
+    fn transform(&self, transform: &AffineTransform) -> Self {
+        Self::new(
+            transform.transform_point(self.p1),
+            transform.transform_point(self.p2),
+            transform.transform_point(self.p3),
+        )
+    }
+
+    fn transform_mut(&mut self, transform: &AffineTransform) -> &mut Self {
+        self.p1.transform_mut(transform);
+        self.p2.transform_mut(transform);
+        self.p3.transform_mut(transform);
+
+        self
+    }
+}
+
+impl AffineTransformable for SubpixelTriangle {
+    fn transform(&self, transform: &AffineTransform) -> Self {
+        Self::new(
+            transform.transform_subpixel_point(self.p1),
+            transform.transform_subpixel_point(self.p2),
+            transform.transform_subpixel_point(self.p3),
+        )
+    }
+
+    fn transform_mut(&mut self, transform: &AffineTransform) -> &mut Self {
+        self.p1.transform_mut(transform);
+        self.p2.transform_mut(transform);
+        self.p3.transform_mut(transform);
+
+        self
+    }
+}
+
+impl<T, S> AffineTransformable for Styled<T, S>
+where
+    T: AffineTransformable,
+    S: Clone,
+{
+    fn transform(&self, transform: &AffineTransform) -> Self {
+        Self {
+            primitive: self.primitive.transform(transform),
+            style: self.style.clone(),
+        }
+    }
+
+    fn transform_mut(&mut self, transform: &AffineTransform) -> &mut Self {
+        self.primitive.transform_mut(transform);
+
+        self
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{pixelcolor::BinaryColor, primitives::Primitive, style::PrimitiveStyle};
+
+    #[test]
+    fn identity() {
+        let point = Point::new(12, -34);
+
+        assert_eq!(AffineTransform::identity().transform_point(point), point);
+        assert_eq!(AffineTransform::default(), AffineTransform::identity());
+    }
+
+    #[test]
+    fn translation() {
+        let transform = AffineTransform::translation(Point::new(5, -7));
+
+        assert_eq!(
+            transform.transform_point(Point::new(1, 2)),
+            Point::new(6, -5)
+        );
+    }
+
+    #[test]
+    fn rotation() {
+        let point = Point::new(10, 0);
+
+        assert_eq!(
+            AffineTransform::rotation(Angle::from_degrees(90)).transform_point(point),
+            Point::new(0, 10)
+        );
+        assert_eq!(
+            AffineTransform::rotation(Angle::from_degrees(180)).transform_point(point),
+            Point::new(-10, 0)
+        );
+        assert_eq!(
+            AffineTransform::rotation(Angle::from_degrees(-90)).transform_point(point),
+            Point::new(0, -10)
+        );
+        assert_eq!(
+            AffineTransform::rotation(Angle::from_degrees(30)).transform_point(Point::new(100, 0)),
+            Point::new(87, 50)
+        );
+    }
+
+    #[test]
+    fn scaling() {
+        assert_eq!(
+            AffineTransform::scaling(2, -3).transform_point(Point::new(4, 5)),
+            Point::new(8, -15)
+        );
+        assert_eq!(
+            AffineTransform::scaling_ratio(1, 2).transform_point(Point::new(4, 6)),
+            Point::new(2, 3)
+        );
+        assert_eq!(
+            AffineTransform::scaling_ratio(3, 2).transform_point(Point::new(4, -6)),
+            Point::new(6, -9)
+        );
+    }
+
+    #[test]
+    fn skew() {
+        assert_eq!(
+            AffineTransform::skew(Angle::from_degrees(45), Angle::from_degrees(0))
+                .transform_point(Point::new(0, 10)),
+            Point::new(10, 10)
+        );
+        assert_eq!(
+            AffineTransform::skew(Angle::from_degrees(0), Angle::from_degrees(-45))
+                .transform_point(Point::new(10, 0)),
+            Point::new(10, -10)
+        );
+    }
+
+    #[test]
+    fn then() {
+        let translate = AffineTransform::translation(Point::new(10, 0));
+        let rotate = AffineTransform::rotation(Angle::from_degrees(90));
+
+        assert_eq!(
+            translate.then(&rotate).transform_point(Point::zero()),
+            Point::new(0, 10)
+        );
+        assert_eq!(
+            rotate.then(&translate).transform_point(Point::zero()),
+            Point::new(10, 0)
+        );
+    }
+
+    #[test]
+    fn with_origin() {
+        let transform =
+            AffineTransform::rotation(Angle::from_degrees(90)).with_origin(Point::new(10, 10));
+
+        assert_eq!(
+            transform.transform_point(Point::new(10, 10)),
+            Point::new(10, 10)
+        );
+        assert_eq!(
+            transform.transform_point(Point::new(15, 10)),
+            Point::new(10, 15)
+        );
+    }
+
+    #[test]
+    fn transform_line() {
+        let transform =
+            AffineTransform::rotation(Angle::from_degrees(180)).with_origin(Point::new(5, 5));
+
+        let mut line = Line::new(Point::new(0, 2), Point::new(8, 4));
+        let expected = Line::new(Point::new(10, 8), Point::new(2, 6));
+
+        assert_eq!(line.transform(&transform), expected);
+        assert_eq!(line.transform_mut(&transform), &expected);
+    }
+
+    #[test]
+    fn transform_styled_triangle() {
+        let transform = AffineTransform::scaling(2, 2);
+        let style = PrimitiveStyle::with_fill(BinaryColor::On);
+
+        let mut triangle =
+            Triangle::new(Point::new(1, 1), Point::new(5, 1), Point::new(1, 4)).into_styled(style);
+        let expected =
+            Triangle::new(Point::new(2, 2), Point::new(10, 2), Point::new(2, 8)).into_styled(style);
+
+        assert_eq!(triangle.transform(&transform), expected);
+        assert_eq!(triangle.transform_mut(&transform), &expected);
+    }
+
+    #[test]
+    fn large_values() {
+        let far = Point::new(100_000, -200_000);
+
+        assert_eq!(
+            AffineTransform::translation(far).transform_point(Point::new(1, 2)),
+            Point::new(100_001, -199_998)
+        );
+        assert_eq!(
+            AffineTransform::scaling(50_000, -50_000).transform_point(Point::new(2, 3)),
+            Point::new(100_000, -150_000)
+        );
+        assert_eq!(
+            AffineTransform::rotation(Angle::from_degrees(90))
+                .with_origin(far)
+                .transform_point(far + Point::new(10, 0)),
+            far + Point::new(0, 10)
+        );
+        assert_eq!(
+            AffineTransform::translation(Point::new(i32::max_value(), i32::min_value()))
+                .then(&AffineTransform::scaling(
+                    i32::max_value(),
+                    i32::max_value()
+                ))
+                .transform_point(Point::new(1, 1)),
+            Point::new(i32::max_value(), i32::min_value())
+        );
+        assert_eq!(
+            AffineTransform::skew(Angle::from_degrees(90), Angle::from_degrees(0))
+                .then(&AffineTransform::scaling(100_000, 1))
+                .transform_point(Point::new(0, 10)),
+            Point::new(i32::max_value(), 10)
+        );
+    }
+
+    #[test]
+    fn transform_subpixel_point() {
+        let transform = AffineTransform::translation(Point::new(2, -3))
+            .then(&AffineTransform::rotation(Angle::from_degrees(90)));
+
+        assert_eq!(
+            transform.transform_subpixel_point(SubpixelPoint::new(128, 64)),
+            SubpixelPoint::new(3 * 256 - 64, 2 * 256 + 128)
+        );
+    }
+
+    #[test]
+    fn transform_subpixel_primitives() {
+        let transform = AffineTransform::rotation(Angle::from_degrees(30));
+
+        let mut line = SubpixelLine::new(SubpixelPoint::new(64, 32), SubpixelPoint::new(2560, 0));
+        let expected = SubpixelLine::new(
+            transform.transform_subpixel_point(line.start),
+            transform.transform_subpixel_point(line.end),
+        );
+        assert_eq!(line.transform(&transform), expected);
+        assert_eq!(line.transform_mut(&transform), &expected);
+        assert_eq!(line.end, SubpixelPoint::new(2217, 1280));
+
+        let mut triangle = SubpixelTriangle::new(
+            SubpixelPoint::new(64, 32),
+            SubpixelPoint::new(2560, 0),
+            SubpixelPoint::new(0, 1000),
+        );
+        let transformed = triangle.transform(&transform);
+        assert_eq!(triangle.transform_mut(&transform), &transformed);
+        assert_eq!(transformed.p2, SubpixelPoint::new(2217, 1280));
+    }
+}
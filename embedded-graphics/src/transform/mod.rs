@@ -1,5 +1,9 @@
 //! Transformations for graphics objects
 
+mod affine;
+
+pub use affine::{AffineTransform, AffineTransformable};
+
 use crate::geometry::Point;
 
 /// Transform operations
@@ -1,6 +1,6 @@
 //! # Example: Custom font
 //!
-//! Shows how to implement the `Font` trait for a custom `SeventSegmentFont` font. This font renders
+//! Shows how to implement the `ImageFont` trait for a custom `SeventSegmentFont` font. This font renders
 //! numbers only and emulates a classic 7 segment display.
 
 use embedded_graphics::{egtext, pixelcolor::BinaryColor, prelude::*, text_style};
@@ -11,7 +11,7 @@ use embedded_graphics_simulator::{
 #[derive(Debug, Copy, Clone, PartialEq, Eq)]
 struct SevenSegmentFont;
 
-impl Font for SevenSegmentFont {
+impl ImageFont for SevenSegmentFont {
     const FONT_IMAGE: &'static [u8] = include_bytes!("assets/seven-segment-font.raw");
     const FONT_IMAGE_WIDTH: u32 = 224;
 
@@ -2,6 +2,7 @@ use crate::{framebuffer::Framebuffer, output_settings::OutputSettings};
 use embedded_graphics::{
     drawable::Pixel,
     geometry::{Point, Size},
+    image::GetPixel,
     pixelcolor::{BinaryColor, PixelColor, Rgb888},
     DrawTarget,
 };
@@ -35,8 +36,7 @@ where
     ///
     /// Panics if `point` is outside the display.
     pub fn get_pixel(&self, point: Point) -> C {
-        self.point_to_index(point)
-            .and_then(|index| self.pixels.get(index).copied())
+        self.pixel(point)
             .expect("can't get point outside of display")
     }
 
@@ -97,6 +97,16 @@ where
     }
 }
 
+impl<C> GetPixel<C> for SimulatorDisplay<C>
+where
+    C: PixelColor,
+{
+    fn pixel(&self, point: Point) -> Option<C> {
+        self.point_to_index(point)
+            .and_then(|index| self.pixels.get(index).copied())
+    }
+}
+
 impl<C> DrawTarget<C> for SimulatorDisplay<C>
 where
     C: PixelColor,
@@ -125,6 +125,32 @@ impl<'a> Bmp<'a> {
         u32::from(self.header.bpp)
     }
 
+    /// Get the raw value of the pixel at the given position.
+    ///
+    /// `None` is returned if the position is outside the image or the bit depth isn't supported.
+    /// The coordinates are relative to the top left corner of the image.
+    pub fn pixel_at(&self, x: u32, y: u32) -> Option<u32> {
+        if x >= self.width() || y >= self.height() {
+            return None;
+        }
+
+        let pixel_stride = match self.bpp() {
+            8 => 1,
+            16 => 2,
+            24 => 3,
+            32 => 4,
+            _ => return None,
+        };
+
+        let row_index = (self.height() - 1) - y;
+        let start = self.bytes_per_row() * row_index as usize + x as usize * pixel_stride;
+
+        let mut bytes = [0; 4];
+        bytes[0..pixel_stride].copy_from_slice(self.image_data.get(start..start + pixel_stride)?);
+
+        Some(u32::from_le_bytes(bytes))
+    }
+
     /// Returns the row length in bytes.
     ///
     /// Each row in a BMP file is a multiple of 4 bytes long.
@@ -239,7 +265,7 @@ mod e_g {
     use embedded_graphics::{
         drawable::Pixel as EgPixel,
         geometry::Point,
-        image::{ImageDimensions, IntoPixelIter},
+        image::{GetPixel, ImageDimensions, IntoPixelIter},
         pixelcolor::{raw::RawData, PixelColor},
     };
 
@@ -277,6 +303,20 @@ mod e_g {
         }
     }
 
+    impl<C> GetPixel<C> for Bmp<'_>
+    where
+        C: PixelColor + From<<C as PixelColor>::Raw>,
+    {
+        fn pixel(&self, point: Point) -> Option<C> {
+            if point.x < 0 || point.y < 0 {
+                return None;
+            }
+
+            self.pixel_at(point.x as u32, point.y as u32)
+                .map(|color| C::Raw::from_u32(color).into())
+        }
+    }
+
     impl<'a, C> IntoPixelIter<C> for &'a Bmp<'_>
     where
         C: PixelColor + From<<C as PixelColor>::Raw>,
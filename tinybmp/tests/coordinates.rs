@@ -20,3 +20,25 @@ fn coordinates() {
 
     assert_eq!(pixels, expected);
 }
+
+#[test]
+fn pixel_at() {
+    let images = [
+        &include_bytes!("./chessboard-8px-24bit.bmp")[..],
+        &include_bytes!("./chessboard-8px-color-16bit.bmp")[..],
+        &include_bytes!("./colors_grey8.bmp")[..],
+        &include_bytes!("./colors_rgb888_32bit.bmp")[..],
+        &include_bytes!("./issue_136.bmp")[..],
+    ];
+
+    for data in images.iter() {
+        let bmp = Bmp::from_slice(data).expect("Failed to parse");
+
+        for Pixel { x, y, color } in bmp.into_iter() {
+            assert_eq!(bmp.pixel_at(x, y), Some(color));
+        }
+
+        assert_eq!(bmp.pixel_at(bmp.width(), 0), None);
+        assert_eq!(bmp.pixel_at(0, bmp.height()), None);
+    }
+}
@@ -1,10 +1,11 @@
 use embedded_graphics::{
     drawable::{Drawable, Pixel},
     geometry::{Dimensions, Point, Size},
-    image::Image,
+    image::{GetPixel, Image},
     mock_display::MockDisplay,
     pixelcolor::{BinaryColor, Gray8, GrayColor, Rgb555, Rgb565, Rgb888, RgbColor},
-    primitives::Rectangle,
+    primitives::{Primitive, Rectangle},
+    style::ImageFill,
     transform::Transform,
 };
 use tinybmp::Bmp;
@@ -171,3 +172,32 @@ fn issue_136_row_size_is_multiple_of_4_bytes() {
         ])
     );
 }
+
+#[test]
+fn get_pixel() {
+    let image = Bmp::from_slice(include_bytes!("./issue_136.bmp")).unwrap();
+
+    assert_eq!(image.pixel(Point::new(0, 0)), Some(Rgb565::WHITE));
+    assert_eq!(image.pixel(Point::new(1, 1)), Some(Rgb565::BLACK));
+    assert_eq!(image.pixel(Point::new(8, 4)), Some(Rgb565::WHITE));
+    assert_eq!(GetPixel::<Rgb565>::pixel(&image, Point::new(-1, 0)), None);
+    assert_eq!(GetPixel::<Rgb565>::pixel(&image, Point::new(9, 0)), None);
+}
+
+#[test]
+fn image_fill() {
+    let image = Bmp::from_slice(include_bytes!("./issue_136.bmp")).unwrap();
+
+    let mut display = MockDisplay::new();
+    Rectangle::new(Point::new(0, 0), Size::new(6, 3))
+        .into_filled(ImageFill::<_, Rgb565>::new(&image, Point::new(1, 1)))
+        .into_iter()
+        .map(|Pixel(p, c)| Pixel(p, BinaryColor::from(c == Rgb565::WHITE)))
+        .draw(&mut display)
+        .unwrap();
+
+    assert_eq!(
+        display,
+        MockDisplay::from_pattern(&["      ", " ####.", " #...."])
+    );
+}
@@ -108,6 +108,70 @@ impl<'a> Tga<'a> {
         self.header.height
     }
 
+    /// Get the raw value of the pixel at the given position.
+    ///
+    /// `None` is returned if the position is outside the image. The coordinates use the same
+    /// order as the pixels returned by [`TgaIterator`].
+    ///
+    /// Only uncompressed images support random access to pixels. `None` is returned for RLE
+    /// compressed images, which can only be decoded sequentially by using [`TgaIterator`].
+    ///
+    /// [`TgaIterator`]: ./struct.TgaIterator.html
+    pub fn pixel_at(&self, x: u32, y: u32) -> Option<u32> {
+        let width = u32::from(self.width());
+
+        if x >= width || y >= u32::from(self.height()) {
+            return None;
+        }
+
+        let index = (y * width + x) as usize;
+
+        match self.header.image_type {
+            ImageType::Monochrome | ImageType::Truecolor | ImageType::ColorMapped => {
+                let stride = usize::from(self.bpp() / 8);
+                let start = index * stride;
+
+                let mut bytes = [0; 4];
+                bytes[0..stride].copy_from_slice(self.pixel_data.get(start..start + stride)?);
+
+                let pixel_value = u32::from_le_bytes(bytes);
+
+                if self.color_map.is_some() {
+                    Some(self.color_map_lookup(pixel_value))
+                } else {
+                    Some(pixel_value)
+                }
+            }
+            _ => None,
+        }
+    }
+
+    /// Returns the color map entry for an index.
+    fn color_map_lookup(&self, index: u32) -> u32 {
+        let color_map = self.color_map.unwrap_or(&[]);
+
+        let entry_size = usize::from(self.header.color_map_depth + 7) / 8;
+        let start = index as usize * entry_size;
+
+        match entry_size {
+            1 => color_map[start] as u32,
+            2 => u32::from_le_bytes([color_map[start], color_map[start + 1], 0, 0]),
+            3 => u32::from_le_bytes([
+                color_map[start],
+                color_map[start + 1],
+                color_map[start + 2],
+                0,
+            ]),
+            4 => u32::from_le_bytes([
+                color_map[start],
+                color_map[start + 1],
+                color_map[start + 2],
+                color_map[start + 3],
+            ]),
+            depth => unreachable!("Depth {} is not supported", depth),
+        }
+    }
+
     /// Get the raw image data contained in this image
     pub fn image_data(&self) -> &[u8] {
         self.pixel_data
@@ -261,27 +325,8 @@ impl<'a> Iterator for TgaIterator<'a> {
             out
         };
 
-        if let Some(color_map) = self.tga.color_map {
-            let entry_size = usize::from(self.tga.header.color_map_depth + 7) / 8;
-            let start = pixel_value as usize * entry_size;
-
-            pixel_value = match entry_size {
-                1 => color_map[start] as u32,
-                2 => u32::from_le_bytes([color_map[start], color_map[start + 1], 0, 0]),
-                3 => u32::from_le_bytes([
-                    color_map[start],
-                    color_map[start + 1],
-                    color_map[start + 2],
-                    0,
-                ]),
-                4 => u32::from_le_bytes([
-                    color_map[start],
-                    color_map[start + 1],
-                    color_map[start + 2],
-                    color_map[start + 3],
-                ]),
-                depth => unreachable!("Depth {} is not supported", depth),
-            };
+        if self.tga.color_map.is_some() {
+            pixel_value = self.tga.color_map_lookup(pixel_value);
         }
 
         let x = self.x;
@@ -309,7 +354,7 @@ mod e_g {
     use embedded_graphics::{
         drawable::Pixel as EgPixel,
         geometry::Point,
-        image::{ImageDimensions, IntoPixelIter},
+        image::{GetPixel, ImageDimensions, IntoPixelIter},
         pixelcolor::{raw::RawData, PixelColor},
     };
 
@@ -347,6 +392,20 @@ mod e_g {
         }
     }
 
+    impl<C> GetPixel<C> for Tga<'_>
+    where
+        C: PixelColor + From<<C as PixelColor>::Raw>,
+    {
+        fn pixel(&self, point: Point) -> Option<C> {
+            if point.x < 0 || point.y < 0 {
+                return None;
+            }
+
+            self.pixel_at(point.x as u32, point.y as u32)
+                .map(|color| C::Raw::from_u32(color).into())
+        }
+    }
+
     impl<'a, C> IntoPixelIter<C> for &'a Tga<'_>
     where
         C: PixelColor + From<<C as PixelColor>::Raw>,
@@ -1,4 +1,4 @@
-use tinytga::{ImageType, Tga, TgaFooter, TgaHeader};
+use tinytga::{ImageType, Pixel, Tga, TgaFooter, TgaHeader};
 
 #[test]
 fn coordinates() {
@@ -65,3 +65,40 @@ fn coordinates() {
         ]
     );
 }
+
+#[test]
+fn pixel_at() {
+    let images = [
+        &include_bytes!("./type1_tl.tga")[..],
+        &include_bytes!("./type2_tl.tga")[..],
+        &include_bytes!("./type3_tl.tga")[..],
+        &include_bytes!("./chessboard_4px_raw.tga")[..],
+    ];
+
+    for data in images.iter() {
+        let img = Tga::from_slice(data).unwrap();
+
+        for Pixel { x, y, color } in img.into_iter() {
+            assert_eq!(img.pixel_at(x, y), Some(color));
+        }
+
+        assert_eq!(img.pixel_at(img.width().into(), 0), None);
+        assert_eq!(img.pixel_at(0, img.height().into()), None);
+    }
+}
+
+#[test]
+fn pixel_at_rle() {
+    let images = [
+        &include_bytes!("./type9_tl.tga")[..],
+        &include_bytes!("./type10_tl.tga")[..],
+        &include_bytes!("./type11_tl.tga")[..],
+        &include_bytes!("./chessboard_4px_rle.tga")[..],
+    ];
+
+    for data in images.iter() {
+        let img = Tga::from_slice(data).unwrap();
+
+        assert_eq!(img.pixel_at(0, 0), None);
+    }
+}
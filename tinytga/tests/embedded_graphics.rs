@@ -1,9 +1,11 @@
 use embedded_graphics::{
     drawable::{Drawable, Pixel},
-    geometry::Point,
-    image::Image,
+    geometry::{Point, Size},
+    image::{GetPixel, Image},
     mock_display::MockDisplay,
     pixelcolor::{Gray8, Rgb888, RgbColor},
+    primitives::{Primitive, Rectangle},
+    style::ImageFill,
 };
 use tinytga::Tga;
 
@@ -66,6 +68,43 @@ fn chessboard_uncompressed() {
     assert_eq!(pixels.next(), None);
 }
 
+#[test]
+fn chessboard_get_pixel() {
+    let im = Tga::from_slice(include_bytes!("./chessboard_raw.tga")).unwrap();
+
+    for (x, y, color) in PIXEL_COLORS.iter() {
+        assert_eq!(im.pixel(Point::new(*x, *y)), Some(*color));
+    }
+
+    assert_eq!(GetPixel::<Rgb888>::pixel(&im, Point::new(4, 0)), None);
+    assert_eq!(GetPixel::<Rgb888>::pixel(&im, Point::new(0, -1)), None);
+}
+
+#[test]
+fn chessboard_get_pixel_rle() {
+    let im = Tga::from_slice(include_bytes!("./chessboard_4px_rle.tga")).unwrap();
+
+    for (x, y, _) in PIXEL_COLORS.iter() {
+        assert_eq!(GetPixel::<Rgb888>::pixel(&im, Point::new(*x, *y)), None);
+    }
+}
+
+#[test]
+fn tiled_image_fill() {
+    let im = Tga::from_slice(include_bytes!("./type2_tl.tga")).unwrap();
+
+    let mut display: MockDisplay<Rgb888> = MockDisplay::new();
+    Rectangle::new(Point::zero(), Size::new(12, 2))
+        .into_filled(ImageFill::tiled(&im, Point::new(3, 0)))
+        .draw(&mut display)
+        .unwrap();
+
+    assert_eq!(
+        display,
+        MockDisplay::from_pattern(&["MCWWKRGBYMCW", "MCWKKRGBYMCW"])
+    );
+}
+
 fn test_color_tga(data: &[u8]) {
     let im = Tga::from_slice(data).unwrap();
     let image: Image<_, Rgb888> = Image::new(&im, Point::zero());